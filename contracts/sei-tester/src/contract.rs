@@ -1,25 +1,23 @@
-use base64::{engine::general_purpose, Engine as _};
 use cosmwasm_std::to_json_binary;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::{
     coin, entry_point, Attribute, BankMsg, Binary, Coin, Decimal, Deps, DepsMut, Env, MessageInfo,
-    Order as IteratorOrder, Reply, Response, StdError, StdResult, SubMsg, SubMsgResponse, Uint128,
+    Order as IteratorOrder, Reply, Response, StdError, StdResult, SubMsg, Uint128,
 };
 use cw_storage_plus::Bound;
 
 use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
-    state::{PARALLEL_VALS, USER_SUMS, VALUES},
+    state::{PARALLEL_VALS, POSITIONS, USER_SUMS, VALUES},
     types::{OrderData, PositionEffect},
 };
-use protobuf::Message;
 use sei_cosmwasm::{
-    BulkOrderPlacementsResponse, Cancellation, DenomAuthorityMetadataResponse, DenomUnit,
-    DenomsFromCreatorResponse, DepositInfo, DexTwapsResponse, EpochResponse, EvmAddressResponse,
-    ExchangeRatesResponse, GetLatestPriceResponse, GetOrderByIdResponse, GetOrdersResponse,
-    Metadata, MsgPlaceOrdersResponse, OracleTwapsResponse, Order, OrderSimulationResponse,
-    OrderType, PositionDirection, SeiAddressResponse, SeiMsg, SeiQuerier, SeiQueryWrapper,
-    SettlementEntry, StaticCallResponse, SudoMsg,
+    parse_reply_place_orders, Cancellation, DenomAuthorityMetadataResponse, DenomUnit,
+    DenomsFromCreatorResponse, DepositInfo, DexHooks, DexTwapsResponse, EpochResponse,
+    EvmAddressResponse, ExchangeRatesResponse, GetLatestPriceResponse, GetOrderByIdResponse,
+    GetOrdersResponse, Metadata, OracleTwapsResponse, Order, OrderBuilder, OrderPlacements,
+    OrderSimulationResponse, OrderType, PositionDirection, PositionsResponse, SeiAddressResponse,
+    SeiMsg, SeiQuerier, SeiQueryWrapper, SettlementEntry, StaticCallResponse, SudoMsg, TypedOrder,
 };
 
 const PLACE_ORDER_REPLY_ID: u64 = 1;
@@ -152,17 +150,14 @@ pub fn place_orders(
         position_effect: PositionEffect::Open,
     };
 
-    let order_placement = Order {
-        price: Decimal::from_atomics(120u128, 0).unwrap(),
-        quantity: Decimal::one(),
-        price_denom: "USDC".to_string(),
-        asset_denom: "ATOM".to_string(),
-        position_direction: PositionDirection::Long,
-        order_type: OrderType::Limit,
-        data: serde_json::to_string(&order_data).unwrap(),
-        status_description: "".to_string(),
-        nominal: Decimal::zero(),
-    };
+    let order_placement = OrderBuilder::limit(
+        "USDC",
+        "ATOM",
+        Decimal::from_atomics(120u128, 0).unwrap(),
+        Decimal::one(),
+    )
+    .data(&order_data)
+    .build()?;
     let fund = Coin {
         denom: "uusdc".to_string(),
         amount: Uint128::new(10000000000u128),
@@ -303,56 +298,46 @@ pub fn set_metadata(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn sudo(
     deps: DepsMut<SeiQueryWrapper>,
-    _env: Env,
+    env: Env,
     msg: SudoMsg,
 ) -> Result<Response<SeiMsg>, StdError> {
-    match msg {
-        SudoMsg::Settlement { epoch, entries } => process_settlements(deps, entries, epoch),
-        SudoMsg::BulkOrderPlacements { orders, deposits } => {
-            process_bulk_order_placements(deps, orders, deposits)
-        }
-        SudoMsg::BulkOrderCancellations { ids } => process_bulk_order_cancellations(deps, ids),
+    SeiTesterHooks.sudo(deps, env, msg)
+}
+
+/// Dex hooks of the tester, accepting every order and tracking the positions of the accounts
+/// from the settlements. Orders placed without data have no `OrderData`.
+pub struct SeiTesterHooks;
+
+impl DexHooks for SeiTesterHooks {
+    type OrderData = Option<OrderData>;
+    type Error = StdError;
+
+    fn settlement(
+        &self,
+        deps: DepsMut<SeiQueryWrapper>,
+        _env: Env,
+        epoch: i64,
+        entries: Vec<SettlementEntry>,
+    ) -> Result<Response<SeiMsg>, StdError> {
+        POSITIONS.apply_settlement(deps.storage, &entries)?;
+        Ok(Response::new()
+            .add_attribute("method", "process_settlements")
+            .add_attribute("epoch", epoch.to_string()))
     }
-}
-
-pub fn process_settlements(
-    _deps: DepsMut<SeiQueryWrapper>,
-    _entries: Vec<SettlementEntry>,
-    _epoch: i64,
-) -> Result<Response<SeiMsg>, StdError> {
-    Ok(Response::new())
-}
-
-pub fn process_bulk_order_placements(
-    deps: DepsMut<SeiQueryWrapper>,
-    _orders: Vec<Order>,
-    _deposits: Vec<DepositInfo>,
-) -> Result<Response<SeiMsg>, StdError> {
-    let response = BulkOrderPlacementsResponse {
-        unsuccessful_orders: vec![],
-    };
-    let serialized_json = match serde_json::to_string(&response) {
-        Ok(val) => val,
-        Err(error) => panic!("Problem parsing response: {:?}", error),
-    };
-    let base64_json_str = general_purpose::STANDARD.encode(serialized_json);
-    let binary = match Binary::from_base64(base64_json_str.as_ref()) {
-        Ok(val) => val,
-        Err(error) => panic!("Problem converting binary for order request: {:?}", error),
-    };
-
-    let mut response: Response = Response::new();
-    response = response.set_data(binary);
-    deps.api
-        .debug(&format!("process_bulk_order_placements: {:?}", response));
-    Ok(Response::new())
-}
 
-pub fn process_bulk_order_cancellations(
-    _deps: DepsMut<SeiQueryWrapper>,
-    _ids: Vec<u64>,
-) -> Result<Response<SeiMsg>, StdError> {
-    Ok(Response::new())
+    fn order_placements(
+        &self,
+        deps: DepsMut<SeiQueryWrapper>,
+        _env: Env,
+        orders: Vec<TypedOrder<Option<OrderData>>>,
+        _deposits: Vec<DepositInfo>,
+    ) -> Result<OrderPlacements, StdError> {
+        deps.api.debug(&format!(
+            "process_bulk_order_placements: {} orders",
+            orders.len()
+        ));
+        Ok(OrderPlacements::default())
+    }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -371,29 +356,18 @@ pub fn handle_place_order_reply(
     deps: DepsMut<SeiQueryWrapper>,
     msg: Reply,
 ) -> Result<Response<SeiMsg>, StdError> {
-    let submsg_response: SubMsgResponse =
-        msg.result.into_result().map_err(StdError::generic_err)?;
-
-    match submsg_response.data {
-        Some(response_data) => {
-            let parsed_order_response: MsgPlaceOrdersResponse =
-                Message::parse_from_bytes(response_data.as_slice()).map_err(|_| {
-                    StdError::parse_err("MsgPlaceOrdersResponse", "failed to parse data")
-                })?;
-            deps.api.debug(&format!(
-                "Order results from contract {:?}",
-                parsed_order_response
-            ));
-
-            Ok(Response::new()
-                .add_attribute("method", "handle_place_order_reply")
-                .add_attribute(
-                    "order_ids",
-                    format!("{:?}", parsed_order_response.order_ids),
-                ))
-        }
-        None => Ok(Response::new().add_attribute("method", "handle_place_order_reply")),
-    }
+    let parsed_order_response = parse_reply_place_orders(msg)?;
+    deps.api.debug(&format!(
+        "Order results from contract {:?}",
+        parsed_order_response
+    ));
+
+    Ok(Response::new()
+        .add_attribute("method", "handle_place_order_reply")
+        .add_attribute(
+            "order_ids",
+            format!("{:?}", parsed_order_response.order_ids),
+        ))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -453,6 +427,7 @@ pub fn query(deps: Deps<SeiQueryWrapper>, _env: Env, msg: QueryMsg) -> StdResult
         QueryMsg::GetSeiAddressByEvmAddress { evm_address } => {
             to_json_binary(&query_sei_address(deps, evm_address)?)
         }
+        QueryMsg::GetPositions { account } => to_json_binary(&query_positions(deps, account)?),
     }
 }
 
@@ -517,6 +492,13 @@ pub fn query_get_orders(
     Ok(res)
 }
 
+pub fn query_positions(
+    deps: Deps<SeiQueryWrapper>,
+    account: String,
+) -> StdResult<PositionsResponse> {
+    POSITIONS.positions(deps.storage, &account)
+}
+
 pub fn query_get_order_by_id(
     deps: Deps<SeiQueryWrapper>,
     contract_address: String,
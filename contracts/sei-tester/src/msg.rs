@@ -83,4 +83,7 @@ pub enum QueryMsg {
     GetSeiAddressByEvmAddress {
         evm_address: String,
     },
+    GetPositions {
+        account: String,
+    },
 }
@@ -1,50 +1,47 @@
-use base64::{engine::general_purpose, Engine as _};
+use bech32::FromBase32;
 use cosmwasm_std::{
-    coin, from_json,
-    testing::{MockApi, MockStorage},
-    Addr, Api, BalanceResponse, Coin, CosmosMsg, Decimal, Empty, GovMsg, IbcMsg, IbcQuery,
-    QueryRequest, StdError, Storage, Uint128,
-};
-use cosmwasm_std::{BlockInfo, Uint64};
-use cw_multi_test::{
-    App, BankKeeper, ContractWrapper, DistributionKeeper, Executor, FailingModule, Router,
-    StakeKeeper, WasmKeeper,
+    coin, from_json, to_json_binary, Addr, Api, BalanceResponse, BankQuery, Binary, Coin,
+    CosmosMsg, Decimal, DenomMetadataResponse, DepsMut, Env, QueryRequest, Response, SignedDecimal,
+    StdError, Storage, Uint128, Uint256,
 };
+use cosmwasm_std::{testing::MockStorage, BlockInfo, Uint64};
+use cw_multi_test::{ContractWrapper, Executor};
+use serde::de::DeserializeOwned;
 
 use sei_cosmwasm::{
-    Cancellation, DenomOracleExchangeRatePair, DexPair, DexTwap, DexTwapsResponse, EpochResponse,
-    EvmAddressResponse, ExchangeRatesResponse, GetOrderByIdResponse, GetOrdersResponse,
-    OracleExchangeRate, OracleTwapsResponse, Order, OrderSimulationResponse, OrderStatus,
-    OrderType, PositionDirection, SeiAddressResponse, SeiMsg, SeiQuery, SeiQueryWrapper, SeiRoute,
-    StaticCallResponse,
+    encode_function_call, format_rfc3339, AbiToken, AbiType, BulkOrderPlacementsResponse,
+    Cancellation, DenomAuthorityMetadataResponse, DenomOracleExchangeRatePair,
+    DenomsFromCreatorResponse, DepositInfo, DexPair, DexTwap, DexTwapsResponse, Epoch,
+    EpochResponse, Erc1155BalanceOfBatchResponse, Erc1155BalanceOfResponse,
+    Erc1155IsApprovedForAllResponse, Erc1155RoyaltyInfoResponse, Erc1155UriResponse, Erc20Contract,
+    Erc721ApprovedResponse, Erc721Contract, Erc721OwnerResponse, EvmAddressResponse,
+    ExchangeRatesResponse, GetOrderByIdResponse, GetOrdersResponse, OracleExchangeRate,
+    OraclePriceOptions, OracleTwapsResponse, Order, OrderBuilder, OrderSimulationResponse,
+    OrderStatus, OrderType, PositionDirection, PositionsResponse, SeiAddressResponse, SeiError,
+    SeiMsg, SeiQuerier, SeiQuery, SeiQueryWrapper, SeiRoute, StaticCallResponse,
+    SudoMsg as SeiSudoMsg, UnsuccessfulOrder,
 };
 use sei_integration_tests::{
-    helper::{get_balance, mock_app},
-    module::{SeiModule, EVM_ADDRESS, SEI_ADDRESS},
+    address::AddressAssociations,
+    dex::{BookOrder, OrderBook, DEX_MODULE_ADDRESS},
+    evm::{evm_address_for, MockErc1155, MockErc20, MockErc721, MockRoyalty},
+    helper::{
+        advance_epoch, end_block, get_balance, mock_app, next_block, replay_oracle_rates,
+        update_oracle_rate, SeiApp, SeiRouter,
+    },
+    module::{EVM_ADDRESS, SEI_ADDRESS},
+    oracle::{parse_rate_updates_csv, parse_rate_updates_json, OracleRateUpdate},
 };
 use sei_tester::{
     contract::{execute, instantiate, query},
-    msg::{InstantiateMsg, QueryMsg},
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
 };
 
 const ADMIN: &str = "admin";
 const NATIVE_DENOM: &str = "usei";
 
 /// Init balances via bank
-
-fn init_default_balances(
-    router: &mut Router<
-        BankKeeper,
-        SeiModule,
-        WasmKeeper<SeiMsg, SeiQueryWrapper>,
-        StakeKeeper,
-        DistributionKeeper,
-        FailingModule<IbcMsg, IbcQuery, Empty>,
-        FailingModule<GovMsg, Empty, Empty>,
-    >,
-    _api: &dyn Api,
-    storage: &mut dyn Storage,
-) {
+fn init_default_balances(router: &mut SeiRouter, _api: &dyn Api, storage: &mut dyn Storage) {
     router
         .bank
         .init_balance(
@@ -92,41 +89,25 @@ fn init_default_balances(
 }
 
 /// Helper for setting up test
-
-fn setup_test(
-    app: &mut App<
-        BankKeeper,
-        MockApi,
-        MockStorage,
-        SeiModule,
-        WasmKeeper<SeiMsg, SeiQueryWrapper>,
-        StakeKeeper,
-        DistributionKeeper,
-        FailingModule<IbcMsg, IbcQuery, Empty>,
-        FailingModule<GovMsg, Empty, Empty>,
-    >,
-) -> Addr {
+fn setup_test(app: &mut SeiApp) -> Addr {
     let sei_tester_code = app.store_code(Box::new(
         ContractWrapper::new(execute, instantiate, query)
             .with_reply(sei_tester::contract::reply)
             .with_sudo(sei_tester::contract::sudo),
     )); //::<SeiMsg, SeiQueryWrapper>
 
-    let sei_tester_addr = app
-        .instantiate_contract(
-            sei_tester_code,
-            Addr::unchecked(ADMIN),
-            &InstantiateMsg {},
-            &[],
-            "sei_tester",
-            Some(ADMIN.to_string()),
-        )
-        .unwrap();
-
-    sei_tester_addr
+    app.instantiate_contract(
+        sei_tester_code,
+        Addr::unchecked(ADMIN),
+        &InstantiateMsg {},
+        &[],
+        "sei_tester",
+        Some(ADMIN.to_string()),
+    )
+    .unwrap()
 }
 
-/// Basic msg examples
+// Basic msg examples
 
 /// Token Factory
 #[test]
@@ -178,7 +159,7 @@ fn test_tokenfactory_integration_foundation() {
 
     assert_eq!(
         err.to_string(),
-        "Must be owner of coin factory denom to mint".to_string()
+        "tokenfactory: must be owner of coin factory denom to mint".to_string()
     );
 
     app.execute_multi(
@@ -196,6 +177,27 @@ fn test_tokenfactory_integration_foundation() {
     assert_eq!(res.amount.amount, Uint128::new(0));
     assert_eq!(res.amount.denom, out);
 
+    // Burning more than the balance fails
+    let err = app
+        .execute_multi(
+            Addr::unchecked(ADMIN),
+            vec![CosmosMsg::Custom(SeiMsg::BurnTokens {
+                amount: Coin {
+                    denom: out.to_string(),
+                    amount: Uint128::new(1),
+                },
+            })],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<SeiError>(),
+        Some(&SeiError::InsufficientFunds {
+            denom: out.to_string(),
+            required: Uint128::new(1),
+            provided: Uint128::zero(),
+        })
+    );
+
     let err = app
         .execute_multi(
             Addr::unchecked("fake"),
@@ -211,8 +213,193 @@ fn test_tokenfactory_integration_foundation() {
 
     assert_eq!(
         err.to_string(),
-        "Must be owner of coin factory denom to burn".to_string()
+        "tokenfactory: must be owner of coin factory denom to burn".to_string()
+    );
+}
+
+/// Token Factory - admin transfer, metadata and creator index
+#[test]
+fn test_tokenfactory_admin_and_metadata() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    let sei_tester_addr = setup_test(&mut app);
+    let denom = format!("factory/{}/subdenom", sei_tester_addr);
+    let new_admin = "sei1hjfwcza3e3uzeznf3qthhakdr9juetl7g6esl4";
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        sei_tester_addr.clone(),
+        &ExecuteMsg::CreateDenom {},
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        sei_tester_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &[],
+    )
+    .unwrap();
+    let res: BalanceResponse = get_balance(&app, ADMIN.to_string(), denom.clone());
+    assert_eq!(res.amount.amount, Uint128::new(100));
+
+    let res: DenomsFromCreatorResponse = app
+        .wrap()
+        .query_wasm_smart(
+            sei_tester_addr.clone(),
+            &QueryMsg::GetDenomsFromCreator {
+                creator: sei_tester_addr.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.denoms, vec![denom.clone()]);
+
+    // Bank metadata is only available once set by the admin
+    let err = app
+        .wrap()
+        .query::<DenomMetadataResponse>(&QueryRequest::Bank(BankQuery::DenomMetadata {
+            denom: denom.clone(),
+        }))
+        .unwrap_err();
+    assert!(err.to_string().contains("not found"));
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        sei_tester_addr.clone(),
+        &ExecuteMsg::SetMetadata {},
+        &[],
+    )
+    .unwrap();
+    let res: DenomMetadataResponse = app
+        .wrap()
+        .query(&QueryRequest::Bank(BankQuery::DenomMetadata {
+            denom: denom.clone(),
+        }))
+        .unwrap();
+    assert_eq!(res.metadata.base, denom);
+    assert_eq!(res.metadata.symbol, "SUB");
+    assert_eq!(res.metadata.denom_units.len(), 2);
+
+    // Only the admin may transfer the denom
+    let err = app
+        .execute(
+            Addr::unchecked("fake"),
+            CosmosMsg::Custom(SeiMsg::ChangeAdmin {
+                denom: denom.clone(),
+                new_admin_address: "fake".to_string(),
+            }),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "tokenfactory: must be owner of coin factory denom to change admin"
     );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        sei_tester_addr.clone(),
+        &ExecuteMsg::ChangeAdmin {},
+        &[],
+    )
+    .unwrap();
+    let res: DenomAuthorityMetadataResponse = app
+        .wrap()
+        .query_wasm_smart(
+            sei_tester_addr.clone(),
+            &QueryMsg::GetDenomAuthorityMetadata {
+                denom: denom.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.authority_metadata.admin, new_admin);
+
+    // The creator lost its minting and metadata rights to the new admin
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        sei_tester_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &[],
+    )
+    .unwrap_err();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        sei_tester_addr.clone(),
+        &ExecuteMsg::SetMetadata {},
+        &[],
+    )
+    .unwrap_err();
+    app.execute(
+        Addr::unchecked(new_admin),
+        CosmosMsg::Custom(SeiMsg::MintTokens {
+            amount: coin(5, denom.clone()),
+        }),
+    )
+    .unwrap();
+    let res: BalanceResponse = get_balance(&app, new_admin.to_string(), denom.clone());
+    assert_eq!(res.amount.amount, Uint128::new(5));
+
+    // The denom stays indexed under its creator
+    let res: DenomsFromCreatorResponse = app
+        .wrap()
+        .query_wasm_smart(
+            sei_tester_addr.clone(),
+            &QueryMsg::GetDenomsFromCreator {
+                creator: sei_tester_addr.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.denoms, vec![denom]);
+}
+
+/// Module failures are reported as `SeiError`
+#[test]
+fn test_sei_errors() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    let sei_tester_addr = setup_test(&mut app);
+
+    let create_denom = CosmosMsg::Custom(SeiMsg::CreateDenom {
+        subdenom: "test".to_string(),
+    });
+    app.execute(Addr::unchecked(ADMIN), create_denom.clone())
+        .unwrap();
+    let err = app
+        .execute(Addr::unchecked(ADMIN), create_denom)
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<SeiError>(),
+        Some(&SeiError::DenomAlreadyExists {
+            denom: "factory/admin/test".to_string()
+        })
+    );
+
+    // Failures of messages sent by a contract keep their type
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            sei_tester_addr.clone(),
+            &call_evm_msg(ERC20_ADDRESS, "totalSupply()", &[]),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().downcast_ref::<SeiError>(),
+        Some(&SeiError::EvmRevert {
+            reason: format!("no contract deployed at {}", ERC20_ADDRESS)
+        })
+    );
+
+    // Contracts recover the module error from failed queries
+    let err = app
+        .wrap()
+        .query_wasm_smart::<GetOrderByIdResponse>(
+            sei_tester_addr.clone(),
+            &QueryMsg::GetOrderById {
+                contract_address: sei_tester_addr.to_string(),
+                price_denom: "USDC".to_string(),
+                asset_denom: "ATOM".to_string(),
+                id: 42,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(SeiError::from(err), SeiError::OrderNotFound { id: 42 });
 }
 
 /// Epoch Module - query
@@ -228,12 +415,113 @@ fn test_epoch_query() {
         .unwrap();
 
     assert_eq!(res.epoch.genesis_time, "".to_string());
-    assert_eq!(res.epoch.duration, 60);
+    assert_eq!(res.epoch.duration, 60_000_000_000);
     assert_eq!(res.epoch.current_epoch, 1);
     assert_eq!(res.epoch.current_epoch_start_time, "".to_string());
     assert_eq!(res.epoch.current_epoch_height, 1);
 }
 
+/// Epoch Module - epochs advance with block time once the epoch has a start time
+#[test]
+fn test_epoch_advances_with_block_time() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    let sei_tester_addr = setup_test(&mut app);
+    let query_epoch = |app: &SeiApp| -> Epoch {
+        let res: EpochResponse = app
+            .wrap()
+            .query_wasm_smart(sei_tester_addr.clone(), &QueryMsg::Epoch {})
+            .unwrap();
+        res.epoch
+    };
+
+    // Without a start time the genesis epoch does not advance
+    next_block(&mut app).unwrap();
+    assert_eq!(query_epoch(&app).current_epoch, 1);
+
+    let block = app.block_info();
+    let epoch = Epoch {
+        genesis_time: format_rfc3339(block.time),
+        duration: 12_000_000_000,
+        current_epoch: 1,
+        current_epoch_start_time: format_rfc3339(block.time),
+        current_epoch_height: block.height as i64,
+    };
+    app.init_modules(|router, _, _| router.custom = router.custom.set_epoch(epoch.clone()));
+    assert_eq!(query_epoch(&app), epoch);
+
+    // Blocks are 5 seconds apart, the epoch of 12 seconds ends during the third block
+    next_block(&mut app).unwrap();
+    next_block(&mut app).unwrap();
+    assert_eq!(query_epoch(&app), epoch);
+    assert_eq!(
+        epoch.next_epoch_time().unwrap(),
+        block.time.plus_seconds(12)
+    );
+    next_block(&mut app).unwrap();
+    let res = query_epoch(&app);
+    assert_eq!(res.current_epoch, 2);
+    assert_eq!(
+        res.current_epoch_start_time,
+        format_rfc3339(block.time.plus_seconds(12))
+    );
+    assert_eq!(res.current_epoch_height, block.height as i64 + 3);
+    assert_eq!(res.genesis_time, epoch.genesis_time);
+
+    // Several epochs can pass between blocks
+    app.update_block(|b| {
+        b.time = b.time.plus_seconds(60);
+        b.height += 12;
+    });
+    let res = query_epoch(&app);
+    assert_eq!(res.current_epoch, 7);
+    assert_eq!(res.epoch_at(app.block_info().time).unwrap(), 7);
+    assert_eq!(res.start_timestamp().unwrap(), block.time.plus_seconds(72));
+    assert_eq!(res.current_epoch_height, block.height as i64 + 15);
+
+    // Dex settlements are sent with the same epoch
+    let now = app.block_info();
+    assert_eq!(
+        app.init_modules(|router, _, _| router.custom.epoch_at(&now)),
+        res
+    );
+}
+
+/// Queries are rejected unless sent to the route of their module
+#[test]
+fn test_query_routes() {
+    let app = mock_app(init_default_balances, vec![]);
+
+    let query = SeiQuery::Erc20Balance {
+        contract_address: "0x1000000000000000000000000000000000000001".to_string(),
+        account: SEI_ADDRESS.to_string(),
+    };
+    assert_eq!(query.route(), SeiRoute::Evm);
+    assert_eq!(
+        SeiQueryWrapper::from(SeiQuery::Epoch {}).route,
+        SeiRoute::Epoch
+    );
+
+    let res: EpochResponse = app.wrap().query(&SeiQuery::Epoch {}.into()).unwrap();
+    assert_eq!(res.epoch.current_epoch, 1);
+
+    let err = app
+        .wrap()
+        .query::<EpochResponse>(&QueryRequest::Custom(SeiQueryWrapper {
+            route: SeiRoute::Oracle,
+            query_data: SeiQuery::Epoch {},
+        }))
+        .unwrap_err();
+    assert!(err.to_string().contains("Unknown Sei Oracle Query"));
+    let err = app
+        .wrap()
+        .query::<cw20::BalanceResponse>(&QueryRequest::Custom(SeiQueryWrapper {
+            route: SeiRoute::Tokenfactory,
+            query_data: query,
+        }))
+        .unwrap_err();
+    assert!(err.to_string().contains("Unknown Sei TokenFactory Query"));
+}
+
 /// Dex Module - place and get orders
 #[test]
 fn test_dex_module_integration_orders() {
@@ -256,15 +544,16 @@ fn test_dex_module_integration_orders() {
     let status_description = "order1".to_string();
 
     let order1: Order = Order {
-        price: price,
-        quantity: quantity,
+        price,
+        quantity,
         price_denom: price_denom.clone(),
         asset_denom: asset_denom.clone(),
-        order_type: order_type,
-        position_direction: position_direction,
-        data: data, // serialized order data, defined by the specific target contract
-        status_description: status_description,
+        order_type,
+        position_direction,
+        data, // serialized order data, defined by the specific target contract
+        status_description,
         nominal: Decimal::zero(),
+        trigger_price: None,
     };
     orders.push(order1);
 
@@ -288,6 +577,7 @@ fn test_dex_module_integration_orders() {
         data: data2, // serialized order data, defined by the specific target contract
         status_description: status_description2,
         nominal: Decimal::zero(),
+        trigger_price: None,
     };
     orders.push(order2);
 
@@ -301,8 +591,8 @@ fn test_dex_module_integration_orders() {
         .execute_multi(
             Addr::unchecked(ADMIN),
             vec![CosmosMsg::Custom(SeiMsg::PlaceOrders {
-                orders: orders,
-                funds: funds,
+                orders,
+                funds,
                 contract_address: Addr::unchecked(&contract_addr),
             })],
         )
@@ -319,7 +609,7 @@ fn test_dex_module_integration_orders() {
             sei_tester_addr.clone(),
             &QueryMsg::GetOrders {
                 contract_address: contract_addr.to_string(),
-                account: sei_tester_addr.to_string(),
+                account: ADMIN.to_string(),
             },
         )
         .unwrap();
@@ -415,8 +705,7 @@ fn test_dex_module_integration_orders() {
     assert!(error.is_some());
 
     // CancelOrders for a contract address that doesn't exist
-    let mut nonexistent_order_ids: Vec<u64> = Vec::new();
-    nonexistent_order_ids.push(3);
+    let nonexistent_order_ids: Vec<u64> = vec![3];
     let cancellations: Vec<Cancellation> = nonexistent_order_ids
         .iter()
         .map(|id| -> Cancellation {
@@ -434,7 +723,7 @@ fn test_dex_module_integration_orders() {
     let res = app.execute_multi(
         Addr::unchecked(ADMIN),
         vec![CosmosMsg::Custom(SeiMsg::CancelOrders {
-            cancellations: cancellations,
+            cancellations,
             contract_address: Addr::unchecked("fake_contract_addr".to_string()),
         })],
     );
@@ -442,8 +731,7 @@ fn test_dex_module_integration_orders() {
     assert!(error.is_some());
 
     // CancelOrders for order id 1
-    let mut cancel_order_ids: Vec<u64> = Vec::new();
-    cancel_order_ids.push(0);
+    let cancel_order_ids: Vec<u64> = vec![0];
     let cancellations: Vec<Cancellation> = cancel_order_ids
         .iter()
         .map(|id| -> Cancellation {
@@ -462,7 +750,7 @@ fn test_dex_module_integration_orders() {
         .execute_multi(
             Addr::unchecked(ADMIN),
             vec![CosmosMsg::Custom(SeiMsg::CancelOrders {
-                cancellations: cancellations,
+                cancellations,
                 contract_address: Addr::unchecked(&contract_addr),
             })],
         )
@@ -479,7 +767,7 @@ fn test_dex_module_integration_orders() {
             sei_tester_addr.clone(),
             &QueryMsg::GetOrders {
                 contract_address: contract_addr.to_string(),
-                account: sei_tester_addr.to_string(),
+                account: ADMIN.to_string(),
             },
         )
         .unwrap();
@@ -494,6 +782,29 @@ fn test_dex_module_integration_orders() {
     assert_eq!(res.orders[0].order_type, order_type2);
     assert_eq!(res.orders[0].position_direction, position_direction2);
 
+    // CancelOrders for order id 0 again
+    let err = app
+        .execute_multi(
+            Addr::unchecked(ADMIN),
+            vec![CosmosMsg::Custom(SeiMsg::CancelOrders {
+                cancellations: vec![Cancellation {
+                    id: 0,
+                    contract_address: contract_addr.to_string(),
+                    price: Decimal::zero(),
+                    price_denom: "pd".to_string(),
+                    asset_denom: "ad".to_string(),
+                    order_type: OrderType::Limit,
+                    position_direction: PositionDirection::Long,
+                }],
+                contract_address: Addr::unchecked(&contract_addr),
+            })],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<SeiError>(),
+        Some(&SeiError::OrderNotFound { id: 0 })
+    );
+
     // Query GetOrderById for order id 0 (doesn't exist)
     let res: Result<GetOrderByIdResponse, StdError> = app.wrap().query_wasm_smart(
         sei_tester_addr.clone(),
@@ -528,15 +839,16 @@ fn test_dex_module_query_order_simulation() {
     let status_description = "order1".to_string();
 
     let order1: Order = Order {
-        price: price,
-        quantity: quantity,
+        price,
+        quantity,
         price_denom: price_denom.clone(),
         asset_denom: asset_denom.clone(),
-        order_type: order_type,
-        position_direction: position_direction,
-        data: data, // serialized order data, defined by the specific target contract
-        status_description: status_description,
+        order_type,
+        position_direction,
+        data, // serialized order data, defined by the specific target contract
+        status_description,
         nominal: Decimal::zero(),
+        trigger_price: None,
     };
     orders.push(order1);
 
@@ -560,6 +872,7 @@ fn test_dex_module_query_order_simulation() {
         data: data2, // serialized order data, defined by the specific target contract
         status_description: status_description2,
         nominal: Decimal::zero(),
+        trigger_price: None,
     };
     orders.push(order2);
 
@@ -571,9 +884,9 @@ fn test_dex_module_query_order_simulation() {
     app.execute_multi(
         Addr::unchecked(ADMIN),
         vec![CosmosMsg::Custom(SeiMsg::PlaceOrders {
-            orders: orders,
-            funds: funds,
-            contract_address: Addr::unchecked(&sei_tester_addr.to_string()),
+            orders,
+            funds,
+            contract_address: Addr::unchecked(sei_tester_addr.to_string()),
         })],
     )
     .unwrap();
@@ -595,6 +908,7 @@ fn test_dex_module_query_order_simulation() {
                     data: "".to_string(),
                     status_description: "test_order".to_string(),
                     nominal: Decimal::zero(),
+                    trigger_price: None,
                 },
             },
         }))
@@ -606,7 +920,7 @@ fn test_dex_module_query_order_simulation() {
 
     assert_eq!(res, expected_order_sim_res);
 
-    // Test part of sim order can be fulfilled
+    // Test part of sim order can be fulfilled, by the limit order only
     let res: OrderSimulationResponse = app
         .wrap()
         .query(&QueryRequest::Custom(SeiQueryWrapper {
@@ -623,13 +937,14 @@ fn test_dex_module_query_order_simulation() {
                     data: "".to_string(),
                     status_description: "test_order".to_string(),
                     nominal: Decimal::zero(),
+                    trigger_price: None,
                 },
             },
         }))
         .unwrap();
 
     let expected_order_sim_res = OrderSimulationResponse {
-        executed_quantity: Decimal::raw(6000),
+        executed_quantity: Decimal::raw(5000),
     };
 
     assert_eq!(res, expected_order_sim_res);
@@ -651,6 +966,7 @@ fn test_dex_module_query_order_simulation() {
                     data: "".to_string(),
                     status_description: "test_order".to_string(),
                     nominal: Decimal::zero(),
+                    trigger_price: None,
                 },
             },
         }))
@@ -679,6 +995,7 @@ fn test_dex_module_query_order_simulation() {
                     data: "".to_string(),
                     status_description: "test_order".to_string(),
                     nominal: Decimal::zero(),
+                    trigger_price: None,
                 },
             },
         }))
@@ -691,55 +1008,340 @@ fn test_dex_module_query_order_simulation() {
     assert_eq!(res, expected_order_sim_res);
 }
 
-/// Oracle Module - set and query exchange rates
+/// Paginated GetOrders, DenomsFromCreator and ExchangeRates queries
 #[test]
-fn test_oracle_module_query_exchange_rate() {
+fn test_paginated_queries() {
+    let rate = |denom: &str| DenomOracleExchangeRatePair {
+        denom: denom.to_string(),
+        oracle_exchange_rate: OracleExchangeRate {
+            exchange_rate: Decimal::one(),
+            last_update: Uint64::zero(),
+            last_update_timestamp: 0,
+        },
+    };
+    let mut app = mock_app(
+        init_default_balances,
+        vec![rate("uusdc"), rate("usei"), rate("uatom")],
+    );
+    let contract_addr = Addr::unchecked("example_contract");
+
+    for subdenom in ["c", "a", "b"] {
+        app.execute(
+            Addr::unchecked(ADMIN),
+            CosmosMsg::Custom(SeiMsg::CreateDenom {
+                subdenom: subdenom.to_string(),
+            }),
+        )
+        .unwrap();
+    }
+    let order = Order {
+        price: Decimal::one(),
+        quantity: Decimal::one(),
+        price_denom: "USDC".to_string(),
+        asset_denom: "ATOM".to_string(),
+        order_type: OrderType::Limit,
+        position_direction: PositionDirection::Long,
+        data: "".to_string(),
+        status_description: "".to_string(),
+        nominal: Decimal::zero(),
+        trigger_price: None,
+    };
+    // Orders 0, 1 and 3 of the admin, 2 of bob
+    for (account, count) in [(ADMIN, 2), ("bob", 1), (ADMIN, 1)] {
+        app.execute(
+            Addr::unchecked(account),
+            CosmosMsg::Custom(SeiMsg::PlaceOrders {
+                orders: vec![order.clone(); count],
+                funds: vec![],
+                contract_address: contract_addr.clone(),
+            }),
+        )
+        .unwrap();
+    }
+
+    let wrapper = app.wrap();
+    let querier = SeiQuerier::new(&wrapper);
+
+    // Pages
+    let res = querier.query_exchange_rates_page(None, Some(2)).unwrap();
+    let denoms: Vec<String> = res
+        .denom_oracle_exchange_rate_pairs
+        .into_iter()
+        .map(|r| r.denom)
+        .collect();
+    assert_eq!(denoms, vec!["uatom", "usei"]);
+    assert_eq!(res.next_key, Some("usei".to_string()));
+    let res = querier
+        .query_exchange_rates_page(res.next_key, Some(2))
+        .unwrap();
+    assert_eq!(res.denom_oracle_exchange_rate_pairs.len(), 1);
+    assert_eq!(res.next_key, None);
+
+    let res = querier
+        .query_get_orders_page(
+            contract_addr.clone(),
+            Addr::unchecked(ADMIN),
+            Some(0),
+            Some(1),
+        )
+        .unwrap();
+    assert_eq!(res.orders.len(), 1);
+    assert_eq!(res.orders[0].id, 1);
+    assert_eq!(res.next_key, Some(1));
+    // Orders of other accounts are skipped before paginating
+    let res = querier
+        .query_get_orders_page(
+            contract_addr.clone(),
+            Addr::unchecked(ADMIN),
+            res.next_key,
+            Some(1),
+        )
+        .unwrap();
+    assert_eq!(res.orders.len(), 1);
+    assert_eq!(res.orders[0].id, 3);
+    assert_eq!(res.next_key, None);
+    let res = querier
+        .query_get_orders(contract_addr.clone(), Addr::unchecked("bob"))
+        .unwrap();
+    let ids: Vec<u64> = res.orders.iter().map(|o| o.id).collect();
+    assert_eq!(ids, vec![2]);
+    assert_eq!(res.orders[0].account, "bob");
+
+    // Unpaginated queries return everything
+    let res = querier
+        .query_denoms_from_creator(Addr::unchecked(ADMIN))
+        .unwrap();
+    assert_eq!(res.denoms.len(), 3);
+    assert_eq!(res.next_key, None);
+
+    // Iterators walk all pages
+    let denoms: Vec<String> = querier
+        .denoms_from_creator_iter(Addr::unchecked(ADMIN), 2)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        denoms,
+        vec!["factory/admin/a", "factory/admin/b", "factory/admin/c"]
+    );
+    let ids: Vec<u64> = querier
+        .orders_iter(contract_addr, Addr::unchecked(ADMIN), 2)
+        .map(|o| o.unwrap().id)
+        .collect();
+    assert_eq!(ids, vec![0, 1, 3]);
+    assert_eq!(querier.exchange_rates_iter(1).count(), 3);
+
+    // A failing page ends the iteration with the error
+    let mut orders = querier.orders_iter(Addr::unchecked("unknown"), Addr::unchecked(ADMIN), 2);
+    assert_eq!(
+        orders.next().unwrap().unwrap_err(),
+        SeiError::OrdersNotFound {
+            contract_address: "unknown".to_string()
+        }
+    );
+    assert!(orders.next().is_none());
+}
+
+/// Oracle Module - prices with staleness checks and cross rates
+#[test]
+fn test_oracle_module_prices() {
+    let block_time = mock_app(init_default_balances, vec![]).block_info().time;
+    let rate =
+        |denom: &str, exchange_rate: Decimal, age_seconds: u64| DenomOracleExchangeRatePair {
+            denom: denom.to_string(),
+            oracle_exchange_rate: OracleExchangeRate {
+                exchange_rate,
+                last_update: Uint64::new(block_time.seconds() - age_seconds),
+                last_update_timestamp: block_time.minus_seconds(age_seconds).nanos() / 1_000_000,
+            },
+        };
     let app = mock_app(
         init_default_balances,
         vec![
-            DenomOracleExchangeRatePair {
-                denom: "uusdc".to_string(),
-                oracle_exchange_rate: OracleExchangeRate {
-                    exchange_rate: Decimal::percent(80),
-                    last_update: Uint64::zero(),
-                    last_update_timestamp: 0,
-                },
-            },
-            DenomOracleExchangeRatePair {
-                denom: "usei".to_string(),
-                oracle_exchange_rate: OracleExchangeRate {
-                    exchange_rate: Decimal::percent(70),
-                    last_update: Uint64::zero(),
-                    last_update_timestamp: 0,
-                },
-            },
-            DenomOracleExchangeRatePair {
-                denom: "uusdc".to_string(),
-                oracle_exchange_rate: OracleExchangeRate {
-                    exchange_rate: Decimal::percent(90),
-                    last_update: Uint64::new(1),
-                    last_update_timestamp: 0,
-                },
-            },
+            rate("usei", Decimal::percent(50), 10),
+            rate("uatom", Decimal::percent(1000), 600),
         ],
     );
+    let wrapper = app.wrap();
+    let querier = SeiQuerier::new(&wrapper);
 
-    let res: ExchangeRatesResponse = app
-        .wrap()
-        .query(&QueryRequest::Custom(SeiQueryWrapper {
-            route: SeiRoute::Oracle,
-            query_data: SeiQuery::ExchangeRates {},
-        }))
-        .unwrap();
+    let res = querier.query_exchange_rate("usei").unwrap();
+    assert_eq!(res.exchange_rate, Decimal::percent(50));
+    assert_eq!(
+        querier.query_exchange_rate("ubtc").unwrap_err(),
+        SeiError::ExchangeRateNotFound {
+            denom: "ubtc".to_string()
+        }
+    );
+    assert_eq!(
+        querier.query_price_in("uatom", "usei").unwrap(),
+        Decimal::percent(2000)
+    );
 
-    for rate in res.denom_oracle_exchange_rate_pairs {
-        match rate.denom.as_str() {
-            "usei" => {
-                assert_eq!(
-                    rate.oracle_exchange_rate,
-                    OracleExchangeRate {
-                        exchange_rate: Decimal::percent(70),
-                        last_update: Uint64::zero(),
+    // Exchange rates older than the max age are rejected
+    let options = OraclePriceOptions::new(block_time, 60);
+    assert_eq!(
+        querier.query_oracle_price("usei", &options).unwrap(),
+        Decimal::percent(50)
+    );
+    let stale = SeiError::StaleExchangeRate {
+        denom: "uatom".to_string(),
+        last_update_timestamp: block_time.minus_seconds(600).nanos() / 1_000_000,
+        max_age_seconds: 60,
+    };
+    assert_eq!(
+        querier.query_oracle_price("uatom", &options).unwrap_err(),
+        stale
+    );
+    assert_eq!(
+        querier
+            .query_checked_price_in("uatom", "usei", &options)
+            .unwrap_err(),
+        stale
+    );
+
+    // unless they can fall back to the TWAP
+    let options = options.with_twap_fallback(30);
+    assert_eq!(
+        querier.query_oracle_price("uatom", &options).unwrap(),
+        querier.query_oracle_twap("uatom", 30).unwrap()
+    );
+    assert_eq!(
+        querier
+            .query_checked_price_in("uatom", "usei", &options)
+            .unwrap(),
+        Decimal::percent(2000)
+    );
+}
+
+/// Oracle Module - exchange rates pushed during the test follow the block time
+#[test]
+fn test_oracle_module_price_timeline() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    let start = app.block_info().time.seconds();
+    let exchange_rate = |app: &SeiApp, denom: &str| {
+        let wrapper = app.wrap();
+        SeiQuerier::new(&wrapper).query_exchange_rate(denom)
+    };
+
+    update_oracle_rate(&mut app, "usei", Decimal::percent(50)).unwrap();
+    let rate = exchange_rate(&app, "usei").unwrap();
+    assert_eq!(rate.exchange_rate, Decimal::percent(50));
+    assert_eq!(rate.last_update, Uint64::new(start));
+    assert_eq!(rate.last_update_timestamp, start * 1_000);
+
+    // Replayed rates become visible once the block time reaches them
+    let history = format!(
+        "timestamp,denom,exchange_rate\n{t},usei,0.6\n{t},uatom,10\n",
+        t = start + 10
+    );
+    let updates = parse_rate_updates_csv(&history).unwrap();
+    assert_eq!(
+        updates[1],
+        OracleRateUpdate::new(start + 10, "uatom", Decimal::percent(1000))
+    );
+    assert_eq!(
+        parse_rate_updates_json(&format!(
+            r#"[{{"timestamp":{},"denom":"usei","exchange_rate":"0.6"}}]"#,
+            start + 10
+        ))
+        .unwrap(),
+        updates[..1]
+    );
+    replay_oracle_rates(&mut app, &updates).unwrap();
+
+    next_block(&mut app).unwrap();
+    assert_eq!(
+        exchange_rate(&app, "usei").unwrap().exchange_rate,
+        Decimal::percent(50)
+    );
+    assert_eq!(
+        exchange_rate(&app, "uatom").unwrap_err(),
+        SeiError::ExchangeRateNotFound {
+            denom: "uatom".to_string()
+        }
+    );
+
+    next_block(&mut app).unwrap();
+    assert_eq!(app.block_info().time.seconds(), start + 10);
+    assert_eq!(
+        exchange_rate(&app, "usei").unwrap().exchange_rate,
+        Decimal::percent(60)
+    );
+    assert_eq!(
+        exchange_rate(&app, "uatom").unwrap().exchange_rate,
+        Decimal::percent(1000)
+    );
+
+    // The TWAP covers the rates up to the current block
+    next_block(&mut app).unwrap();
+    next_block(&mut app).unwrap();
+    let wrapper = app.wrap();
+    let querier = SeiQuerier::new(&wrapper);
+    assert_eq!(
+        querier.query_oracle_twap("usei", 20).unwrap(),
+        Decimal::percent(55)
+    );
+    assert_eq!(
+        querier.query_oracle_twap("uatom", 20).unwrap(),
+        Decimal::percent(1000)
+    );
+
+    assert!(parse_rate_updates_csv("timestamp,denom\n1,usei").is_err());
+    assert!(parse_rate_updates_csv("timestamp,denom,exchange_rate\n1,usei,abc").is_err());
+}
+
+/// Oracle Module - set and query exchange rates
+#[test]
+fn test_oracle_module_query_exchange_rate() {
+    let app = mock_app(
+        init_default_balances,
+        vec![
+            DenomOracleExchangeRatePair {
+                denom: "uusdc".to_string(),
+                oracle_exchange_rate: OracleExchangeRate {
+                    exchange_rate: Decimal::percent(80),
+                    last_update: Uint64::zero(),
+                    last_update_timestamp: 0,
+                },
+            },
+            DenomOracleExchangeRatePair {
+                denom: "usei".to_string(),
+                oracle_exchange_rate: OracleExchangeRate {
+                    exchange_rate: Decimal::percent(70),
+                    last_update: Uint64::zero(),
+                    last_update_timestamp: 0,
+                },
+            },
+            DenomOracleExchangeRatePair {
+                denom: "uusdc".to_string(),
+                oracle_exchange_rate: OracleExchangeRate {
+                    exchange_rate: Decimal::percent(90),
+                    last_update: Uint64::new(1),
+                    last_update_timestamp: 0,
+                },
+            },
+        ],
+    );
+
+    let res: ExchangeRatesResponse = app
+        .wrap()
+        .query(&QueryRequest::Custom(SeiQueryWrapper {
+            route: SeiRoute::Oracle,
+            query_data: SeiQuery::ExchangeRates {
+                start_after: None,
+                limit: None,
+            },
+        }))
+        .unwrap();
+
+    for rate in res.denom_oracle_exchange_rate_pairs {
+        match rate.denom.as_str() {
+            "usei" => {
+                assert_eq!(
+                    rate.oracle_exchange_rate,
+                    OracleExchangeRate {
+                        exchange_rate: Decimal::percent(70),
+                        last_update: Uint64::zero(),
                         last_update_timestamp: 0,
                     }
                 );
@@ -789,6 +1391,15 @@ fn test_oracle_module_query_twaps() {
                     last_update_timestamp: 0,
                 },
             },
+            // Updated after the block time
+            DenomOracleExchangeRatePair {
+                denom: "uatom".to_string(),
+                oracle_exchange_rate: OracleExchangeRate {
+                    exchange_rate: Decimal::percent(60),
+                    last_update: Uint64::new(1_571_797_500),
+                    last_update_timestamp: 0,
+                },
+            },
         ],
     );
 
@@ -810,9 +1421,24 @@ fn test_oracle_module_query_twaps() {
             "uusdc" => {
                 assert_eq!(rate.twap, Decimal::percent(84),);
             }
+            "uatom" => {
+                assert_eq!(rate.twap, Decimal::percent(60),);
+            }
             _ => panic!("Unexpected denom"),
         }
     }
+
+    // Lookbacks reaching before the unix epoch
+    let res: OracleTwapsResponse = app
+        .wrap()
+        .query(&QueryRequest::Custom(SeiQueryWrapper {
+            route: SeiRoute::Oracle,
+            query_data: SeiQuery::OracleTwaps {
+                lookback_seconds: 2_000_000_000,
+            },
+        }))
+        .unwrap();
+    assert_eq!(res.oracle_twaps.len(), 3);
 }
 
 #[test]
@@ -833,27 +1459,28 @@ fn test_dex_module_query_dex_twap() {
     let status_description = "order1".to_string();
 
     let order1: Order = Order {
-        price: price,
-        quantity: quantity,
+        price,
+        quantity,
         price_denom: price_denom.clone(),
         asset_denom: asset_denom.clone(),
-        order_type: order_type,
-        position_direction: position_direction,
-        data: data, // serialized order data, defined by the specific target contract
-        status_description: status_description,
+        order_type,
+        position_direction,
+        data, // serialized order data, defined by the specific target contract
+        status_description,
         nominal: Decimal::zero(),
+        trigger_price: None,
     };
     orders.push(order1);
 
     app.execute_multi(
         Addr::unchecked(ADMIN),
         vec![CosmosMsg::Custom(SeiMsg::PlaceOrders {
-            orders: orders,
+            orders,
             funds: vec![Coin {
                 denom: "usei".to_string(),
                 amount: Uint128::new(10),
             }],
-            contract_address: Addr::unchecked(&sei_tester_addr.to_string()),
+            contract_address: Addr::unchecked(sei_tester_addr.to_string()),
         })],
     )
     .unwrap();
@@ -886,18 +1513,19 @@ fn test_dex_module_query_dex_twap() {
         data: data2, // serialized order data, defined by the specific target contract
         status_description: status_description2,
         nominal: Decimal::zero(),
+        trigger_price: None,
     };
     orders.push(order2);
 
     app.execute_multi(
         Addr::unchecked(ADMIN),
         vec![CosmosMsg::Custom(SeiMsg::PlaceOrders {
-            orders: orders,
+            orders,
             funds: vec![Coin {
                 denom: "usei".to_string(),
                 amount: Uint128::new(10),
             }],
-            contract_address: Addr::unchecked(&sei_tester_addr.to_string()),
+            contract_address: Addr::unchecked(sei_tester_addr.to_string()),
         })],
     )
     .unwrap();
@@ -913,7 +1541,7 @@ fn test_dex_module_query_dex_twap() {
         .query(&QueryRequest::Custom(SeiQueryWrapper {
             route: SeiRoute::Dex,
             query_data: SeiQuery::DexTwaps {
-                contract_address: Addr::unchecked(&sei_tester_addr.to_string()),
+                contract_address: Addr::unchecked(sei_tester_addr.to_string()),
                 lookback_seconds: 6,
             },
         }))
@@ -942,9 +1570,10 @@ fn test_static_call_query() {
     let mut app = mock_app(init_default_balances, vec![]);
     let sei_tester_addr = setup_test(&mut app);
 
-    let res: StaticCallResponse = app
+    // No mock contract is deployed at the address
+    let err = app
         .wrap()
-        .query_wasm_smart(
+        .query_wasm_smart::<StaticCallResponse>(
             sei_tester_addr.clone(),
             &QueryMsg::StaticCall {
                 from: SEI_ADDRESS.to_string(),
@@ -952,12 +1581,13 @@ fn test_static_call_query() {
                 data: "".to_string(),
             },
         )
-        .unwrap();
-
-    let expected_res = StaticCallResponse {
-        encoded_data: general_purpose::STANDARD.encode(b"static call response"),
-    };
-    assert_eq!(res, expected_res);
+        .unwrap_err();
+    assert_eq!(
+        SeiError::from(err),
+        SeiError::EvmRevert {
+            reason: format!("no contract deployed at {}", EVM_ADDRESS)
+        }
+    );
 }
 
 #[test]
@@ -1039,3 +1669,2088 @@ fn test_sei_address_query() {
     };
     assert_eq!(res, expected_res);
 }
+
+// EVM Module - mock ERC-20 and ERC-721 contracts
+
+const ERC20_ADDRESS: &str = "0x1000000000000000000000000000000000000001";
+const ERC721_ADDRESS: &str = "0x2000000000000000000000000000000000000002";
+const ERC1155_ADDRESS: &str = "0x3000000000000000000000000000000000000003";
+
+fn call_evm_msg(to: &str, signature: &str, args: &[AbiToken]) -> ExecuteMsg {
+    ExecuteMsg::CallEvm {
+        value: Uint128::zero(),
+        to: to.to_string(),
+        data: Binary::from(encode_function_call(signature, args).unwrap()).to_base64(),
+    }
+}
+
+fn query_evm<T: DeserializeOwned>(app: &SeiApp, query_data: SeiQuery) -> T {
+    app.wrap()
+        .query(&QueryRequest::Custom(SeiQueryWrapper {
+            route: SeiRoute::Evm,
+            query_data,
+        }))
+        .unwrap()
+}
+
+#[test]
+fn test_erc20_call_evm() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    let sei_tester_addr = setup_test(&mut app);
+
+    app.init_modules(|router, _, storage| {
+        router.custom.register_erc20(
+            storage,
+            ERC20_ADDRESS,
+            MockErc20::new("Test Token", "TST", 6).with_balance(sei_tester_addr.as_str(), 1000u128),
+        )
+    })
+    .unwrap();
+
+    let transfer = call_evm_msg(
+        ERC20_ADDRESS,
+        "transfer(address,uint256)",
+        &[
+            AbiToken::address(EVM_ADDRESS).unwrap(),
+            AbiToken::uint(400u128),
+        ],
+    );
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        sei_tester_addr.clone(),
+        &transfer,
+        &[],
+    )
+    .unwrap();
+
+    let res: cw20::BalanceResponse = query_evm(
+        &app,
+        SeiQuery::Erc20Balance {
+            contract_address: ERC20_ADDRESS.to_string(),
+            account: SEI_ADDRESS.to_string(),
+        },
+    );
+    assert_eq!(res.balance, Uint128::new(400));
+
+    let res: StaticCallResponse = app
+        .wrap()
+        .query_wasm_smart(
+            sei_tester_addr.clone(),
+            &QueryMsg::StaticCall {
+                from: SEI_ADDRESS.to_string(),
+                to: ERC20_ADDRESS.to_string(),
+                data: Binary::from(
+                    encode_function_call(
+                        "balanceOf(address)",
+                        &[AbiToken::address(EVM_ADDRESS).unwrap()],
+                    )
+                    .unwrap(),
+                )
+                .to_base64(),
+            },
+        )
+        .unwrap();
+    let tokens = res.decode(&[AbiType::Uint(256)]).unwrap();
+    assert_eq!(
+        tokens[0].clone().into_uint().unwrap(),
+        Uint256::from(400u128)
+    );
+
+    let res: cw20::TokenInfoResponse = query_evm(
+        &app,
+        SeiQuery::Erc20TokenInfo {
+            contract_address: ERC20_ADDRESS.to_string(),
+            caller: SEI_ADDRESS.to_string(),
+        },
+    );
+    assert_eq!(res.symbol, "TST");
+    assert_eq!(res.total_supply, Uint128::new(1000));
+
+    // Only 600 tokens left
+    let transfer = call_evm_msg(
+        ERC20_ADDRESS,
+        "transfer(address,uint256)",
+        &[
+            AbiToken::address(EVM_ADDRESS).unwrap(),
+            AbiToken::uint(601u128),
+        ],
+    );
+    let err = app
+        .execute_contract(Addr::unchecked(ADMIN), sei_tester_addr, &transfer, &[])
+        .unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("transfer amount exceeds balance"));
+}
+
+#[test]
+fn test_erc721_call_evm() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    let sei_tester_addr = setup_test(&mut app);
+    let sei_tester_evm_addr = evm_address_for(sei_tester_addr.as_str());
+
+    app.init_modules(|router, _, storage| {
+        router.custom.register_erc721(
+            storage,
+            ERC721_ADDRESS,
+            MockErc721::new("Test NFT", "NFT").with_token(
+                "1",
+                sei_tester_addr.as_str(),
+                "ipfs://1",
+            ),
+        )
+    })
+    .unwrap();
+
+    let res: Erc721OwnerResponse = query_evm(
+        &app,
+        SeiQuery::Erc721Owner {
+            caller: SEI_ADDRESS.to_string(),
+            contract_address: ERC721_ADDRESS.to_string(),
+            token_id: "1".to_string(),
+        },
+    );
+    assert_eq!(res.owner, sei_tester_addr.to_string());
+
+    // Approve the associated EVM address
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        sei_tester_addr.clone(),
+        &call_evm_msg(
+            ERC721_ADDRESS,
+            "approve(address,uint256)",
+            &[
+                AbiToken::address(EVM_ADDRESS).unwrap(),
+                AbiToken::uint(1u128),
+            ],
+        ),
+        &[],
+    )
+    .unwrap();
+    let res: Erc721ApprovedResponse = query_evm(
+        &app,
+        SeiQuery::Erc721Approved {
+            caller: SEI_ADDRESS.to_string(),
+            contract_address: ERC721_ADDRESS.to_string(),
+            token_id: "1".to_string(),
+        },
+    );
+    assert_eq!(res.approved, SEI_ADDRESS.to_string());
+
+    // Transfer the token, which clears the approval
+    let transfer = call_evm_msg(
+        ERC721_ADDRESS,
+        "transferFrom(address,address,uint256)",
+        &[
+            AbiToken::address(&sei_tester_evm_addr).unwrap(),
+            AbiToken::address(EVM_ADDRESS).unwrap(),
+            AbiToken::uint(1u128),
+        ],
+    );
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        sei_tester_addr.clone(),
+        &transfer,
+        &[],
+    )
+    .unwrap();
+
+    let res: Erc721OwnerResponse = query_evm(
+        &app,
+        SeiQuery::Erc721Owner {
+            caller: SEI_ADDRESS.to_string(),
+            contract_address: ERC721_ADDRESS.to_string(),
+            token_id: "1".to_string(),
+        },
+    );
+    assert_eq!(res.owner, SEI_ADDRESS.to_string());
+    let res: Erc721ApprovedResponse = query_evm(
+        &app,
+        SeiQuery::Erc721Approved {
+            caller: SEI_ADDRESS.to_string(),
+            contract_address: ERC721_ADDRESS.to_string(),
+            token_id: "1".to_string(),
+        },
+    );
+    assert_eq!(
+        res.approved,
+        "0x0000000000000000000000000000000000000000".to_string()
+    );
+
+    // The contract no longer owns the token
+    let err = app
+        .execute_contract(Addr::unchecked(ADMIN), sei_tester_addr, &transfer, &[])
+        .unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("transfer from incorrect owner"));
+}
+
+#[test]
+fn test_erc721_enumeration_and_royalties() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    app.init_modules(|router, _, storage| {
+        router.custom.register_erc721(
+            storage,
+            ERC721_ADDRESS,
+            MockErc721::new("Test NFT", "NFT")
+                .with_token("10", "alice", "ipfs://10")
+                .with_token("2", "bob", "ipfs://2")
+                .with_token("7", "alice", "ipfs://7")
+                .with_default_royalty(MockRoyalty::new(ADMIN, 500))
+                .with_royalty("7", MockRoyalty::new("charlie", 1000)),
+        )
+    })
+    .unwrap();
+    let wrapper = app.wrap();
+    let querier = SeiQuerier::new(&wrapper);
+    let erc721 = Erc721Contract::new(ERC721_ADDRESS);
+
+    assert_eq!(
+        erc721.total_supply(&querier, ADMIN).unwrap(),
+        Uint128::new(3)
+    );
+    let tokens: Vec<String> = (0..3)
+        .map(|index| erc721.token_by_index(&querier, ADMIN, index).unwrap())
+        .collect();
+    assert_eq!(tokens, vec!["2", "7", "10"]);
+    let err = querier
+        .erc721_token_by_index(ADMIN.to_string(), ERC721_ADDRESS.to_string(), 3)
+        .unwrap_err();
+    assert!(err.to_string().contains("global index out of bounds"));
+
+    assert_eq!(
+        erc721.balance_of(&querier, ADMIN, "alice").unwrap(),
+        Uint128::new(2)
+    );
+    let res = querier
+        .erc721_token_of_owner_by_index(
+            ADMIN.to_string(),
+            ERC721_ADDRESS.to_string(),
+            "alice".to_string(),
+            1,
+        )
+        .unwrap();
+    assert_eq!(res.token_id, "10");
+    let err = erc721
+        .token_of_owner_by_index(&querier, ADMIN, "bob", 1)
+        .unwrap_err();
+    assert!(err.to_string().contains("owner index out of bounds"));
+
+    let res = querier
+        .erc721_royalty_info(
+            ADMIN.to_string(),
+            ERC721_ADDRESS.to_string(),
+            "2".to_string(),
+            Uint128::new(1_000),
+        )
+        .unwrap();
+    assert_eq!(res.receiver, ADMIN);
+    assert_eq!(res.royalty_amount, Uint128::new(50));
+    let res = erc721
+        .royalty_info(&querier, ADMIN, "7", Uint128::new(1_000))
+        .unwrap();
+    assert_eq!(res.receiver, "charlie");
+    assert_eq!(res.royalty_amount, Uint128::new(100));
+
+    // The EVM functions return the same values
+    let tokens = querier
+        .static_call_typed(
+            SEI_ADDRESS.to_string(),
+            ERC721_ADDRESS.to_string(),
+            "royaltyInfo(uint256,uint256)",
+            &[AbiToken::uint(7u128), AbiToken::uint(1_000u128)],
+            &[AbiType::Address, AbiType::Uint(256)],
+        )
+        .unwrap();
+    assert_eq!(
+        tokens[0].clone().into_address().unwrap().to_lowercase(),
+        evm_address_for("charlie")
+    );
+    assert_eq!(
+        tokens[1].clone().into_uint().unwrap(),
+        Uint256::from(100u128)
+    );
+    let tokens = querier
+        .static_call_typed(
+            SEI_ADDRESS.to_string(),
+            ERC721_ADDRESS.to_string(),
+            "tokenOfOwnerByIndex(address,uint256)",
+            &[
+                AbiToken::address(&evm_address_for("alice")).unwrap(),
+                AbiToken::uint(0u128),
+            ],
+            &[AbiType::Uint(256)],
+        )
+        .unwrap();
+    assert_eq!(tokens[0].clone().into_uint().unwrap(), Uint256::from(7u128));
+}
+
+#[test]
+fn test_erc1155_call_evm() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    let sei_tester_addr = setup_test(&mut app);
+
+    app.init_modules(|router, _, storage| {
+        router.custom.register_erc1155(
+            storage,
+            ERC1155_ADDRESS,
+            MockErc1155::new("ipfs://{id}")
+                .with_balance("1", sei_tester_addr.as_str(), 100u128)
+                .with_balance("2", sei_tester_addr.as_str(), 5u128)
+                .with_uri("2", "ipfs://sword")
+                .with_default_royalty(MockRoyalty::new(ADMIN, 250)),
+        )
+    })
+    .unwrap();
+
+    // Transfer through the payload queries
+    let wrapper = app.wrap();
+    let querier = SeiQuerier::new(&wrapper);
+    let transfer = querier
+        .erc1155_transfer_payload(
+            sei_tester_addr.to_string(),
+            SEI_ADDRESS.to_string(),
+            "1".to_string(),
+            Uint128::new(40),
+        )
+        .unwrap();
+    let batch_transfer = querier
+        .erc1155_batch_transfer_payload(
+            sei_tester_addr.to_string(),
+            "alice".to_string(),
+            vec!["1".to_string(), "2".to_string()],
+            vec![Uint128::new(10), Uint128::new(5)],
+        )
+        .unwrap();
+    for payload in [transfer, batch_transfer] {
+        app.execute_contract(
+            Addr::unchecked(ADMIN),
+            sei_tester_addr.clone(),
+            &ExecuteMsg::CallEvm {
+                value: Uint128::zero(),
+                to: ERC1155_ADDRESS.to_string(),
+                data: payload.encoded_payload,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let res: Erc1155BalanceOfResponse = query_evm(
+        &app,
+        SeiQuery::Erc1155BalanceOf {
+            caller: SEI_ADDRESS.to_string(),
+            contract_address: ERC1155_ADDRESS.to_string(),
+            account: SEI_ADDRESS.to_string(),
+            token_id: "1".to_string(),
+        },
+    );
+    assert_eq!(res.balance, Uint128::new(40));
+    let res: Erc1155BalanceOfBatchResponse = query_evm(
+        &app,
+        SeiQuery::Erc1155BalanceOfBatch {
+            caller: SEI_ADDRESS.to_string(),
+            contract_address: ERC1155_ADDRESS.to_string(),
+            accounts: vec![
+                sei_tester_addr.to_string(),
+                "alice".to_string(),
+                "alice".to_string(),
+                sei_tester_addr.to_string(),
+            ],
+            token_ids: vec![
+                "1".to_string(),
+                "1".to_string(),
+                "2".to_string(),
+                "2".to_string(),
+            ],
+        },
+    );
+    assert_eq!(
+        res.balances,
+        vec![
+            Uint128::new(50),
+            Uint128::new(10),
+            Uint128::new(5),
+            Uint128::zero()
+        ]
+    );
+
+    // Static call of the same function
+    let res: StaticCallResponse = query_evm(
+        &app,
+        SeiQuery::StaticCall {
+            from: SEI_ADDRESS.to_string(),
+            to: ERC1155_ADDRESS.to_string(),
+            data: Binary::from(
+                encode_function_call(
+                    "balanceOf(address,uint256)",
+                    &[
+                        AbiToken::address(EVM_ADDRESS).unwrap(),
+                        AbiToken::uint(1u128),
+                    ],
+                )
+                .unwrap(),
+            )
+            .to_base64(),
+        },
+    );
+    let tokens = res.decode(&[AbiType::Uint(256)]).unwrap();
+    assert_eq!(
+        tokens[0].clone().into_uint().unwrap(),
+        Uint256::from(40u128)
+    );
+
+    let res: Erc1155UriResponse = query_evm(
+        &app,
+        SeiQuery::Erc1155Uri {
+            caller: SEI_ADDRESS.to_string(),
+            contract_address: ERC1155_ADDRESS.to_string(),
+            token_id: "1".to_string(),
+        },
+    );
+    assert_eq!(res.uri, "ipfs://{id}");
+    let res: Erc1155UriResponse = query_evm(
+        &app,
+        SeiQuery::Erc1155Uri {
+            caller: SEI_ADDRESS.to_string(),
+            contract_address: ERC1155_ADDRESS.to_string(),
+            token_id: "2".to_string(),
+        },
+    );
+    assert_eq!(res.uri, "ipfs://sword");
+
+    let res: Erc1155RoyaltyInfoResponse = query_evm(
+        &app,
+        SeiQuery::Erc1155RoyaltyInfo {
+            caller: SEI_ADDRESS.to_string(),
+            contract_address: ERC1155_ADDRESS.to_string(),
+            token_id: "1".to_string(),
+            sale_price: Uint128::new(10_000),
+        },
+    );
+    assert_eq!(res.receiver, ADMIN);
+    assert_eq!(res.royalty_amount, Uint128::new(250));
+
+    // The sender is neither the owner nor an operator of the owner
+    let transfer = call_evm_msg(
+        ERC1155_ADDRESS,
+        "safeTransferFrom(address,address,uint256,uint256,bytes)",
+        &[
+            AbiToken::address(EVM_ADDRESS).unwrap(),
+            AbiToken::address(&evm_address_for("alice")).unwrap(),
+            AbiToken::uint(1u128),
+            AbiToken::uint(40u128),
+            AbiToken::Bytes(vec![]),
+        ],
+    );
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            sei_tester_addr.clone(),
+            &transfer,
+            &[],
+        )
+        .unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("caller is not token owner or approved"));
+
+    // Approve the contract as operator of the associated EVM address
+    let approve = SeiQuerier::new(&app.wrap())
+        .erc1155_set_approval_all_payload(sei_tester_addr.to_string(), true)
+        .unwrap();
+    app.execute(
+        Addr::unchecked(SEI_ADDRESS),
+        CosmosMsg::Custom(SeiMsg::CallEvm {
+            value: Uint128::zero(),
+            to: ERC1155_ADDRESS.to_string(),
+            data: approve.encoded_payload,
+        }),
+    )
+    .unwrap();
+    let res: Erc1155IsApprovedForAllResponse = query_evm(
+        &app,
+        SeiQuery::Erc1155IsApprovedForAll {
+            caller: SEI_ADDRESS.to_string(),
+            contract_address: ERC1155_ADDRESS.to_string(),
+            owner: SEI_ADDRESS.to_string(),
+            operator: sei_tester_addr.to_string(),
+        },
+    );
+    assert!(res.is_approved);
+
+    app.execute_contract(Addr::unchecked(ADMIN), sei_tester_addr, &transfer, &[])
+        .unwrap();
+    let res: Erc1155BalanceOfResponse = query_evm(
+        &app,
+        SeiQuery::Erc1155BalanceOf {
+            caller: SEI_ADDRESS.to_string(),
+            contract_address: ERC1155_ADDRESS.to_string(),
+            account: "alice".to_string(),
+            token_id: "1".to_string(),
+        },
+    );
+    assert_eq!(res.balance, Uint128::new(50));
+}
+
+#[test]
+fn test_erc_contract_handles() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    app.init_modules(|router, _, storage| {
+        router.custom.register_erc20(
+            storage,
+            ERC20_ADDRESS,
+            MockErc20::new("Test Token", "TST", 6).with_balance("alice", 1000u128),
+        )?;
+        router.custom.register_erc721(
+            storage,
+            ERC721_ADDRESS,
+            MockErc721::new("Test NFT", "NFT").with_token("1", "alice", "ipfs://1"),
+        )
+    })
+    .unwrap();
+    let erc20 = Erc20Contract::new(ERC20_ADDRESS);
+    let erc721 = Erc721Contract::new(ERC721_ADDRESS);
+
+    // ERC-20
+    let wrapper = app.wrap();
+    let querier = SeiQuerier::new(&wrapper);
+    let transfer = erc20
+        .transfer(&querier, SEI_ADDRESS, Uint128::new(300))
+        .unwrap();
+    let approve = erc20.approve(&querier, ADMIN, Uint128::new(500)).unwrap();
+    let transfer_from = erc20
+        .transfer_from(&querier, "alice", ADMIN, Uint128::new(200))
+        .unwrap();
+    assert_eq!(
+        transfer,
+        CosmosMsg::Custom(
+            SeiMsg::call_evm(
+                Uint128::zero(),
+                ERC20_ADDRESS,
+                "transfer(address,uint256)",
+                &[
+                    AbiToken::address(EVM_ADDRESS).unwrap(),
+                    AbiToken::uint(300u128),
+                ],
+            )
+            .unwrap()
+        )
+    );
+    app.execute_multi(Addr::unchecked("alice"), vec![transfer, approve])
+        .unwrap();
+    app.execute(Addr::unchecked(ADMIN), transfer_from).unwrap();
+
+    let wrapper = app.wrap();
+    let querier = SeiQuerier::new(&wrapper);
+    assert_eq!(erc20.balance(&querier, "alice").unwrap(), Uint128::new(500));
+    assert_eq!(
+        erc20.balance(&querier, SEI_ADDRESS).unwrap(),
+        Uint128::new(300)
+    );
+    assert_eq!(erc20.balance(&querier, ADMIN).unwrap(), Uint128::new(200));
+    assert_eq!(
+        erc20.allowance(&querier, "alice", ADMIN).unwrap(),
+        Uint128::new(300)
+    );
+    assert_eq!(erc20.token_info(&querier, ADMIN).unwrap().symbol, "TST");
+
+    // ERC-721
+    let approve = erc721.approve(&querier, ADMIN, "1").unwrap();
+    let set_approval = erc721
+        .set_approval_for_all(&querier, SEI_ADDRESS, true)
+        .unwrap();
+    app.execute_multi(Addr::unchecked("alice"), vec![approve, set_approval])
+        .unwrap();
+
+    let wrapper = app.wrap();
+    let querier = SeiQuerier::new(&wrapper);
+    assert_eq!(erc721.approved(&querier, ADMIN, "1").unwrap(), ADMIN);
+    assert!(erc721
+        .is_approved_for_all(&querier, ADMIN, "alice", SEI_ADDRESS)
+        .unwrap());
+    assert_eq!(erc721.token_uri(&querier, ADMIN, "1").unwrap(), "ipfs://1");
+    assert_eq!(erc721.name_symbol(&querier, ADMIN).unwrap().symbol, "NFT");
+
+    let transfer = erc721
+        .transfer_from(&querier, "alice", SEI_ADDRESS, "1")
+        .unwrap();
+    app.execute(Addr::unchecked(ADMIN), transfer).unwrap();
+
+    let wrapper = app.wrap();
+    let querier = SeiQuerier::new(&wrapper);
+    assert_eq!(erc721.owner_of(&querier, ADMIN, "1").unwrap(), SEI_ADDRESS);
+}
+
+#[test]
+fn test_address_association() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    let sei_tester_addr = setup_test(&mut app);
+
+    // Public key of the secp256k1 private key 1
+    let pubkey = Binary::from_base64("Anm+Zn753LusVaBilc6HCwcCm/zbLc4o2VnygVsW+BeY").unwrap();
+    let (sei_address, evm_address) = app
+        .init_modules(|router, _, storage| router.custom.associate_pubkey(storage, &pubkey))
+        .unwrap();
+    assert_eq!(evm_address, "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf");
+    let (prefix, data, _) = bech32::decode(&sei_address).unwrap();
+    assert_eq!(prefix, "sei");
+    assert_eq!(
+        Vec::<u8>::from_base32(&data).unwrap(),
+        hex_bytes("751e76e8199196d454941c45d1b3a323f1433bd6")
+    );
+
+    let res: EvmAddressResponse = app
+        .wrap()
+        .query_wasm_smart(
+            sei_tester_addr.clone(),
+            &QueryMsg::GetEvmAddressBySeiAddress {
+                sei_address: sei_address.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        EvmAddressResponse {
+            evm_address: evm_address.clone(),
+            associated: true,
+        }
+    );
+
+    let res: SeiAddressResponse = app
+        .wrap()
+        .query_wasm_smart(
+            sei_tester_addr.clone(),
+            &QueryMsg::GetSeiAddressByEvmAddress {
+                evm_address: evm_address.to_lowercase(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        SeiAddressResponse {
+            sei_address: sei_address.clone(),
+            associated: true,
+        }
+    );
+
+    // Mock EVM contracts resolve the associated address
+    app.init_modules(|router, _, storage| {
+        router.custom.register_erc20(
+            storage,
+            ERC20_ADDRESS,
+            MockErc20::new("Test Token", "TST", 6).with_balance(evm_address.as_str(), 1000u128),
+        )
+    })
+    .unwrap();
+    let res: cw20::BalanceResponse = query_evm(
+        &app,
+        SeiQuery::Erc20Balance {
+            contract_address: ERC20_ADDRESS.to_string(),
+            account: sei_address.clone(),
+        },
+    );
+    assert_eq!(res.balance, Uint128::new(1000));
+
+    // Associated addresses cannot be associated again, neither from genesis nor during a test
+    let other_evm_address = "0x1000000000000000000000000000000000000002";
+    app.init_modules(|router, _, storage| {
+        router
+            .custom
+            .associate_address(storage, &sei_address, &evm_address)
+    })
+    .unwrap();
+    for (sei, evm, address, associated_address) in [
+        (
+            sei_address.as_str(),
+            other_evm_address,
+            sei_address.as_str(),
+            evm_address.as_str(),
+        ),
+        (
+            "alice",
+            evm_address.as_str(),
+            evm_address.as_str(),
+            sei_address.as_str(),
+        ),
+        (SEI_ADDRESS, other_evm_address, SEI_ADDRESS, EVM_ADDRESS),
+    ] {
+        let err = app
+            .init_modules(|router, _, storage| router.custom.associate_address(storage, sei, evm))
+            .unwrap_err();
+        assert_eq!(
+            err.downcast::<SeiError>().unwrap(),
+            SeiError::AddressAlreadyAssociated {
+                address: address.to_string(),
+                associated_address: associated_address.to_string(),
+            }
+        );
+    }
+    let res: SeiAddressResponse = app
+        .wrap()
+        .query_wasm_smart(
+            sei_tester_addr,
+            &QueryMsg::GetSeiAddressByEvmAddress {
+                evm_address: other_evm_address.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(!res.associated);
+
+    // Genesis associations replace the previous associations of both addresses
+    let storage = MockStorage::new();
+    let mut associations = AddressAssociations::default();
+    associations.insert(SEI_ADDRESS.to_string(), EVM_ADDRESS.to_string());
+    associations.insert(SEI_ADDRESS.to_string(), other_evm_address.to_string());
+    assert_eq!(associations.sei_address(&storage, EVM_ADDRESS), None);
+    assert_eq!(
+        associations.sei_address(&storage, other_evm_address),
+        Some(SEI_ADDRESS.to_string())
+    );
+    associations.insert("alice".to_string(), other_evm_address.to_string());
+    assert_eq!(associations.evm_address(&storage, SEI_ADDRESS), None);
+}
+
+fn hex_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Dex Module - market orders only match limit orders
+#[test]
+fn test_dex_market_orders_match_limit_orders() {
+    let order = |id, order_type, position_direction, price: u64, quantity: u64| BookOrder {
+        id,
+        account: "alice".to_string(),
+        price: Decimal::from_ratio(price, 1u64),
+        quantity: Decimal::from_ratio(quantity, 1u64),
+        order_type,
+        position_direction,
+        nominal: Decimal::zero(),
+        trigger_price: Decimal::zero(),
+    };
+    let fills = |book: &mut OrderBook| -> Vec<(u64, u64, Decimal, Decimal)> {
+        book.match_orders()
+            .into_iter()
+            .map(|f| (f.long.id, f.short.id, f.price, f.quantity))
+            .collect()
+    };
+
+    // Market orders at any price do not cross each other
+    let mut book = OrderBook::new("USDC", "ATOM");
+    book.insert(order(0, OrderType::Market, PositionDirection::Long, 0, 2));
+    book.insert(order(1, OrderType::Market, PositionDirection::Short, 0, 2));
+    assert!(fills(&mut book).is_empty());
+    assert_eq!(book.last_price, None);
+    // nor are they counted by simulations
+    let limit_long = |price: u64| {
+        OrderBuilder::limit(
+            "USDC",
+            "ATOM",
+            Decimal::from_ratio(price, 1u64),
+            Decimal::from_ratio(2u64, 1u64),
+        )
+        .build()
+        .unwrap()
+    };
+    assert_eq!(book.simulate(&limit_long(20)), Decimal::zero());
+
+    // Each fills against the limit orders behind the other one, the older first
+    book.insert(order(2, OrderType::Limit, PositionDirection::Long, 9, 1));
+    book.insert(order(3, OrderType::Limit, PositionDirection::Short, 11, 3));
+    assert_eq!(
+        fills(&mut book),
+        vec![
+            (
+                0,
+                3,
+                Decimal::from_ratio(11u64, 1u64),
+                Decimal::from_ratio(2u64, 1u64)
+            ),
+            (2, 1, Decimal::from_ratio(9u64, 1u64), Decimal::one()),
+        ]
+    );
+    let left: Vec<(u64, Decimal)> = book
+        .longs
+        .iter()
+        .chain(&book.shorts)
+        .map(|o| (o.id, o.quantity))
+        .collect();
+    assert_eq!(left, vec![(1, Decimal::one()), (3, Decimal::one())]);
+    assert_eq!(book.simulate(&limit_long(20)), Decimal::one());
+}
+
+/// Dex Module - order book matching and settlement
+#[test]
+fn test_dex_module_order_matching() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    let sei_tester_addr = setup_test(&mut app);
+
+    app.init_modules(|router, _, storage| {
+        router.custom.register_dex_pair(
+            storage,
+            &sei_tester_addr,
+            DexPair {
+                price_denom: "USDC".to_string(),
+                asset_denom: "ATOM".to_string(),
+                price_tick_size: Decimal::percent(1),
+                quantity_tick_size: Decimal::one(),
+            },
+        )
+    })
+    .unwrap();
+
+    let order = |order_type, position_direction, price: u64, quantity: u64| Order {
+        price: Decimal::from_ratio(price, 1u64),
+        quantity: Decimal::from_ratio(quantity, 1u64),
+        price_denom: "USDC".to_string(),
+        asset_denom: "ATOM".to_string(),
+        order_type,
+        position_direction,
+        data: "".to_string(),
+        status_description: "".to_string(),
+        nominal: Decimal::zero(),
+        trigger_price: None,
+    };
+    let place_orders = |app: &mut SeiApp, orders: Vec<Order>| {
+        app.execute_multi(
+            Addr::unchecked(ADMIN),
+            vec![CosmosMsg::Custom(SeiMsg::PlaceOrders {
+                orders,
+                funds: vec![],
+                contract_address: sei_tester_addr.clone(),
+            })],
+        )
+    };
+
+    place_orders(
+        &mut app,
+        vec![
+            order(OrderType::Limit, PositionDirection::Short, 10, 5),
+            order(OrderType::Limit, PositionDirection::Short, 11, 5),
+            order(OrderType::Limit, PositionDirection::Long, 11, 7),
+        ],
+    )
+    .unwrap();
+
+    // Price off the tick size
+    let mut off_tick = order(OrderType::Limit, PositionDirection::Long, 10, 1);
+    off_tick.price = Decimal::permille(10_005);
+    let err = place_orders(&mut app, vec![off_tick]).unwrap_err();
+    assert!(err.root_cause().to_string().contains("price tick size"));
+
+    // The long order fills against both short orders at their prices
+    let block = app.block_info();
+    let settlements = app
+        .init_modules(|router, api, storage| router.custom.end_block(api, storage, router, &block))
+        .unwrap();
+    assert_eq!(settlements.len(), 1);
+    let (contract_address, SeiSudoMsg::Settlement { entries, .. }) = &settlements[0] else {
+        panic!("expected settlement");
+    };
+    assert_eq!(contract_address, &sei_tester_addr);
+    let fills: Vec<(u64, Decimal, Decimal)> = entries
+        .iter()
+        .map(|e| (e.order_id, e.quantity, e.execution_cost_or_proceed))
+        .collect();
+    assert_eq!(
+        fills,
+        vec![
+            (
+                2,
+                Decimal::from_ratio(5u64, 1u64),
+                Decimal::from_ratio(10u64, 1u64)
+            ),
+            (
+                0,
+                Decimal::from_ratio(5u64, 1u64),
+                Decimal::from_ratio(10u64, 1u64)
+            ),
+            (
+                2,
+                Decimal::from_ratio(2u64, 1u64),
+                Decimal::from_ratio(11u64, 1u64)
+            ),
+            (
+                1,
+                Decimal::from_ratio(2u64, 1u64),
+                Decimal::from_ratio(11u64, 1u64)
+            ),
+        ]
+    );
+    assert_eq!(entries[0].account, ADMIN);
+
+    let res: GetOrdersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            sei_tester_addr.clone(),
+            &QueryMsg::GetOrders {
+                contract_address: sei_tester_addr.to_string(),
+                account: ADMIN.to_string(),
+            },
+        )
+        .unwrap();
+    let statuses: Vec<(OrderStatus, Decimal)> =
+        res.orders.iter().map(|o| (o.status, o.quantity)).collect();
+    assert_eq!(
+        statuses,
+        vec![
+            (OrderStatus::Fulfilled, Decimal::from_ratio(5u64, 1u64)),
+            (OrderStatus::Placed, Decimal::from_ratio(3u64, 1u64)),
+            (OrderStatus::Fulfilled, Decimal::from_ratio(7u64, 1u64)),
+        ]
+    );
+
+    // A market order takes the remaining liquidity, its unfilled rest is cancelled
+    place_orders(
+        &mut app,
+        vec![order(OrderType::Market, PositionDirection::Long, 0, 10)],
+    )
+    .unwrap();
+    let res = end_block(&mut app).unwrap();
+    // BulkOrderPlacements and Settlement
+    assert_eq!(res.responses.len(), 2);
+
+    let book = app
+        .read_module(|router, _, storage| {
+            router
+                .custom
+                .order_book(storage, &sei_tester_addr, "USDC", "ATOM")
+        })
+        .unwrap()
+        .unwrap();
+    assert!(book.longs.is_empty());
+    assert!(book.shorts.is_empty());
+
+    let res: GetOrderByIdResponse = app
+        .wrap()
+        .query_wasm_smart(
+            sei_tester_addr.clone(),
+            &QueryMsg::GetOrderById {
+                contract_address: sei_tester_addr.to_string(),
+                price_denom: "USDC".to_string(),
+                asset_denom: "ATOM".to_string(),
+                id: 3,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.order.status, OrderStatus::Cancelled);
+}
+
+// Dex contract that rejects order 1 and tags the hooks it is called with
+/// Dex Module - fill-or-kill, by value and stop orders
+#[test]
+fn test_dex_module_order_types() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    let sei_tester_addr = setup_test(&mut app);
+
+    app.init_modules(|router, _, storage| {
+        router.custom.register_dex_pair(
+            storage,
+            &sei_tester_addr,
+            DexPair {
+                price_denom: "USDC".to_string(),
+                asset_denom: "ATOM".to_string(),
+                price_tick_size: Decimal::percent(1),
+                quantity_tick_size: Decimal::one(),
+            },
+        )
+    })
+    .unwrap();
+
+    let dec = |value: u64| Decimal::from_ratio(value, 1u64);
+    let place_orders = |app: &mut SeiApp, orders: Vec<Order>| {
+        app.execute_multi(
+            Addr::unchecked(ADMIN),
+            vec![CosmosMsg::Custom(SeiMsg::PlaceOrders {
+                orders,
+                funds: vec![],
+                contract_address: sei_tester_addr.clone(),
+            })],
+        )
+    };
+    let end_block_fills = |app: &mut SeiApp| {
+        let block = app.block_info();
+        let settlements = app
+            .init_modules(|router, api, storage| {
+                router.custom.end_block(api, storage, router, &block)
+            })
+            .unwrap();
+        settlements
+            .into_iter()
+            .flat_map(|(_, settlement)| match settlement {
+                SeiSudoMsg::Settlement { entries, .. } => entries,
+                _ => vec![],
+            })
+            .map(|e| {
+                (
+                    e.order_id,
+                    e.order_type,
+                    e.quantity,
+                    e.execution_cost_or_proceed,
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    let order_status = |app: &SeiApp, id: u64| {
+        let res: GetOrderByIdResponse = app
+            .wrap()
+            .query_wasm_smart(
+                sei_tester_addr.clone(),
+                &QueryMsg::GetOrderById {
+                    contract_address: sei_tester_addr.to_string(),
+                    price_denom: "USDC".to_string(),
+                    asset_denom: "ATOM".to_string(),
+                    id,
+                },
+            )
+            .unwrap();
+        (res.order.status, res.order.quantity)
+    };
+
+    place_orders(
+        &mut app,
+        vec![
+            OrderBuilder::limit("USDC", "ATOM", dec(10), dec(5))
+                .short()
+                .build()
+                .unwrap(),
+            OrderBuilder::limit("USDC", "ATOM", dec(11), dec(5))
+                .short()
+                .build()
+                .unwrap(),
+            // more than the book holds
+            OrderBuilder::fok_market("USDC", "ATOM", Decimal::zero(), dec(20))
+                .build()
+                .unwrap(),
+            // 5 ATOM at 10
+            OrderBuilder::fok_market_by_value("USDC", "ATOM", Decimal::zero(), dec(50))
+                .build()
+                .unwrap(),
+            // buy stop, placed once ATOM trades at 11
+            OrderBuilder::stop_loss("USDC", "ATOM", dec(11), Decimal::zero(), dec(2))
+                .build()
+                .unwrap(),
+        ],
+    )
+    .unwrap();
+
+    // Orders lacking the fields of their type are rejected
+    let mut stop_limit = OrderBuilder::limit("USDC", "ATOM", dec(12), dec(1))
+        .build()
+        .unwrap();
+    stop_limit.order_type = OrderType::StopLimit;
+    let err = place_orders(&mut app, vec![stop_limit]).unwrap_err();
+    assert_eq!(
+        err.root_cause().downcast_ref::<SeiError>(),
+        Some(&SeiError::InvalidOrder {
+            reason: "stop orders need a trigger price".to_string()
+        })
+    );
+
+    assert_eq!(
+        end_block_fills(&mut app),
+        vec![
+            (3, OrderType::FokMarketByValue, dec(5), dec(10)),
+            (0, OrderType::Limit, dec(5), dec(10)),
+        ]
+    );
+    assert_eq!(order_status(&app, 2), (OrderStatus::Cancelled, dec(20)));
+    assert_eq!(order_status(&app, 3).0, OrderStatus::Fulfilled);
+    assert_eq!(order_status(&app, 4), (OrderStatus::Placed, dec(2)));
+
+    // A fill at 11 triggers the stop loss order, which fills at the market
+    place_orders(
+        &mut app,
+        vec![OrderBuilder::limit("USDC", "ATOM", dec(11), dec(1))
+            .build()
+            .unwrap()],
+    )
+    .unwrap();
+    assert_eq!(
+        end_block_fills(&mut app),
+        vec![
+            (5, OrderType::Limit, dec(1), dec(11)),
+            (1, OrderType::Limit, dec(1), dec(11)),
+            (4, OrderType::StopLoss, dec(2), dec(11)),
+            (1, OrderType::Limit, dec(2), dec(11)),
+        ]
+    );
+    assert_eq!(order_status(&app, 4).0, OrderStatus::Fulfilled);
+    assert_eq!(order_status(&app, 1), (OrderStatus::Placed, dec(2)));
+
+    let book = app
+        .read_module(|router, _, storage| {
+            router
+                .custom
+                .order_book(storage, &sei_tester_addr, "USDC", "ATOM")
+        })
+        .unwrap()
+        .unwrap();
+    assert!(book.stops.is_empty());
+    assert_eq!(book.last_price, Some(dec(11)));
+}
+
+fn rejecting_sudo(
+    _deps: DepsMut<SeiQueryWrapper>,
+    _env: Env,
+    msg: SeiSudoMsg,
+) -> Result<Response<SeiMsg>, StdError> {
+    match msg {
+        SeiSudoMsg::BulkOrderCancellations { ids } => Ok(Response::new()
+            .add_attribute("hook", "cancellations")
+            .add_attribute("ids", format!("{:?}", ids))),
+        SeiSudoMsg::BulkOrderPlacements { orders, .. } => Ok(Response::new()
+            .add_attribute("hook", "placements")
+            .add_attribute("orders", orders.len().to_string())
+            .set_data(to_json_binary(&BulkOrderPlacementsResponse {
+                unsuccessful_orders: vec![UnsuccessfulOrder {
+                    id: 1,
+                    reason: "rejected".to_string(),
+                }],
+            })?)),
+        SeiSudoMsg::Settlement { .. } => Ok(Response::new().add_attribute("hook", "settlement")),
+    }
+}
+
+/// Dex Module - end block sudo hooks
+#[test]
+fn test_dex_module_end_block_hooks() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    let code_id = app.store_code(Box::new(
+        ContractWrapper::new(execute, instantiate, query).with_sudo(rejecting_sudo),
+    ));
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {},
+            &[],
+            "dex_contract",
+            None,
+        )
+        .unwrap();
+
+    let order = |position_direction| Order {
+        price: Decimal::one(),
+        quantity: Decimal::one(),
+        price_denom: "USDC".to_string(),
+        asset_denom: "ATOM".to_string(),
+        order_type: OrderType::Limit,
+        position_direction,
+        data: "".to_string(),
+        status_description: "".to_string(),
+        nominal: Decimal::zero(),
+        trigger_price: None,
+    };
+    app.execute_multi(
+        Addr::unchecked(ADMIN),
+        vec![
+            CosmosMsg::Custom(SeiMsg::PlaceOrders {
+                orders: vec![
+                    order(PositionDirection::Long),
+                    order(PositionDirection::Short),
+                    order(PositionDirection::Short),
+                ],
+                funds: vec![],
+                contract_address: contract_addr.clone(),
+            }),
+            CosmosMsg::Custom(SeiMsg::CancelOrders {
+                cancellations: vec![Cancellation {
+                    id: 2,
+                    contract_address: contract_addr.to_string(),
+                    price_denom: "USDC".to_string(),
+                    asset_denom: "ATOM".to_string(),
+                    order_type: OrderType::Limit,
+                    position_direction: PositionDirection::Short,
+                    price: Decimal::one(),
+                }],
+                contract_address: contract_addr.clone(),
+            }),
+        ],
+    )
+    .unwrap();
+
+    let height = app.block_info().height;
+    let res = next_block(&mut app).unwrap();
+    assert_eq!(app.block_info().height, height + 1);
+
+    // Cancellations, then placements; the only short order was rejected so nothing settles
+    let hooks: Vec<String> = res
+        .responses
+        .iter()
+        .map(|r| {
+            r.events
+                .iter()
+                .flat_map(|e| e.attributes.iter())
+                .find(|a| a.key == "hook")
+                .unwrap()
+                .value
+                .clone()
+        })
+        .collect();
+    assert_eq!(hooks, vec!["cancellations", "placements"]);
+    assert_eq!(
+        res.unsuccessful_orders,
+        vec![(
+            contract_addr.clone(),
+            UnsuccessfulOrder {
+                id: 1,
+                reason: "rejected".to_string(),
+            }
+        )]
+    );
+
+    let res: GetOrderByIdResponse = app
+        .wrap()
+        .query(&QueryRequest::Custom(SeiQueryWrapper {
+            route: SeiRoute::Dex,
+            query_data: SeiQuery::GetOrderById {
+                contract_address: contract_addr.clone(),
+                price_denom: "USDC".to_string(),
+                asset_denom: "ATOM".to_string(),
+                id: 1,
+            },
+        }))
+        .unwrap();
+    assert_eq!(res.order.status, OrderStatus::FailedToPlace);
+    assert_eq!(res.order.status_description, "rejected");
+
+    // Nothing happened in this block
+    let res = next_block(&mut app).unwrap();
+    assert!(res.responses.is_empty());
+
+    let epoch = advance_epoch(&mut app).unwrap();
+    let res: EpochResponse = app
+        .wrap()
+        .query(&QueryRequest::Custom(SeiQueryWrapper {
+            route: SeiRoute::Epoch,
+            query_data: SeiQuery::Epoch {},
+        }))
+        .unwrap();
+    assert_eq!(res.epoch, epoch);
+    assert_eq!(res.epoch.current_epoch, 2);
+    assert_eq!(
+        res.epoch.current_epoch_height,
+        app.block_info().height as i64
+    );
+
+    // The started epoch lasts the genesis minute, 12 blocks of 5 seconds
+    let start = app.block_info();
+    for _ in 0..11 {
+        next_block(&mut app).unwrap();
+    }
+    let query_epoch = |app: &SeiApp| -> Epoch {
+        let res: EpochResponse = app
+            .wrap()
+            .query(&QueryRequest::Custom(SeiQueryWrapper {
+                route: SeiRoute::Epoch,
+                query_data: SeiQuery::Epoch {},
+            }))
+            .unwrap();
+        res.epoch
+    };
+    assert_eq!(query_epoch(&app), epoch);
+    next_block(&mut app).unwrap();
+    let res = query_epoch(&app);
+    assert_eq!(res.current_epoch, 3);
+    assert_eq!(
+        res.current_epoch_start_time,
+        format_rfc3339(start.time.plus_seconds(60))
+    );
+    assert_eq!(res.current_epoch_height, start.height as i64 + 12);
+    for _ in 0..24 {
+        next_block(&mut app).unwrap();
+    }
+    let res = query_epoch(&app);
+    assert_eq!(res.current_epoch, 5);
+    assert_eq!(res.current_epoch_height, start.height as i64 + 36);
+}
+
+/// Dex Module - funds sent with orders are escrowed and refunded on cancel
+#[test]
+fn test_dex_module_order_escrow() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    let sei_tester_addr = setup_test(&mut app);
+
+    let order = |position_direction, price: u64, quantity: u64| Order {
+        price: Decimal::from_ratio(price, 1u64),
+        quantity: Decimal::from_ratio(quantity, 1u64),
+        price_denom: "usei".to_string(),
+        asset_denom: "uatom".to_string(),
+        order_type: OrderType::Limit,
+        position_direction,
+        data: "".to_string(),
+        status_description: "".to_string(),
+        nominal: Decimal::zero(),
+        trigger_price: None,
+    };
+    let cancel = |app: &mut SeiApp, id: u64| {
+        app.execute_multi(
+            Addr::unchecked("alice"),
+            vec![CosmosMsg::Custom(SeiMsg::CancelOrders {
+                cancellations: vec![Cancellation {
+                    id,
+                    contract_address: sei_tester_addr.to_string(),
+                    price: Decimal::zero(),
+                    price_denom: "usei".to_string(),
+                    asset_denom: "uatom".to_string(),
+                    order_type: OrderType::Limit,
+                    position_direction: PositionDirection::Long,
+                }],
+                contract_address: sei_tester_addr.clone(),
+            })],
+        )
+        .unwrap();
+    };
+    let balance = |app: &SeiApp, addr: &str, denom: &str| {
+        get_balance(app, addr.to_string(), denom.to_string())
+            .amount
+            .amount
+    };
+
+    // 6 usei for the long order, 4 uatom for the short order and 4 usei more
+    app.execute_multi(
+        Addr::unchecked("alice"),
+        vec![CosmosMsg::Custom(SeiMsg::PlaceOrders {
+            orders: vec![
+                order(PositionDirection::Long, 2, 3),
+                order(PositionDirection::Short, 2, 4),
+            ],
+            funds: vec![coin(10, "usei"), coin(4, "uatom")],
+            contract_address: sei_tester_addr.clone(),
+        })],
+    )
+    .unwrap();
+    assert_eq!(balance(&app, "alice", "usei"), Uint128::new(9_999_990));
+    assert_eq!(balance(&app, "alice", "uatom"), Uint128::new(9_999_996));
+    assert_eq!(balance(&app, DEX_MODULE_ADDRESS, "usei"), Uint128::new(10));
+    assert_eq!(balance(&app, DEX_MODULE_ADDRESS, "uatom"), Uint128::new(4));
+
+    let res: GetOrderByIdResponse = app
+        .wrap()
+        .query(&QueryRequest::Custom(SeiQueryWrapper {
+            route: SeiRoute::Dex,
+            query_data: SeiQuery::GetOrderById {
+                contract_address: sei_tester_addr.clone(),
+                price_denom: "usei".to_string(),
+                asset_denom: "uatom".to_string(),
+                id: 0,
+            },
+        }))
+        .unwrap();
+    assert_eq!(res.order.account, "alice");
+    assert_eq!(res.order.contract_address, sei_tester_addr.to_string());
+
+    // The deposits are delivered with the orders to BulkOrderPlacements
+    let batches = app
+        .init_modules(|router, _, storage| router.custom.take_block_batches(storage))
+        .unwrap();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(
+        batches[0].1.deposits,
+        vec![
+            DepositInfo {
+                account: "alice".to_string(),
+                denom: "usei".to_string(),
+                amount: Decimal::from_ratio(10u64, 1u64),
+            },
+            DepositInfo {
+                account: "alice".to_string(),
+                denom: "uatom".to_string(),
+                amount: Decimal::from_ratio(4u64, 1u64),
+            },
+        ]
+    );
+
+    // Funds beyond the balance of the sender
+    let err = app
+        .execute_multi(
+            Addr::unchecked("bob"),
+            vec![CosmosMsg::Custom(SeiMsg::PlaceOrders {
+                orders: vec![order(PositionDirection::Long, 2, 3)],
+                funds: vec![coin(20_000_000, "usei")],
+                contract_address: sei_tester_addr.clone(),
+            })],
+        )
+        .unwrap_err();
+    assert!(err.root_cause().to_string().contains("Cannot Sub"));
+
+    // Cancelling the short order refunds its 4 uatom
+    cancel(&mut app, 1);
+    assert_eq!(balance(&app, "alice", "uatom"), Uint128::new(10_000_000));
+    assert_eq!(balance(&app, DEX_MODULE_ADDRESS, "usei"), Uint128::new(10));
+
+    // Cancelling the last order refunds the rest of the deposit
+    cancel(&mut app, 0);
+    assert_eq!(balance(&app, "alice", "usei"), Uint128::new(10_000_000));
+    assert_eq!(balance(&app, DEX_MODULE_ADDRESS, "usei"), Uint128::zero());
+}
+
+/// Dex Module - escrowed funds of filled, cancelled and failed orders
+#[test]
+fn test_dex_module_escrow_settlement() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    let sei_tester_addr = setup_test(&mut app);
+
+    let order = |order_type, position_direction, price: u64, quantity: u64| Order {
+        price: Decimal::from_ratio(price, 1u64),
+        quantity: Decimal::from_ratio(quantity, 1u64),
+        price_denom: "usei".to_string(),
+        asset_denom: "uatom".to_string(),
+        order_type,
+        position_direction,
+        data: "".to_string(),
+        status_description: "".to_string(),
+        nominal: Decimal::zero(),
+        trigger_price: None,
+    };
+    let place = |app: &mut SeiApp, account: &str, order: Order, funds: Vec<Coin>| {
+        app.execute_multi(
+            Addr::unchecked(account),
+            vec![CosmosMsg::Custom(SeiMsg::PlaceOrders {
+                orders: vec![order],
+                funds,
+                contract_address: sei_tester_addr.clone(),
+            })],
+        )
+        .unwrap();
+    };
+    let cancel = |app: &mut SeiApp, account: &str, id: u64| {
+        app.execute_multi(
+            Addr::unchecked(account),
+            vec![CosmosMsg::Custom(SeiMsg::CancelOrders {
+                cancellations: vec![Cancellation {
+                    id,
+                    contract_address: sei_tester_addr.to_string(),
+                    price: Decimal::zero(),
+                    price_denom: "usei".to_string(),
+                    asset_denom: "uatom".to_string(),
+                    order_type: OrderType::Limit,
+                    position_direction: PositionDirection::Long,
+                }],
+                contract_address: sei_tester_addr.clone(),
+            })],
+        )
+    };
+    let balance = |app: &SeiApp, addr: &str, denom: &str| {
+        get_balance(app, addr.to_string(), denom.to_string())
+            .amount
+            .amount
+            .u128()
+    };
+
+    // Alice's long of 3 at 2 is allotted 6 of her 10 usei, bob's short fills 1 of it
+    place(
+        &mut app,
+        "alice",
+        order(OrderType::Limit, PositionDirection::Long, 2, 3),
+        vec![coin(10, "usei")],
+    );
+    place(
+        &mut app,
+        "bob",
+        order(OrderType::Limit, PositionDirection::Short, 2, 1),
+        vec![coin(1, "uatom")],
+    );
+    next_block(&mut app).unwrap();
+
+    // The filled share of the allotments goes to the contract
+    assert_eq!(balance(&app, sei_tester_addr.as_str(), "usei"), 2);
+    assert_eq!(balance(&app, sei_tester_addr.as_str(), "uatom"), 1);
+    assert_eq!(balance(&app, DEX_MODULE_ADDRESS, "usei"), 8);
+    assert_eq!(balance(&app, DEX_MODULE_ADDRESS, "uatom"), 0);
+
+    // Only the account of an order and the contract can cancel it
+    let err = cancel(&mut app, "bob", 0).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<SeiError>(),
+        Some(&SeiError::NotOrderOwner {
+            id: 0,
+            sender: "bob".to_string()
+        })
+    );
+
+    // Filled orders cannot be cancelled
+    let err = cancel(&mut app, "bob", 1).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<SeiError>(),
+        Some(&SeiError::OrderNotPlaced { id: 1 })
+    );
+    assert_eq!(balance(&app, "bob", "uatom"), 9_999_999);
+
+    // Cancelling the rest of alice's order refunds its allotment and the unallotted usei
+    cancel(&mut app, "alice", 0).unwrap();
+    assert_eq!(balance(&app, "alice", "usei"), 9_999_998);
+    assert_eq!(balance(&app, DEX_MODULE_ADDRESS, "usei"), 0);
+
+    // Market orders cancelled at the end of the block are refunded
+    place(
+        &mut app,
+        "charlie",
+        order(OrderType::Market, PositionDirection::Long, 3, 1),
+        vec![coin(3, "usei")],
+    );
+    next_block(&mut app).unwrap();
+    assert_eq!(balance(&app, "charlie", "usei"), 10_000_000);
+
+    // Filling an order completely returns the unallotted funds to its account
+    place(
+        &mut app,
+        "charlie",
+        order(OrderType::Limit, PositionDirection::Long, 2, 1),
+        vec![coin(5, "usei")],
+    );
+    place(
+        &mut app,
+        "bob",
+        order(OrderType::Limit, PositionDirection::Short, 2, 1),
+        vec![coin(1, "uatom")],
+    );
+    next_block(&mut app).unwrap();
+    assert_eq!(balance(&app, "charlie", "usei"), 9_999_998);
+    assert_eq!(balance(&app, sei_tester_addr.as_str(), "usei"), 4);
+    assert_eq!(balance(&app, sei_tester_addr.as_str(), "uatom"), 2);
+    assert_eq!(balance(&app, DEX_MODULE_ADDRESS, "usei"), 0);
+    assert_eq!(balance(&app, DEX_MODULE_ADDRESS, "uatom"), 0);
+
+    // Orders the contract fails to place are refunded
+    let mut app = mock_app(init_default_balances, vec![]);
+    let code_id = app.store_code(Box::new(
+        ContractWrapper::new(execute, instantiate, query).with_sudo(rejecting_sudo),
+    ));
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {},
+            &[],
+            "dex_contract",
+            None,
+        )
+        .unwrap();
+    app.execute_multi(
+        Addr::unchecked("alice"),
+        vec![CosmosMsg::Custom(SeiMsg::PlaceOrders {
+            orders: vec![
+                order(OrderType::Limit, PositionDirection::Long, 1, 1),
+                order(OrderType::Limit, PositionDirection::Short, 1, 1),
+            ],
+            funds: vec![coin(1, "usei"), coin(1, "uatom")],
+            contract_address: contract_addr.clone(),
+        })],
+    )
+    .unwrap();
+    let res = next_block(&mut app).unwrap();
+    assert_eq!(res.unsuccessful_orders.len(), 1);
+    assert_eq!(balance(&app, "alice", "uatom"), 10_000_000);
+    assert_eq!(balance(&app, "alice", "usei"), 9_999_999);
+    assert_eq!(balance(&app, DEX_MODULE_ADDRESS, "uatom"), 0);
+    assert_eq!(balance(&app, DEX_MODULE_ADDRESS, "usei"), 1);
+}
+
+fn failing_settlement_sudo(
+    _deps: DepsMut<SeiQueryWrapper>,
+    _env: Env,
+    msg: SeiSudoMsg,
+) -> Result<Response<SeiMsg>, StdError> {
+    match msg {
+        SeiSudoMsg::Settlement { .. } => Err(StdError::generic_err("settlement failed")),
+        _ => Ok(Response::new()),
+    }
+}
+
+/// Dex Module - a failing sudo reverts the whole end block
+#[test]
+fn test_dex_module_end_block_failing_sudo() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    let code_id = app.store_code(Box::new(
+        ContractWrapper::new(execute, instantiate, query).with_sudo(failing_settlement_sudo),
+    ));
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {},
+            &[],
+            "dex_contract",
+            None,
+        )
+        .unwrap();
+
+    let order = |position_direction| Order {
+        price: Decimal::from_ratio(2u64, 1u64),
+        quantity: Decimal::one(),
+        price_denom: "usei".to_string(),
+        asset_denom: "uatom".to_string(),
+        order_type: OrderType::Limit,
+        position_direction,
+        data: "".to_string(),
+        status_description: "".to_string(),
+        nominal: Decimal::zero(),
+        trigger_price: None,
+    };
+    for (account, position_direction, funds) in [
+        ("alice", PositionDirection::Long, coin(2, "usei")),
+        ("bob", PositionDirection::Short, coin(1, "uatom")),
+    ] {
+        app.execute_multi(
+            Addr::unchecked(account),
+            vec![CosmosMsg::Custom(SeiMsg::PlaceOrders {
+                orders: vec![order(position_direction)],
+                funds: vec![funds],
+                contract_address: contract_addr.clone(),
+            })],
+        )
+        .unwrap();
+    }
+    let balance = |app: &SeiApp, addr: &str, denom: &str| {
+        get_balance(app, addr.to_string(), denom.to_string())
+            .amount
+            .amount
+            .u128()
+    };
+    let status = |app: &SeiApp, id: u64| {
+        let res: GetOrderByIdResponse = app
+            .wrap()
+            .query(&QueryRequest::Custom(SeiQueryWrapper {
+                route: SeiRoute::Dex,
+                query_data: SeiQuery::GetOrderById {
+                    contract_address: contract_addr.clone(),
+                    price_denom: "usei".to_string(),
+                    asset_denom: "uatom".to_string(),
+                    id,
+                },
+            }))
+            .unwrap();
+        res.order.status
+    };
+
+    // The orders match, but the settlement sudo fails after the fills were paid out
+    let err = end_block(&mut app).unwrap_err();
+    assert!(err.root_cause().to_string().contains("settlement failed"));
+
+    // Nothing of the end block is kept
+    assert_eq!(status(&app, 0), OrderStatus::Placed);
+    assert_eq!(status(&app, 1), OrderStatus::Placed);
+    assert_eq!(balance(&app, DEX_MODULE_ADDRESS, "usei"), 2);
+    assert_eq!(balance(&app, DEX_MODULE_ADDRESS, "uatom"), 1);
+    assert_eq!(balance(&app, contract_addr.as_str(), "usei"), 0);
+    let batches = app
+        .init_modules(|router, _, storage| router.custom.take_block_batches(storage))
+        .unwrap();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].1.orders.len(), 2);
+    assert_eq!(batches[0].1.deposits.len(), 2);
+}
+
+/// Dex Module - settlements update the positions tracked by the contract
+#[test]
+fn test_dex_module_settlement_positions() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    let sei_tester_addr = setup_test(&mut app);
+
+    let place_order = |app: &mut SeiApp, account: &str, position_direction, price: u64| {
+        let order = Order {
+            price: Decimal::from_ratio(price, 1u64),
+            quantity: Decimal::from_ratio(2u64, 1u64),
+            price_denom: "USDC".to_string(),
+            asset_denom: "ATOM".to_string(),
+            order_type: OrderType::Limit,
+            position_direction,
+            data: "".to_string(),
+            status_description: "".to_string(),
+            nominal: Decimal::zero(),
+            trigger_price: None,
+        };
+        app.execute_multi(
+            Addr::unchecked(account),
+            vec![CosmosMsg::Custom(SeiMsg::PlaceOrders {
+                orders: vec![order],
+                funds: vec![],
+                contract_address: sei_tester_addr.clone(),
+            })],
+        )
+        .unwrap();
+    };
+    let positions = |app: &SeiApp, account: &str| -> PositionsResponse {
+        app.wrap()
+            .query_wasm_smart(
+                sei_tester_addr.clone(),
+                &QueryMsg::GetPositions {
+                    account: account.to_string(),
+                },
+            )
+            .unwrap()
+    };
+
+    // Alice buys 2 ATOM from Bob at 10, then sells them to Charlie at 13
+    place_order(&mut app, "bob", PositionDirection::Short, 10);
+    place_order(&mut app, "alice", PositionDirection::Long, 11);
+    next_block(&mut app).unwrap();
+
+    let res = positions(&app, "alice");
+    assert_eq!(res.positions.len(), 1);
+    assert_eq!(res.positions[0].quantity, SignedDecimal::from_ratio(2, 1));
+    assert_eq!(
+        res.positions[0].entry_price,
+        Decimal::from_ratio(10u64, 1u64)
+    );
+    assert_eq!(
+        res.positions[0].deposit_surplus,
+        SignedDecimal::from_ratio(2, 1)
+    );
+    let res = positions(&app, "bob");
+    assert_eq!(res.positions[0].quantity, SignedDecimal::from_ratio(-2, 1));
+
+    place_order(&mut app, "charlie", PositionDirection::Long, 13);
+    place_order(&mut app, "alice", PositionDirection::Short, 13);
+    next_block(&mut app).unwrap();
+
+    let res = positions(&app, "alice");
+    assert!(res.positions[0].is_flat());
+    assert_eq!(
+        res.positions[0].realized_pnl,
+        SignedDecimal::from_ratio(6, 1)
+    );
+    assert!(positions(&app, "dave").positions.is_empty());
+}
+
+/// Dex Module - contract and pair registration
+#[test]
+fn test_dex_module_contract_registration() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    let sei_tester_addr = setup_test(&mut app);
+
+    let pair = |price_tick_size| DexPair {
+        price_denom: "USDC".to_string(),
+        asset_denom: "ATOM".to_string(),
+        price_tick_size,
+        quantity_tick_size: Decimal::one(),
+    };
+    let execute = |app: &mut SeiApp, sender: &str, msg: SeiMsg| {
+        app.execute_multi(Addr::unchecked(sender), vec![CosmosMsg::Custom(msg)])
+    };
+    let place_order = |app: &mut SeiApp, price: Decimal| {
+        let order = OrderBuilder::limit("USDC", "ATOM", price, Decimal::one())
+            .build()
+            .unwrap();
+        execute(
+            app,
+            ADMIN,
+            SeiMsg::PlaceOrders {
+                orders: vec![order],
+                funds: vec![],
+                contract_address: sei_tester_addr.clone(),
+            },
+        )
+    };
+    let rent_balance = |app: &SeiApp| {
+        app.read_module(|router, _, storage| {
+            router
+                .custom
+                .dex_contract_info(storage, &sei_tester_addr)
+                .unwrap()
+                .map(|info| info.rent_balance)
+        })
+    };
+    let admin_balance = get_balance(&app, ADMIN.to_string(), NATIVE_DENOM.to_string())
+        .amount
+        .amount;
+
+    // Pairs can only be registered for registered contracts
+    let err = execute(
+        &mut app,
+        ADMIN,
+        SeiMsg::RegisterPairs {
+            contract_address: sei_tester_addr.clone(),
+            pairs: vec![pair(Decimal::percent(10))],
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast::<SeiError>().unwrap(),
+        SeiError::ContractNotRegistered {
+            contract_address: sei_tester_addr.to_string(),
+        }
+    );
+
+    execute(
+        &mut app,
+        ADMIN,
+        SeiMsg::RegisterContract {
+            contract_address: sei_tester_addr.clone(),
+            code_id: 1,
+            need_hook: true,
+            need_order_matching: true,
+            rent: Uint128::new(1_000),
+        },
+    )
+    .unwrap();
+    execute(
+        &mut app,
+        ADMIN,
+        SeiMsg::RegisterPairs {
+            contract_address: sei_tester_addr.clone(),
+            pairs: vec![pair(Decimal::percent(10))],
+        },
+    )
+    .unwrap();
+    let err = place_order(&mut app, Decimal::percent(105)).unwrap_err();
+    assert!(err.root_cause().to_string().contains("price tick size"));
+
+    // Only the creator manages the registration
+    let err = execute(
+        &mut app,
+        "bob",
+        SeiMsg::UpdateTickSizes {
+            contract_address: sei_tester_addr.clone(),
+            pairs: vec![pair(Decimal::percent(5))],
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast::<SeiError>().unwrap(),
+        SeiError::NotContractCreator {
+            contract_address: sei_tester_addr.to_string(),
+            sender: "bob".to_string(),
+        }
+    );
+
+    execute(
+        &mut app,
+        ADMIN,
+        SeiMsg::UpdateTickSizes {
+            contract_address: sei_tester_addr.clone(),
+            pairs: vec![pair(Decimal::percent(5))],
+        },
+    )
+    .unwrap();
+    place_order(&mut app, Decimal::percent(105)).unwrap();
+
+    let mut unknown = pair(Decimal::percent(5));
+    unknown.asset_denom = "OSMO".to_string();
+    let err = execute(
+        &mut app,
+        ADMIN,
+        SeiMsg::UpdateTickSizes {
+            contract_address: sei_tester_addr.clone(),
+            pairs: vec![unknown],
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err.downcast::<SeiError>().unwrap(),
+        SeiError::PairNotRegistered { .. }
+    ));
+
+    // Anyone can top up the rent
+    execute(
+        &mut app,
+        "bob",
+        SeiMsg::DepositRent {
+            contract_address: sei_tester_addr.clone(),
+            amount: Uint128::new(500),
+        },
+    )
+    .unwrap();
+    assert_eq!(rent_balance(&app), Some(Uint128::new(1_500)));
+    assert_eq!(
+        get_balance(
+            &app,
+            DEX_MODULE_ADDRESS.to_string(),
+            NATIVE_DENOM.to_string()
+        )
+        .amount
+        .amount,
+        Uint128::new(1_500)
+    );
+
+    // Unregistering refunds the rent to the creator
+    execute(
+        &mut app,
+        ADMIN,
+        SeiMsg::UnregisterContract {
+            contract_address: sei_tester_addr.clone(),
+        },
+    )
+    .unwrap();
+    assert_eq!(rent_balance(&app), None);
+    assert_eq!(
+        get_balance(&app, ADMIN.to_string(), NATIVE_DENOM.to_string())
+            .amount
+            .amount,
+        admin_balance + Uint128::new(500)
+    );
+    place_order(&mut app, Decimal::percent(101)).unwrap();
+}
+
+/// Dex Module - book depth, registered pairs and price queries
+#[test]
+fn test_dex_module_market_queries() {
+    let mut app = mock_app(init_default_balances, vec![]);
+    let sei_tester_addr = setup_test(&mut app);
+    let dec = |value: u64| Decimal::from_ratio(value, 1u64);
+    let pair = DexPair {
+        price_denom: "USDC".to_string(),
+        asset_denom: "ATOM".to_string(),
+        price_tick_size: Decimal::percent(1),
+        quantity_tick_size: Decimal::one(),
+    };
+    app.init_modules(|router, _, storage| {
+        router
+            .custom
+            .register_dex_pair(storage, &sei_tester_addr, pair.clone())
+    })
+    .unwrap();
+
+    let place_orders = |app: &mut SeiApp, orders: Vec<(PositionDirection, u64, u64)>| {
+        let orders = orders
+            .into_iter()
+            .map(|(position_direction, price, quantity)| {
+                OrderBuilder::limit("USDC", "ATOM", dec(price), dec(quantity))
+                    .direction(position_direction)
+                    .build()
+                    .unwrap()
+            })
+            .collect();
+        app.execute_multi(
+            Addr::unchecked(ADMIN),
+            vec![CosmosMsg::Custom(SeiMsg::PlaceOrders {
+                orders,
+                funds: vec![],
+                contract_address: sei_tester_addr.clone(),
+            })],
+        )
+        .unwrap();
+    };
+
+    place_orders(
+        &mut app,
+        vec![
+            (PositionDirection::Short, 10, 2),
+            (PositionDirection::Short, 11, 3),
+            (PositionDirection::Short, 11, 1),
+            (PositionDirection::Long, 9, 2),
+            (PositionDirection::Long, 8, 1),
+        ],
+    );
+
+    let wrapper = app.wrap();
+    let querier = SeiQuerier::new(&wrapper);
+    let res = querier
+        .query_order_book_depth(
+            sei_tester_addr.clone(),
+            "USDC".to_string(),
+            "ATOM".to_string(),
+            None,
+        )
+        .unwrap();
+    let levels = |levels: &[sei_cosmwasm::PriceLevel]| -> Vec<(Decimal, Decimal)> {
+        levels.iter().map(|l| (l.price, l.quantity)).collect()
+    };
+    assert_eq!(levels(&res.longs), vec![(dec(9), dec(2)), (dec(8), dec(1))]);
+    assert_eq!(
+        levels(&res.shorts),
+        vec![(dec(10), dec(2)), (dec(11), dec(4))]
+    );
+    let res = querier
+        .query_order_book_depth(
+            sei_tester_addr.clone(),
+            "USDC".to_string(),
+            "ATOM".to_string(),
+            Some(1),
+        )
+        .unwrap();
+    assert_eq!(levels(&res.shorts), vec![(dec(10), dec(2))]);
+
+    let res = querier
+        .query_registered_pairs(sei_tester_addr.clone())
+        .unwrap();
+    assert_eq!(res.pairs, vec![pair.clone()]);
+
+    let err = querier
+        .query_get_latest_price(
+            sei_tester_addr.clone(),
+            "USDC".to_string(),
+            "ATOM".to_string(),
+        )
+        .unwrap_err();
+    assert!(matches!(err, SeiError::PriceNotFound { .. }));
+
+    // Fills at 10, then at 11 in the next block
+    let start = app.block_info().time.seconds();
+    next_block(&mut app).unwrap();
+    place_orders(&mut app, vec![(PositionDirection::Long, 10, 2)]);
+    next_block(&mut app).unwrap();
+    place_orders(&mut app, vec![(PositionDirection::Long, 11, 1)]);
+    next_block(&mut app).unwrap();
+    let first_fill = start + 5;
+
+    let wrapper = app.wrap();
+    let querier = SeiQuerier::new(&wrapper);
+    let res = querier
+        .query_get_latest_price(
+            sei_tester_addr.clone(),
+            "USDC".to_string(),
+            "ATOM".to_string(),
+        )
+        .unwrap();
+    assert_eq!(res.price.price, dec(11));
+    assert_eq!(res.price.snapshot_timestamp_in_seconds, first_fill + 5);
+    assert_eq!(res.price.pair, pair);
+
+    let res = querier
+        .query_market_summary(
+            sei_tester_addr.clone(),
+            "USDC".to_string(),
+            "ATOM".to_string(),
+            60,
+        )
+        .unwrap();
+    assert_eq!(res.high_price, Some(dec(11)));
+    assert_eq!(res.low_price, Some(dec(10)));
+    assert_eq!(res.last_price, Some(dec(11)));
+    assert_eq!(res.total_volume, dec(3));
+    assert_eq!(res.total_volume_notional, dec(31));
+
+    // Only the last fill is within the last 5 seconds
+    let res = querier
+        .query_market_summary(
+            sei_tester_addr.clone(),
+            "USDC".to_string(),
+            "ATOM".to_string(),
+            5,
+        )
+        .unwrap();
+    assert_eq!(res.low_price, Some(dec(11)));
+    assert_eq!(res.total_volume, dec(1));
+
+    let res = querier
+        .query_historical_prices(
+            sei_tester_addr.clone(),
+            "USDC".to_string(),
+            "ATOM".to_string(),
+            5,
+            4,
+        )
+        .unwrap();
+    let candles: Vec<(u64, u64, Decimal, Decimal)> = res
+        .prices
+        .iter()
+        .map(|c| (c.begin_timestamp, c.end_timestamp, c.open, c.volume))
+        .collect();
+    assert_eq!(
+        candles,
+        vec![
+            (first_fill - 5, first_fill, dec(10), dec(2)),
+            (first_fill, first_fill + 5, dec(11), dec(1)),
+        ]
+    );
+}
@@ -1,23 +1,84 @@
 use std::env::current_dir;
-use std::fs::create_dir_all;
 
-use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
+use cosmwasm_schema::{export_schema, schema_for, write_api};
+use cosmwasm_std::Empty;
 use sei_cosmwasm::{
-    DexTwapsResponse, ExchangeRatesResponse, OracleTwapsResponse, SeiMsg, SeiQuery,
-    SeiQueryWrapper, SeiRoute,
+    BulkOrderPlacementsResponse, Cancellation, DenomAuthorityMetadataResponse,
+    DenomsFromCreatorResponse, DexTwapsResponse, EpochResponse, Erc1155BalanceOfBatchResponse,
+    Erc1155BalanceOfResponse, Erc1155IsApprovedForAllResponse, Erc1155RoyaltyInfoResponse,
+    Erc1155UriResponse, Erc20AllowanceResponse, Erc721ApprovedResponse, Erc721BalanceOfResponse,
+    Erc721IsApprovedForAllResponse, Erc721NameSymbolResponse, Erc721OwnerResponse,
+    Erc721RoyaltyInfoResponse, Erc721TokenByIndexResponse, Erc721TokenOfOwnerByIndexResponse,
+    Erc721TotalSupplyResponse, Erc721UriResponse, ErcPayloadResponse, EvmAddressResponse,
+    ExchangeRatesResponse, GetHistoricalPricesResponse, GetLatestPriceResponse,
+    GetOrderByIdResponse, GetOrdersResponse, GetRegisteredPairsResponse, MarketSummaryResponse,
+    Metadata, OracleTwapsResponse, Order, OrderBookDepthResponse, OrderResponse,
+    OrderSimulationResponse, SeiAddressResponse, SeiMsg, SeiQuery, SeiQueryWrapper, SeiRoute,
+    SettlementEntry, StaticCallResponse, SudoMsg,
 };
 
 fn main() {
+    // Versioned bundle of the messages, queries and the response of each query, written to
+    // schema/sei-cosmwasm.json and schema/raw
+    write_api! {
+        instantiate: Empty,
+        execute: SeiMsg,
+        query: SeiQuery,
+        sudo: SudoMsg,
+    }
+
     let mut out_dir = current_dir().unwrap();
     out_dir.push("schema");
-    create_dir_all(&out_dir).unwrap();
-    remove_schemas(&out_dir).unwrap();
 
     export_schema(&schema_for!(SeiMsg), &out_dir);
+    export_schema(&schema_for!(SudoMsg), &out_dir);
     export_schema(&schema_for!(SeiQueryWrapper), &out_dir);
     export_schema(&schema_for!(SeiQuery), &out_dir);
     export_schema(&schema_for!(SeiRoute), &out_dir);
+
+    // Types of messages and sudo messages
+    export_schema(&schema_for!(Order), &out_dir);
+    export_schema(&schema_for!(Cancellation), &out_dir);
+    export_schema(&schema_for!(Metadata), &out_dir);
+    export_schema(&schema_for!(SettlementEntry), &out_dir);
+    export_schema(&schema_for!(OrderResponse), &out_dir);
+    export_schema(&schema_for!(BulkOrderPlacementsResponse), &out_dir);
+
+    // Query responses
     export_schema(&schema_for!(ExchangeRatesResponse), &out_dir);
     export_schema(&schema_for!(OracleTwapsResponse), &out_dir);
     export_schema(&schema_for!(DexTwapsResponse), &out_dir);
+    export_schema(&schema_for!(EpochResponse), &out_dir);
+    export_schema(&schema_for!(GetOrdersResponse), &out_dir);
+    export_schema(&schema_for!(GetOrderByIdResponse), &out_dir);
+    export_schema(&schema_for!(GetLatestPriceResponse), &out_dir);
+    export_schema(&schema_for!(OrderBookDepthResponse), &out_dir);
+    export_schema(&schema_for!(GetRegisteredPairsResponse), &out_dir);
+    export_schema(&schema_for!(MarketSummaryResponse), &out_dir);
+    export_schema(&schema_for!(GetHistoricalPricesResponse), &out_dir);
+    export_schema(&schema_for!(OrderSimulationResponse), &out_dir);
+    export_schema(&schema_for!(DenomAuthorityMetadataResponse), &out_dir);
+    export_schema(&schema_for!(DenomsFromCreatorResponse), &out_dir);
+    export_schema(&schema_for!(StaticCallResponse), &out_dir);
+    export_schema(&schema_for!(ErcPayloadResponse), &out_dir);
+    export_schema(&schema_for!(Erc20AllowanceResponse), &out_dir);
+    export_schema(&schema_for!(cw20::TokenInfoResponse), &out_dir);
+    export_schema(&schema_for!(cw20::BalanceResponse), &out_dir);
+    export_schema(&schema_for!(Erc721OwnerResponse), &out_dir);
+    export_schema(&schema_for!(Erc721ApprovedResponse), &out_dir);
+    export_schema(&schema_for!(Erc721IsApprovedForAllResponse), &out_dir);
+    export_schema(&schema_for!(Erc721NameSymbolResponse), &out_dir);
+    export_schema(&schema_for!(Erc721UriResponse), &out_dir);
+    export_schema(&schema_for!(Erc721TotalSupplyResponse), &out_dir);
+    export_schema(&schema_for!(Erc721TokenByIndexResponse), &out_dir);
+    export_schema(&schema_for!(Erc721TokenOfOwnerByIndexResponse), &out_dir);
+    export_schema(&schema_for!(Erc721BalanceOfResponse), &out_dir);
+    export_schema(&schema_for!(Erc721RoyaltyInfoResponse), &out_dir);
+    export_schema(&schema_for!(Erc1155BalanceOfResponse), &out_dir);
+    export_schema(&schema_for!(Erc1155BalanceOfBatchResponse), &out_dir);
+    export_schema(&schema_for!(Erc1155IsApprovedForAllResponse), &out_dir);
+    export_schema(&schema_for!(Erc1155UriResponse), &out_dir);
+    export_schema(&schema_for!(Erc1155RoyaltyInfoResponse), &out_dir);
+    export_schema(&schema_for!(EvmAddressResponse), &out_dir);
+    export_schema(&schema_for!(SeiAddressResponse), &out_dir);
 }
@@ -0,0 +1,547 @@
+use core::fmt;
+
+use cosmwasm_std::{Int256, StdError, StdResult, Uint256};
+use sha3::{Digest, Keccak256};
+
+const WORD: usize = 32;
+
+/// `AbiType` describes a Solidity ABI parameter type. It drives decoding of EVM return data and
+/// can be parsed from its canonical string form, e.g. `uint256`, `address[]` or
+/// `(string,uint8)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AbiType {
+    Address,
+    Bool,
+    /// Unsigned integer of the given bit size (8..=256, multiple of 8)
+    Uint(usize),
+    /// Signed integer of the given bit size (8..=256, multiple of 8)
+    Int(usize),
+    /// Dynamic length `bytes`
+    Bytes,
+    /// `bytes1` .. `bytes32`
+    FixedBytes(usize),
+    String,
+    /// Dynamic length array `T[]`
+    Array(Box<AbiType>),
+    /// Fixed length array `T[N]`
+    FixedArray(Box<AbiType>, usize),
+    /// Tuple `(T1,T2,...)`
+    Tuple(Vec<AbiType>),
+}
+
+/// `AbiToken` is a Solidity ABI encoded value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AbiToken {
+    /// 20-byte EVM address
+    Address([u8; 20]),
+    Bool(bool),
+    Uint(Uint256),
+    Int(Int256),
+    Bytes(Vec<u8>),
+    /// Up to 32 bytes, right padded when encoded
+    FixedBytes(Vec<u8>),
+    String(String),
+    Array(Vec<AbiToken>),
+    FixedArray(Vec<AbiToken>),
+    Tuple(Vec<AbiToken>),
+}
+
+/// Computes the keccak256 hash of the given data
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+/// Computes the 4-byte function selector for a canonical function signature such as
+/// `transfer(address,uint256)`.
+pub fn function_selector(signature: &str) -> [u8; 4] {
+    let hash = keccak256(signature.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// ABI-encodes the given tokens as the parameters of a function call or return value.
+///
+/// # Errors
+/// Returns an error if a `FixedBytes` token holds more than 32 bytes.
+pub fn abi_encode(tokens: &[AbiToken]) -> StdResult<Vec<u8>> {
+    encode_tuple(tokens)
+}
+
+/// ABI-encodes a call of the function with the given canonical `signature`, prefixing the
+/// encoded `args` with the function selector.
+///
+/// # Errors
+/// Returns an error if the `args` cannot be encoded, see `abi_encode`.
+pub fn encode_function_call(signature: &str, args: &[AbiToken]) -> StdResult<Vec<u8>> {
+    let mut data = function_selector(signature).to_vec();
+    data.extend(abi_encode(args)?);
+    Ok(data)
+}
+
+/// Decodes ABI encoded `data` into tokens of the given `types`.
+///
+/// # Errors
+/// Returns an error if the data is truncated, contains out of range offsets or values that do not
+/// fit the expected types.
+pub fn abi_decode(types: &[AbiType], data: &[u8]) -> StdResult<Vec<AbiToken>> {
+    decode_tuple(types, data)
+}
+
+impl AbiType {
+    /// Parses a canonical Solidity type, e.g. `uint256`, `bytes32[2]` or `(address,bool)[]`.
+    pub fn parse(s: &str) -> StdResult<Self> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(abi_err("empty type"));
+        }
+
+        // array suffixes are applied last, so strip the outermost one first
+        if let Some(stripped) = s.strip_suffix(']') {
+            let open = stripped
+                .rfind('[')
+                .ok_or_else(|| abi_err(format!("invalid type `{}`", s)))?;
+            let inner = Box::new(AbiType::parse(&stripped[..open])?);
+            let size = &stripped[open + 1..];
+            return if size.is_empty() {
+                Ok(AbiType::Array(inner))
+            } else {
+                let size = size
+                    .parse::<usize>()
+                    .map_err(|_| abi_err(format!("invalid array size in `{}`", s)))?;
+                Ok(AbiType::FixedArray(inner, size))
+            };
+        }
+
+        if let Some(inner) = s.strip_prefix('(') {
+            let inner = inner
+                .strip_suffix(')')
+                .ok_or_else(|| abi_err(format!("invalid tuple `{}`", s)))?;
+            return Ok(AbiType::Tuple(AbiType::parse_list(inner)?));
+        }
+
+        match s {
+            "address" => Ok(AbiType::Address),
+            "bool" => Ok(AbiType::Bool),
+            "string" => Ok(AbiType::String),
+            "bytes" => Ok(AbiType::Bytes),
+            "uint" => Ok(AbiType::Uint(256)),
+            "int" => Ok(AbiType::Int(256)),
+            _ => {
+                if let Some(bits) = s.strip_prefix("uint") {
+                    Ok(AbiType::Uint(parse_bits(s, bits)?))
+                } else if let Some(bits) = s.strip_prefix("int") {
+                    Ok(AbiType::Int(parse_bits(s, bits)?))
+                } else if let Some(len) = s.strip_prefix("bytes") {
+                    match len.parse::<usize>() {
+                        Ok(len) if (1..=32).contains(&len) => Ok(AbiType::FixedBytes(len)),
+                        _ => Err(abi_err(format!("invalid type `{}`", s))),
+                    }
+                } else {
+                    Err(abi_err(format!("unknown type `{}`", s)))
+                }
+            }
+        }
+    }
+
+    /// Parses a comma separated list of types, e.g. `address,uint256`. Commas nested in tuples
+    /// are handled.
+    pub fn parse_list(s: &str) -> StdResult<Vec<Self>> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut types = vec![];
+        let mut depth = 0usize;
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => {
+                    depth = depth
+                        .checked_sub(1)
+                        .ok_or_else(|| abi_err(format!("unbalanced parentheses in `{}`", s)))?
+                }
+                ',' if depth == 0 => {
+                    types.push(AbiType::parse(&s[start..i])?);
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        if depth != 0 {
+            return Err(abi_err(format!("unbalanced parentheses in `{}`", s)));
+        }
+        types.push(AbiType::parse(&s[start..])?);
+        Ok(types)
+    }
+
+    /// Returns true if the type is encoded in the tail section of its enclosing tuple
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            AbiType::Bytes | AbiType::String | AbiType::Array(_) => true,
+            AbiType::FixedArray(inner, _) => inner.is_dynamic(),
+            AbiType::Tuple(types) => types.iter().any(AbiType::is_dynamic),
+            _ => false,
+        }
+    }
+
+    fn head_size(&self) -> usize {
+        match self {
+            AbiType::FixedArray(inner, size) if !self.is_dynamic() => inner.head_size() * size,
+            AbiType::Tuple(types) if !self.is_dynamic() => {
+                types.iter().map(AbiType::head_size).sum()
+            }
+            _ => WORD,
+        }
+    }
+}
+
+impl fmt::Display for AbiType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AbiType::Address => write!(f, "address"),
+            AbiType::Bool => write!(f, "bool"),
+            AbiType::Uint(bits) => write!(f, "uint{}", bits),
+            AbiType::Int(bits) => write!(f, "int{}", bits),
+            AbiType::Bytes => write!(f, "bytes"),
+            AbiType::FixedBytes(len) => write!(f, "bytes{}", len),
+            AbiType::String => write!(f, "string"),
+            AbiType::Array(inner) => write!(f, "{}[]", inner),
+            AbiType::FixedArray(inner, size) => write!(f, "{}[{}]", inner, size),
+            AbiType::Tuple(types) => {
+                write!(f, "(")?;
+                for (i, ty) in types.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", ty)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+impl AbiToken {
+    /// Creates an address token from a hex encoded (optionally `0x` prefixed) EVM address
+    pub fn address(address: &str) -> StdResult<Self> {
+        Ok(AbiToken::Address(parse_evm_address(address)?))
+    }
+
+    /// Creates an unsigned integer token
+    pub fn uint(value: impl Into<Uint256>) -> Self {
+        AbiToken::Uint(value.into())
+    }
+
+    /// Creates a string token
+    pub fn string(value: impl Into<String>) -> Self {
+        AbiToken::String(value.into())
+    }
+
+    /// Returns the EIP-55 checksummed address if the token is an address
+    pub fn into_address(self) -> Option<String> {
+        match self {
+            AbiToken::Address(address) => Some(to_checksum_address(&address)),
+            _ => None,
+        }
+    }
+
+    pub fn into_bool(self) -> Option<bool> {
+        match self {
+            AbiToken::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn into_uint(self) -> Option<Uint256> {
+        match self {
+            AbiToken::Uint(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn into_int(self) -> Option<Int256> {
+        match self {
+            AbiToken::Int(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the bytes of a `bytes` or `bytesN` token
+    pub fn into_bytes(self) -> Option<Vec<u8>> {
+        match self {
+            AbiToken::Bytes(value) | AbiToken::FixedBytes(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn into_string(self) -> Option<String> {
+        match self {
+            AbiToken::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of an array, fixed array or tuple token
+    pub fn into_tokens(self) -> Option<Vec<AbiToken>> {
+        match self {
+            AbiToken::Array(tokens) | AbiToken::FixedArray(tokens) | AbiToken::Tuple(tokens) => {
+                Some(tokens)
+            }
+            _ => None,
+        }
+    }
+
+    fn is_dynamic(&self) -> bool {
+        match self {
+            AbiToken::Bytes(_) | AbiToken::String(_) | AbiToken::Array(_) => true,
+            AbiToken::FixedArray(tokens) | AbiToken::Tuple(tokens) => {
+                tokens.iter().any(AbiToken::is_dynamic)
+            }
+            _ => false,
+        }
+    }
+
+    fn head_size(&self) -> usize {
+        match self {
+            AbiToken::FixedArray(tokens) | AbiToken::Tuple(tokens) if !self.is_dynamic() => {
+                tokens.iter().map(AbiToken::head_size).sum()
+            }
+            _ => WORD,
+        }
+    }
+}
+
+/// Parses a hex encoded (optionally `0x` prefixed) 20-byte EVM address
+pub fn parse_evm_address(address: &str) -> StdResult<[u8; 20]> {
+    let hex = address.strip_prefix("0x").unwrap_or(address);
+    let bytes = decode_hex(hex).map_err(|_| abi_err(format!("invalid address `{}`", address)))?;
+    bytes
+        .try_into()
+        .map_err(|_| abi_err(format!("invalid address length `{}`", address)))
+}
+
+/// Formats a 20-byte EVM address as a `0x` prefixed EIP-55 checksummed hex string
+pub fn to_checksum_address(address: &[u8; 20]) -> String {
+    let lower = encode_hex(address);
+    let hash = keccak256(lower.as_bytes());
+    let mut checksummed = String::with_capacity(42);
+    checksummed.push_str("0x");
+    for (i, c) in lower.chars().enumerate() {
+        let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+        if c.is_ascii_alphabetic() && nibble >= 8 {
+            checksummed.push(c.to_ascii_uppercase());
+        } else {
+            checksummed.push(c);
+        }
+    }
+    checksummed
+}
+
+fn encode_tuple(tokens: &[AbiToken]) -> StdResult<Vec<u8>> {
+    let head_size: usize = tokens.iter().map(AbiToken::head_size).sum();
+    let mut head = Vec::with_capacity(head_size);
+    let mut tail = vec![];
+    for token in tokens {
+        if token.is_dynamic() {
+            head.extend(usize_word(head_size + tail.len()));
+            tail.extend(encode_token(token)?);
+        } else {
+            head.extend(encode_token(token)?);
+        }
+    }
+    head.extend(tail);
+    Ok(head)
+}
+
+fn encode_token(token: &AbiToken) -> StdResult<Vec<u8>> {
+    let encoded = match token {
+        AbiToken::Address(address) => {
+            let mut word = [0u8; WORD];
+            word[12..].copy_from_slice(address);
+            word.to_vec()
+        }
+        AbiToken::Bool(value) => usize_word(*value as usize).to_vec(),
+        AbiToken::Uint(value) => value.to_be_bytes().to_vec(),
+        AbiToken::Int(value) => value.to_be_bytes().to_vec(),
+        AbiToken::FixedBytes(bytes) => {
+            if bytes.len() > WORD {
+                return Err(abi_err(format!(
+                    "{} bytes do not fit in a fixed bytes value",
+                    bytes.len()
+                )));
+            }
+            let mut word = [0u8; WORD];
+            word[..bytes.len()].copy_from_slice(bytes);
+            word.to_vec()
+        }
+        AbiToken::Bytes(bytes) => encode_bytes(bytes),
+        AbiToken::String(value) => encode_bytes(value.as_bytes()),
+        AbiToken::Array(tokens) => {
+            let mut encoded = usize_word(tokens.len()).to_vec();
+            encoded.extend(encode_tuple(tokens)?);
+            encoded
+        }
+        AbiToken::FixedArray(tokens) | AbiToken::Tuple(tokens) => encode_tuple(tokens)?,
+    };
+    Ok(encoded)
+}
+
+fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    let padding = (WORD - bytes.len() % WORD) % WORD;
+    let mut encoded = usize_word(bytes.len()).to_vec();
+    encoded.extend_from_slice(bytes);
+    encoded.resize(encoded.len() + padding, 0);
+    encoded
+}
+
+fn usize_word(value: usize) -> [u8; WORD] {
+    let mut word = [0u8; WORD];
+    word[WORD - 8..].copy_from_slice(&(value as u64).to_be_bytes());
+    word
+}
+
+fn decode_tuple(types: &[AbiType], data: &[u8]) -> StdResult<Vec<AbiToken>> {
+    let mut tokens = Vec::with_capacity(types.len());
+    let mut head = 0;
+    for ty in types {
+        let token = if ty.is_dynamic() {
+            let offset = read_usize(data, head)?;
+            let tail = data
+                .get(offset..)
+                .ok_or_else(|| abi_err(format!("offset {} out of bounds", offset)))?;
+            decode_token(ty, tail)?
+        } else {
+            decode_token(ty, data.get(head..).unwrap_or_default())?
+        };
+        tokens.push(token);
+        head += ty.head_size();
+    }
+    Ok(tokens)
+}
+
+fn decode_token(ty: &AbiType, data: &[u8]) -> StdResult<AbiToken> {
+    match ty {
+        AbiType::Address => {
+            let word = read_word(data, 0)?;
+            if word[..12].iter().any(|b| *b != 0) {
+                return Err(abi_err("invalid address padding"));
+            }
+            let mut address = [0u8; 20];
+            address.copy_from_slice(&word[12..]);
+            Ok(AbiToken::Address(address))
+        }
+        AbiType::Bool => match Uint256::from_be_bytes(read_word(data, 0)?) {
+            v if v.is_zero() => Ok(AbiToken::Bool(false)),
+            v if v == Uint256::one() => Ok(AbiToken::Bool(true)),
+            _ => Err(abi_err("invalid bool value")),
+        },
+        AbiType::Uint(bits) => {
+            let word = read_word(data, 0)?;
+            if word[..WORD - bits / 8].iter().any(|b| *b != 0) {
+                return Err(abi_err(format!("value out of range for uint{}", bits)));
+            }
+            Ok(AbiToken::Uint(Uint256::from_be_bytes(word)))
+        }
+        AbiType::Int(bits) => {
+            let word = read_word(data, 0)?;
+            let value = Int256::from_be_bytes(word);
+            let sign = if value.is_negative() { 0xff } else { 0x00 };
+            // every byte above the value width and the value's top bit must match the sign
+            let width = WORD - bits / 8;
+            if word[..width].iter().any(|b| *b != sign) || (word[width] >> 7) != (sign & 1) {
+                return Err(abi_err(format!("value out of range for int{}", bits)));
+            }
+            Ok(AbiToken::Int(value))
+        }
+        AbiType::FixedBytes(len) => {
+            let word = read_word(data, 0)?;
+            Ok(AbiToken::FixedBytes(word[..*len].to_vec()))
+        }
+        AbiType::Bytes => Ok(AbiToken::Bytes(read_bytes(data)?.to_vec())),
+        AbiType::String => {
+            let bytes = read_bytes(data)?;
+            String::from_utf8(bytes.to_vec())
+                .map(AbiToken::String)
+                .map_err(|_| abi_err("invalid utf-8 string"))
+        }
+        AbiType::Array(inner) => {
+            let len = read_usize(data, 0)?;
+            let elements = &data[WORD..];
+            // guards against allocating for lengths the data cannot possibly hold, and bounds
+            // the length of arrays of zero sized elements such as `T[0][]` by the data length
+            if len.saturating_mul(inner.head_size()) > elements.len() || len > data.len() {
+                return Err(abi_err(format!("array length {} out of bounds", len)));
+            }
+            let types = vec![inner.as_ref().clone(); len];
+            Ok(AbiToken::Array(decode_tuple(&types, elements)?))
+        }
+        AbiType::FixedArray(inner, size) => {
+            let types = vec![inner.as_ref().clone(); *size];
+            Ok(AbiToken::FixedArray(decode_tuple(&types, data)?))
+        }
+        AbiType::Tuple(types) => Ok(AbiToken::Tuple(decode_tuple(types, data)?)),
+    }
+}
+
+fn read_word(data: &[u8], at: usize) -> StdResult<[u8; WORD]> {
+    data.get(at..at + WORD)
+        .and_then(|word| word.try_into().ok())
+        .ok_or_else(|| abi_err("unexpected end of data"))
+}
+
+fn read_usize(data: &[u8], at: usize) -> StdResult<usize> {
+    let word = read_word(data, at)?;
+    if word[..WORD - 8].iter().any(|b| *b != 0) {
+        return Err(abi_err("length or offset out of range"));
+    }
+    let value = u64::from_be_bytes(word[WORD - 8..].try_into().unwrap());
+    usize::try_from(value).map_err(|_| abi_err("length or offset out of range"))
+}
+
+fn read_bytes(data: &[u8]) -> StdResult<&[u8]> {
+    let len = read_usize(data, 0)?;
+    data.get(WORD..WORD.saturating_add(len))
+        .ok_or_else(|| abi_err("unexpected end of data"))
+}
+
+fn parse_bits(ty: &str, bits: &str) -> StdResult<usize> {
+    match bits.parse::<usize>() {
+        Ok(bits) if bits > 0 && bits <= 256 && bits % 8 == 0 => Ok(bits),
+        _ => Err(abi_err(format!("invalid type `{}`", ty))),
+    }
+}
+
+pub(crate) fn encode_hex(bytes: &[u8]) -> String {
+    const CHARS: &[u8; 16] = b"0123456789abcdef";
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        hex.push(CHARS[(byte >> 4) as usize] as char);
+        hex.push(CHARS[(byte & 0x0f) as usize] as char);
+    }
+    hex
+}
+
+pub(crate) fn decode_hex(hex: &str) -> Result<Vec<u8>, ()> {
+    fn nibble(c: u8) -> Result<u8, ()> {
+        match c {
+            b'0'..=b'9' => Ok(c - b'0'),
+            b'a'..=b'f' => Ok(c - b'a' + 10),
+            b'A'..=b'F' => Ok(c - b'A' + 10),
+            _ => Err(()),
+        }
+    }
+
+    let bytes = hex.as_bytes();
+    let pairs = bytes.chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return Err(());
+    }
+    pairs
+        .map(|pair| Ok(nibble(pair[0])? << 4 | nibble(pair[1])?))
+        .collect()
+}
+
+fn abi_err(msg: impl Into<String>) -> StdError {
+    StdError::generic_err(format!("ABI: {}", msg.into()))
+}
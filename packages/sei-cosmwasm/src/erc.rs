@@ -0,0 +1,281 @@
+use cosmwasm_std::{CosmosMsg, Uint128};
+use cw20::TokenInfoResponse;
+
+use crate::error::SeiResult;
+use crate::msg::SeiMsg;
+use crate::querier::SeiQuerier;
+use crate::query::{Erc721NameSymbolResponse, Erc721RoyaltyInfoResponse, ErcPayloadResponse};
+
+/// Handle to the ERC-20 contract deployed at an EVM address, wrapping the `erc20_*` queries of
+/// `SeiQuerier` and building `SeiMsg::CallEvm` messages from their payloads.
+///
+/// Addresses of accounts are Sei native (bech32-encoded 'sei*') addresses, as in the queries.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Erc20Contract(pub String);
+
+impl Erc20Contract {
+    pub fn new(address: impl Into<String>) -> Self {
+        Erc20Contract(address.into())
+    }
+
+    /// The EVM address of the contract
+    pub fn addr(&self) -> &str {
+        &self.0
+    }
+
+    /// Calls the contract with the base64 encoded `payload`, sending no value
+    pub fn call(&self, payload: ErcPayloadResponse) -> CosmosMsg<SeiMsg> {
+        call(&self.0, payload)
+    }
+
+    /// Returns the balance of `account`
+    pub fn balance(&self, querier: &SeiQuerier, account: impl Into<String>) -> SeiResult<Uint128> {
+        Ok(querier
+            .erc20_balance(self.0.clone(), account.into())?
+            .balance)
+    }
+
+    /// Returns the amount `spender` is still allowed to transfer on behalf of `owner`
+    pub fn allowance(
+        &self,
+        querier: &SeiQuerier,
+        owner: impl Into<String>,
+        spender: impl Into<String>,
+    ) -> SeiResult<Uint128> {
+        let res = querier.erc20_allowance(self.0.clone(), owner.into(), spender.into())?;
+        Ok(res.allowance)
+    }
+
+    /// Returns the name, symbol, decimals and total supply of the token
+    pub fn token_info(
+        &self,
+        querier: &SeiQuerier,
+        caller: impl Into<String>,
+    ) -> SeiResult<TokenInfoResponse> {
+        querier.erc20_token_info(self.0.clone(), caller.into())
+    }
+
+    /// Message transferring `amount` from the sender to `recipient`
+    pub fn transfer(
+        &self,
+        querier: &SeiQuerier,
+        recipient: impl Into<String>,
+        amount: Uint128,
+    ) -> SeiResult<CosmosMsg<SeiMsg>> {
+        let payload = querier.erc20_transfer_payload(recipient.into(), amount)?;
+        Ok(self.call(payload))
+    }
+
+    /// Message transferring `amount` from `owner` to `recipient` out of the allowance of the
+    /// sender
+    pub fn transfer_from(
+        &self,
+        querier: &SeiQuerier,
+        owner: impl Into<String>,
+        recipient: impl Into<String>,
+        amount: Uint128,
+    ) -> SeiResult<CosmosMsg<SeiMsg>> {
+        let payload =
+            querier.erc20_transfer_from_payload(owner.into(), recipient.into(), amount)?;
+        Ok(self.call(payload))
+    }
+
+    /// Message allowing `spender` to transfer up to `amount` on behalf of the sender
+    pub fn approve(
+        &self,
+        querier: &SeiQuerier,
+        spender: impl Into<String>,
+        amount: Uint128,
+    ) -> SeiResult<CosmosMsg<SeiMsg>> {
+        let payload = querier.erc20_approve_payload(spender.into(), amount)?;
+        Ok(self.call(payload))
+    }
+}
+
+/// Handle to the ERC-721 contract deployed at an EVM address, wrapping the `erc721_*` queries of
+/// `SeiQuerier` and building `SeiMsg::CallEvm` messages from their payloads.
+///
+/// Addresses of accounts are Sei native (bech32-encoded 'sei*') addresses, as in the queries.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Erc721Contract(pub String);
+
+impl Erc721Contract {
+    pub fn new(address: impl Into<String>) -> Self {
+        Erc721Contract(address.into())
+    }
+
+    /// The EVM address of the contract
+    pub fn addr(&self) -> &str {
+        &self.0
+    }
+
+    /// Calls the contract with the base64 encoded `payload`, sending no value
+    pub fn call(&self, payload: ErcPayloadResponse) -> CosmosMsg<SeiMsg> {
+        call(&self.0, payload)
+    }
+
+    /// Returns the owner of the NFT `token_id`
+    pub fn owner_of(
+        &self,
+        querier: &SeiQuerier,
+        caller: impl Into<String>,
+        token_id: impl Into<String>,
+    ) -> SeiResult<String> {
+        let res = querier.erc721_owner(caller.into(), self.0.clone(), token_id.into())?;
+        Ok(res.owner)
+    }
+
+    /// Returns the address approved for the NFT `token_id`, the zero address if there is none
+    pub fn approved(
+        &self,
+        querier: &SeiQuerier,
+        caller: impl Into<String>,
+        token_id: impl Into<String>,
+    ) -> SeiResult<String> {
+        let res = querier.erc721_approved(caller.into(), self.0.clone(), token_id.into())?;
+        Ok(res.approved)
+    }
+
+    /// Returns whether `operator` may transfer all the NFTs of `owner`
+    pub fn is_approved_for_all(
+        &self,
+        querier: &SeiQuerier,
+        caller: impl Into<String>,
+        owner: impl Into<String>,
+        operator: impl Into<String>,
+    ) -> SeiResult<bool> {
+        let res = querier.erc721_is_approved_for_all(
+            caller.into(),
+            self.0.clone(),
+            owner.into(),
+            operator.into(),
+        )?;
+        Ok(res.is_approved)
+    }
+
+    /// Returns the name and symbol of the collection
+    pub fn name_symbol(
+        &self,
+        querier: &SeiQuerier,
+        caller: impl Into<String>,
+    ) -> SeiResult<Erc721NameSymbolResponse> {
+        querier.erc721_name_symbol(caller.into(), self.0.clone())
+    }
+
+    /// Returns the URI of the NFT `token_id`
+    pub fn token_uri(
+        &self,
+        querier: &SeiQuerier,
+        caller: impl Into<String>,
+        token_id: impl Into<String>,
+    ) -> SeiResult<String> {
+        let res = querier.erc721_uri(caller.into(), self.0.clone(), token_id.into())?;
+        Ok(res.uri)
+    }
+
+    /// Returns the number of NFTs tracked by the contract
+    pub fn total_supply(
+        &self,
+        querier: &SeiQuerier,
+        caller: impl Into<String>,
+    ) -> SeiResult<Uint128> {
+        let res = querier.erc721_total_supply(caller.into(), self.0.clone())?;
+        Ok(res.supply)
+    }
+
+    /// Returns the NFT at `index` of all the NFTs
+    pub fn token_by_index(
+        &self,
+        querier: &SeiQuerier,
+        caller: impl Into<String>,
+        index: u64,
+    ) -> SeiResult<String> {
+        let res = querier.erc721_token_by_index(caller.into(), self.0.clone(), index)?;
+        Ok(res.token_id)
+    }
+
+    /// Returns the NFT at `index` of the NFTs of `owner`
+    pub fn token_of_owner_by_index(
+        &self,
+        querier: &SeiQuerier,
+        caller: impl Into<String>,
+        owner: impl Into<String>,
+        index: u64,
+    ) -> SeiResult<String> {
+        let res = querier.erc721_token_of_owner_by_index(
+            caller.into(),
+            self.0.clone(),
+            owner.into(),
+            index,
+        )?;
+        Ok(res.token_id)
+    }
+
+    /// Returns the number of NFTs of `owner`
+    pub fn balance_of(
+        &self,
+        querier: &SeiQuerier,
+        caller: impl Into<String>,
+        owner: impl Into<String>,
+    ) -> SeiResult<Uint128> {
+        let res = querier.erc721_balance_of(caller.into(), self.0.clone(), owner.into())?;
+        Ok(res.balance)
+    }
+
+    /// Returns the royalty receiver and amount owed for a sale of the NFT `token_id` at
+    /// `sale_price`
+    pub fn royalty_info(
+        &self,
+        querier: &SeiQuerier,
+        caller: impl Into<String>,
+        token_id: impl Into<String>,
+        sale_price: Uint128,
+    ) -> SeiResult<Erc721RoyaltyInfoResponse> {
+        querier.erc721_royalty_info(caller.into(), self.0.clone(), token_id.into(), sale_price)
+    }
+
+    /// Message transferring the NFT `token_id` from `from` to `recipient`, the sender being the
+    /// owner, approved for the NFT or an operator of `from`
+    pub fn transfer_from(
+        &self,
+        querier: &SeiQuerier,
+        from: impl Into<String>,
+        recipient: impl Into<String>,
+        token_id: impl Into<String>,
+    ) -> SeiResult<CosmosMsg<SeiMsg>> {
+        let payload =
+            querier.erc721_transfer_payload(from.into(), recipient.into(), token_id.into())?;
+        Ok(self.call(payload))
+    }
+
+    /// Message approving `spender` to transfer the NFT `token_id` of the sender
+    pub fn approve(
+        &self,
+        querier: &SeiQuerier,
+        spender: impl Into<String>,
+        token_id: impl Into<String>,
+    ) -> SeiResult<CosmosMsg<SeiMsg>> {
+        let payload = querier.erc721_approve_payload(spender.into(), token_id.into())?;
+        Ok(self.call(payload))
+    }
+
+    /// Message allowing or forbidding `operator` to transfer all the NFTs of the sender
+    pub fn set_approval_for_all(
+        &self,
+        querier: &SeiQuerier,
+        operator: impl Into<String>,
+        approved: bool,
+    ) -> SeiResult<CosmosMsg<SeiMsg>> {
+        let payload = querier.erc721_set_approval_all_payload(operator.into(), approved)?;
+        Ok(self.call(payload))
+    }
+}
+
+fn call(to: &str, payload: ErcPayloadResponse) -> CosmosMsg<SeiMsg> {
+    SeiMsg::CallEvm {
+        value: Uint128::zero(),
+        to: to.to_string(),
+        data: payload.encoded_payload,
+    }
+    .into()
+}
@@ -0,0 +1,391 @@
+use cosmwasm_std::{Decimal, StdError, Uint128};
+use thiserror::Error;
+
+use crate::abi::{abi_decode, decode_hex, encode_hex, AbiToken, AbiType};
+
+/// Selector of the Solidity `Error(string)` revert
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+/// Selector of the Solidity `Panic(uint256)` revert
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Errors of the Sei modules.
+///
+/// Module errors reach contracts as plain strings. Converting a `StdError` returned by a query
+/// (as `SeiQuerier` does) recovers the module variant from the messages emitted by the
+/// `sei-integration-tests` mock chain, which are the `Display` output of these variants. The
+/// messages of the Sei chain modules are not recognized and fall back to `SeiError::Std`.
+#[derive(Error, Debug, PartialEq)]
+pub enum SeiError {
+    #[error("{0}")]
+    Std(StdError),
+
+    // Oracle
+    #[error("oracle: no exchange rate for denom {denom}")]
+    ExchangeRateNotFound { denom: String },
+
+    #[error("oracle: exchange rate of {denom} is stale, last updated at {last_update_timestamp} (max age {max_age_seconds}s)")]
+    StaleExchangeRate {
+        denom: String,
+        /// Unix time in milliseconds
+        last_update_timestamp: u64,
+        max_age_seconds: u64,
+    },
+
+    // Dex
+    #[error("dex: no orders for contract {contract_address}")]
+    OrdersNotFound { contract_address: String },
+
+    #[error("dex: order {id} not found")]
+    OrderNotFound { id: u64 },
+
+    /// The order was filled, cancelled or failed to place
+    #[error("dex: order {id} is not placed")]
+    OrderNotPlaced { id: u64 },
+
+    #[error("dex: {sender} is not the owner of order {id}")]
+    NotOrderOwner { id: u64, sender: String },
+
+    #[error(
+        "dex: pair {asset_denom}/{price_denom} is not registered for contract {contract_address}"
+    )]
+    PairNotRegistered {
+        contract_address: String,
+        price_denom: String,
+        asset_denom: String,
+    },
+
+    /// `field` is `price` or `quantity`
+    #[error("dex: {field} {value} is not a multiple of the {field} tick size {tick_size}")]
+    InvalidTickSize {
+        field: String,
+        value: Decimal,
+        tick_size: Decimal,
+    },
+
+    #[error("dex: invalid order: {reason}")]
+    InvalidOrder { reason: String },
+
+    #[error("dex: no price for pair {asset_denom}/{price_denom} of contract {contract_address}")]
+    PriceNotFound {
+        contract_address: String,
+        price_denom: String,
+        asset_denom: String,
+    },
+
+    #[error("dex: contract {contract_address} is not registered")]
+    ContractNotRegistered { contract_address: String },
+
+    #[error("dex: {sender} is not the creator of contract {contract_address}")]
+    NotContractCreator {
+        contract_address: String,
+        sender: String,
+    },
+
+    #[error("dex: insufficient {denom} funds, {required} required but {provided} sent")]
+    InsufficientFunds {
+        denom: String,
+        required: Uint128,
+        provided: Uint128,
+    },
+
+    // Epoch
+    #[error("epoch: invalid timestamp {timestamp}")]
+    InvalidEpochTimestamp { timestamp: String },
+
+    // Tokenfactory
+    #[error("tokenfactory: denom {denom} already exists")]
+    DenomAlreadyExists { denom: String },
+
+    #[error("tokenfactory: denom {denom} does not exist")]
+    DenomNotFound { denom: String },
+
+    #[error("tokenfactory: must be owner of coin factory denom to {action}")]
+    NotDenomAdmin { action: String },
+
+    #[error("tokenfactory: invalid metadata: {reason}")]
+    InvalidMetadata { reason: String },
+
+    // EVM
+    #[error("execution reverted: {reason}")]
+    EvmRevert { reason: String },
+
+    #[error("evm: address {address} is not associated")]
+    AddressNotAssociated { address: String },
+
+    #[error("evm: address {address} is already associated with {associated_address}")]
+    AddressAlreadyAssociated {
+        address: String,
+        associated_address: String,
+    },
+}
+
+/// Result type of the `SeiQuerier` methods
+pub type SeiResult<T> = Result<T, SeiError>;
+
+impl SeiError {
+    /// Builds an `EvmRevert` from the revert data of an EVM call, see `decode_revert_reason`
+    pub fn evm_revert(data: &[u8]) -> Self {
+        SeiError::EvmRevert {
+            reason: decode_revert_reason(data),
+        }
+    }
+
+    /// Builds the error of a failed EVM static call from the query error. Revert data reported hex
+    /// encoded (`execution reverted: 0x08c379a0...`) is decoded with `decode_revert_reason`, and
+    /// a revert without data is an `EvmRevert` with the reason `0x`.
+    pub fn from_evm_query(err: StdError) -> Self {
+        match SeiError::from(err) {
+            SeiError::EvmRevert { reason } => {
+                match reason
+                    .strip_prefix("0x")
+                    .and_then(|hex| decode_hex(hex).ok())
+                {
+                    Some(data) => SeiError::evm_revert(&data),
+                    None => SeiError::EvmRevert { reason },
+                }
+            }
+            SeiError::Std(StdError::GenericErr { msg, .. })
+                if msg.ends_with("execution reverted") =>
+            {
+                SeiError::evm_revert(&[])
+            }
+            err => err,
+        }
+    }
+
+    /// Recovers the module error from its message as emitted by the `sei-integration-tests`
+    /// mock, possibly wrapped by the querier (`Querier contract error: `) and by contracts
+    /// forwarding the `StdError` (`Generic error: `). Returns `None` for any other message,
+    /// including the errors of the Sei chain.
+    pub fn parse(msg: &str) -> Option<Self> {
+        let mut rest = msg;
+        while let Some(inner) = ["Querier contract error: ", "Generic error: "]
+            .iter()
+            .find_map(|wrapper| rest.strip_prefix(wrapper))
+        {
+            rest = inner;
+        }
+
+        if let Some(reason) = rest.strip_prefix("execution reverted: ") {
+            return Some(SeiError::EvmRevert {
+                reason: reason.to_string(),
+            });
+        }
+        if let Some(action) =
+            rest.strip_prefix("tokenfactory: must be owner of coin factory denom to ")
+        {
+            return Some(SeiError::NotDenomAdmin {
+                action: action.to_string(),
+            });
+        }
+
+        if let Some(denom) = rest.strip_prefix("oracle: no exchange rate for denom ") {
+            return Some(SeiError::ExchangeRateNotFound {
+                denom: denom.to_string(),
+            });
+        }
+        if let Some((denom, rest)) = rest
+            .strip_prefix("oracle: exchange rate of ")
+            .and_then(|rest| rest.split_once(" is stale, last updated at "))
+        {
+            let (last_update_timestamp, max_age_seconds) = rest
+                .strip_suffix("s)")
+                .and_then(|rest| rest.split_once(" (max age "))?;
+            return Some(SeiError::StaleExchangeRate {
+                denom: denom.to_string(),
+                last_update_timestamp: last_update_timestamp.parse().ok()?,
+                max_age_seconds: max_age_seconds.parse().ok()?,
+            });
+        }
+        if let Some(contract_address) = rest.strip_prefix("dex: no orders for contract ") {
+            return Some(SeiError::OrdersNotFound {
+                contract_address: contract_address.to_string(),
+            });
+        }
+        if let Some(id) = rest
+            .strip_prefix("dex: order ")
+            .and_then(|rest| rest.strip_suffix(" not found"))
+        {
+            return id.parse().ok().map(|id| SeiError::OrderNotFound { id });
+        }
+        if let Some(id) = rest
+            .strip_prefix("dex: order ")
+            .and_then(|rest| rest.strip_suffix(" is not placed"))
+        {
+            return id.parse().ok().map(|id| SeiError::OrderNotPlaced { id });
+        }
+        if let Some((pair, contract_address)) = rest
+            .strip_prefix("dex: pair ")
+            .and_then(|rest| rest.split_once(" is not registered for contract "))
+        {
+            let (asset_denom, price_denom) = pair.split_once('/')?;
+            return Some(SeiError::PairNotRegistered {
+                contract_address: contract_address.to_string(),
+                price_denom: price_denom.to_string(),
+                asset_denom: asset_denom.to_string(),
+            });
+        }
+        if let Some((pair, contract_address)) = rest
+            .strip_prefix("dex: no price for pair ")
+            .and_then(|rest| rest.split_once(" of contract "))
+        {
+            let (asset_denom, price_denom) = pair.split_once('/')?;
+            return Some(SeiError::PriceNotFound {
+                contract_address: contract_address.to_string(),
+                price_denom: price_denom.to_string(),
+                asset_denom: asset_denom.to_string(),
+            });
+        }
+        if let Some(contract_address) = rest
+            .strip_prefix("dex: contract ")
+            .and_then(|rest| rest.strip_suffix(" is not registered"))
+        {
+            return Some(SeiError::ContractNotRegistered {
+                contract_address: contract_address.to_string(),
+            });
+        }
+        if let Some((sender, contract_address)) = rest
+            .strip_prefix("dex: ")
+            .and_then(|rest| rest.split_once(" is not the creator of contract "))
+        {
+            return Some(SeiError::NotContractCreator {
+                contract_address: contract_address.to_string(),
+                sender: sender.to_string(),
+            });
+        }
+        if let Some((sender, id)) = rest
+            .strip_prefix("dex: ")
+            .and_then(|rest| rest.split_once(" is not the owner of order "))
+        {
+            return Some(SeiError::NotOrderOwner {
+                id: id.parse().ok()?,
+                sender: sender.to_string(),
+            });
+        }
+        if let Some((field_value, tick)) = rest
+            .strip_prefix("dex: ")
+            .and_then(|rest| rest.split_once(" is not a multiple of the "))
+        {
+            let (field, value) = field_value.split_once(' ')?;
+            let tick_size = tick.strip_prefix(field)?.strip_prefix(" tick size ")?;
+            return Some(SeiError::InvalidTickSize {
+                field: field.to_string(),
+                value: value.parse().ok()?,
+                tick_size: tick_size.parse().ok()?,
+            });
+        }
+        if let Some(reason) = rest.strip_prefix("dex: invalid order: ") {
+            return Some(SeiError::InvalidOrder {
+                reason: reason.to_string(),
+            });
+        }
+        if let Some((denom, rest)) = rest
+            .strip_prefix("dex: insufficient ")
+            .and_then(|rest| rest.split_once(" funds, "))
+        {
+            let (required, provided) = rest
+                .strip_suffix(" sent")
+                .and_then(|rest| rest.split_once(" required but "))?;
+            return Some(SeiError::InsufficientFunds {
+                denom: denom.to_string(),
+                required: required.parse().ok()?,
+                provided: provided.parse().ok()?,
+            });
+        }
+        if let Some(timestamp) = rest.strip_prefix("epoch: invalid timestamp ") {
+            return Some(SeiError::InvalidEpochTimestamp {
+                timestamp: timestamp.to_string(),
+            });
+        }
+        if let Some(denom) = rest
+            .strip_prefix("tokenfactory: denom ")
+            .and_then(|rest| rest.strip_suffix(" already exists"))
+        {
+            return Some(SeiError::DenomAlreadyExists {
+                denom: denom.to_string(),
+            });
+        }
+        if let Some(denom) = rest
+            .strip_prefix("tokenfactory: denom ")
+            .and_then(|rest| rest.strip_suffix(" does not exist"))
+        {
+            return Some(SeiError::DenomNotFound {
+                denom: denom.to_string(),
+            });
+        }
+        if let Some(reason) = rest.strip_prefix("tokenfactory: invalid metadata: ") {
+            return Some(SeiError::InvalidMetadata {
+                reason: reason.to_string(),
+            });
+        }
+        if let Some(address) = rest
+            .strip_prefix("evm: address ")
+            .and_then(|rest| rest.strip_suffix(" is not associated"))
+        {
+            return Some(SeiError::AddressNotAssociated {
+                address: address.to_string(),
+            });
+        }
+        if let Some((address, associated_address)) = rest
+            .strip_prefix("evm: address ")
+            .and_then(|rest| rest.split_once(" is already associated with "))
+        {
+            return Some(SeiError::AddressAlreadyAssociated {
+                address: address.to_string(),
+                associated_address: associated_address.to_string(),
+            });
+        }
+        None
+    }
+}
+
+impl From<StdError> for SeiError {
+    fn from(err: StdError) -> Self {
+        match &err {
+            StdError::GenericErr { msg, .. } => SeiError::parse(msg).unwrap_or(SeiError::Std(err)),
+            _ => SeiError::Std(err),
+        }
+    }
+}
+
+impl From<SeiError> for StdError {
+    fn from(err: SeiError) -> Self {
+        match err {
+            SeiError::Std(err) => err,
+            err => StdError::generic_err(err.to_string()),
+        }
+    }
+}
+
+/// Decodes the revert data of an EVM call: the message of a `require`/`revert` with a reason
+/// (`Error(string)`), `panic code 0x..` for a `Panic(uint256)` (e.g. an overflow), and the hex
+/// encoded data of any other (custom) error.
+pub fn decode_revert_reason(data: &[u8]) -> String {
+    if data.len() >= 4 {
+        let (selector, args) = data.split_at(4);
+        if selector == ERROR_SELECTOR {
+            if let Ok(tokens) = abi_decode(&[AbiType::String], args) {
+                if let [AbiToken::String(reason)] = tokens.as_slice() {
+                    return reason.clone();
+                }
+            }
+        }
+        if selector == PANIC_SELECTOR {
+            if let Ok(tokens) = abi_decode(&[AbiType::Uint(256)], args) {
+                if let [AbiToken::Uint(code)] = tokens.as_slice() {
+                    let code = code.to_be_bytes();
+                    return format!("panic code 0x{}", encode_hex(trim_leading_zeros(&code)));
+                }
+            }
+        }
+    }
+    format!("0x{}", encode_hex(data))
+}
+
+fn trim_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let start = bytes
+        .iter()
+        .position(|b| *b != 0)
+        .unwrap_or(bytes.len() - 1);
+    &bytes[start..]
+}
@@ -0,0 +1,160 @@
+use cosmwasm_std::{from_json, to_json_binary, DepsMut, Env, Response, StdError, StdResult};
+use serde::de::DeserializeOwned;
+
+use crate::msg::{SeiMsg, SudoMsg};
+use crate::query::SeiQueryWrapper;
+use crate::sei_types::{
+    BulkOrderPlacementsResponse, DepositInfo, Order, SettlementEntry, UnsuccessfulOrder,
+};
+
+/// Order placed with a dex contract, with its `data` deserialized into the contract's order
+/// data type
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypedOrder<T> {
+    /// Position of the order in its `BulkOrderPlacements` batch, the id to list it under in
+    /// `OrderPlacements::unsuccessful_orders`
+    pub id: u64,
+    pub order: Order,
+    pub data: T,
+}
+
+impl<T: DeserializeOwned> TypedOrder<T> {
+    /// Deserializes the JSON `data` of `order`, the `id`th order of its batch. Empty data is read
+    /// as `null`, so orders placed without data parse into an `Option` or `()` order data type.
+    pub fn parse(id: u64, order: Order) -> StdResult<Self> {
+        let data = if order.data.is_empty() {
+            from_json(b"null")?
+        } else {
+            from_json(order.data.as_bytes())?
+        };
+        Ok(TypedOrder { id, order, data })
+    }
+}
+
+/// Result of `DexHooks::order_placements`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OrderPlacements {
+    pub response: Response<SeiMsg>,
+    /// Orders rejected by the contract, removed from the order book by the dex module
+    pub unsuccessful_orders: Vec<UnsuccessfulOrder>,
+}
+
+impl From<Response<SeiMsg>> for OrderPlacements {
+    fn from(response: Response<SeiMsg>) -> Self {
+        OrderPlacements {
+            response,
+            unsuccessful_orders: vec![],
+        }
+    }
+}
+
+/// Dex end block hooks of a contract, with the order data of the contract as `OrderData`.
+///
+/// `sudo` dispatches the dex `SudoMsg` to the hooks, so the contract's sudo entry point only
+/// forwards it:
+///
+/// ```
+/// # use cosmwasm_std::{DepsMut, Env, Response, StdError};
+/// # use serde::Deserialize;
+/// # use sei_cosmwasm::{DepositInfo, DexHooks, OrderPlacements, SeiMsg, SeiQueryWrapper, SudoMsg, TypedOrder};
+/// #[derive(Deserialize)]
+/// struct OrderData {
+///     leverage: String,
+/// }
+///
+/// struct Hooks;
+///
+/// impl DexHooks for Hooks {
+///     type OrderData = OrderData;
+///     type Error = StdError;
+///
+///     fn order_placements(
+///         &self,
+///         _deps: DepsMut<SeiQueryWrapper>,
+///         _env: Env,
+///         orders: Vec<TypedOrder<OrderData>>,
+///         _deposits: Vec<DepositInfo>,
+///     ) -> Result<OrderPlacements, StdError> {
+///         let leverages: Vec<String> = orders.into_iter().map(|o| o.data.leverage).collect();
+///         Ok(Response::new().add_attribute("leverages", leverages.join(",")).into())
+///     }
+/// }
+///
+/// pub fn sudo(
+///     deps: DepsMut<SeiQueryWrapper>,
+///     env: Env,
+///     msg: SudoMsg,
+/// ) -> Result<Response<SeiMsg>, StdError> {
+///     Hooks.sudo(deps, env, msg)
+/// }
+/// ```
+pub trait DexHooks {
+    type OrderData: DeserializeOwned;
+    type Error: From<StdError>;
+
+    /// Called with the fills of the contract's orders matched at the end of the block
+    fn settlement(
+        &self,
+        _deps: DepsMut<SeiQueryWrapper>,
+        _env: Env,
+        _epoch: i64,
+        _entries: Vec<SettlementEntry>,
+    ) -> Result<Response<SeiMsg>, Self::Error> {
+        Ok(Response::new())
+    }
+
+    /// Called with the orders placed with the contract and the funds deposited with them during
+    /// the block
+    fn order_placements(
+        &self,
+        deps: DepsMut<SeiQueryWrapper>,
+        env: Env,
+        orders: Vec<TypedOrder<Self::OrderData>>,
+        deposits: Vec<DepositInfo>,
+    ) -> Result<OrderPlacements, Self::Error>;
+
+    /// Called with the ids of the contract's orders cancelled during the block
+    fn order_cancellations(
+        &self,
+        _deps: DepsMut<SeiQueryWrapper>,
+        _env: Env,
+        _ids: Vec<u64>,
+    ) -> Result<Response<SeiMsg>, Self::Error> {
+        Ok(Response::new())
+    }
+
+    /// Dispatches `msg` to the hooks. The data of the placements response is replaced by the
+    /// `BulkOrderPlacementsResponse` listing the unsuccessful orders, as the dex module expects.
+    /// Orders whose data does not deserialize into `OrderData` are not passed to
+    /// `order_placements` but listed as unsuccessful, with their position in the batch as id.
+    fn sudo(
+        &self,
+        deps: DepsMut<SeiQueryWrapper>,
+        env: Env,
+        msg: SudoMsg,
+    ) -> Result<Response<SeiMsg>, Self::Error> {
+        match msg {
+            SudoMsg::Settlement { epoch, entries } => self.settlement(deps, env, epoch, entries),
+            SudoMsg::BulkOrderPlacements { orders, deposits } => {
+                let mut typed_orders = vec![];
+                let mut malformed_orders = vec![];
+                for (id, order) in orders.into_iter().enumerate() {
+                    match TypedOrder::parse(id as u64, order) {
+                        Ok(order) => typed_orders.push(order),
+                        Err(err) => malformed_orders.push(UnsuccessfulOrder {
+                            id: id as u64,
+                            reason: format!("invalid order data: {}", err),
+                        }),
+                    }
+                }
+                let placements = self.order_placements(deps, env, typed_orders, deposits)?;
+                malformed_orders.extend(placements.unsuccessful_orders);
+                let data = to_json_binary(&BulkOrderPlacementsResponse {
+                    unsuccessful_orders: malformed_orders,
+                })?;
+                Ok(placements.response.set_data(data))
+            }
+            SudoMsg::BulkOrderCancellations { ids } => self.order_cancellations(deps, env, ids),
+        }
+    }
+}
@@ -1,28 +1,69 @@
+mod abi;
+mod erc;
+mod error;
+mod hooks;
 mod msg;
+mod oracle;
+mod order;
+mod pagination;
+mod positions;
+mod proto;
 mod proto_structs;
 mod querier;
 mod query;
+mod reply;
 mod route;
 mod sei_types;
+mod time;
 mod tx;
 
+pub use abi::{
+    abi_decode, abi_encode, encode_function_call, function_selector, keccak256, parse_evm_address,
+    to_checksum_address, AbiToken, AbiType,
+};
+pub use erc::{Erc20Contract, Erc721Contract};
+pub use error::{decode_revert_reason, SeiError, SeiResult};
+pub use hooks::{DexHooks, OrderPlacements, TypedOrder};
 pub use msg::{SeiMsg, SudoMsg};
+pub use oracle::{check_exchange_rate_age, OraclePriceOptions};
+pub use order::{required_funds, OrderBuilder};
+pub use pagination::{Page, PageIter};
+pub use positions::{Position, PositionBook, PositionsResponse};
+pub use proto::MsgResponse;
 pub use proto_structs::{
     DenomOracleExchangeRatePair, DexPair, DexTwap, Epoch, OracleExchangeRate, OracleTwap,
 };
 pub use querier::SeiQuerier;
 pub use query::{
     DenomAuthorityMetadataResponse, DenomsFromCreatorResponse, DexTwapsResponse, EpochResponse,
-    EvmAddressResponse, ExchangeRatesResponse, GetLatestPriceResponse, GetOrderByIdResponse,
-    GetOrdersResponse, OracleTwapsResponse, OrderSimulationResponse, PriceResponse,
-    SeiAddressResponse, SeiQuery, SeiQueryWrapper, StaticCallResponse,
+    Erc1155BalanceOfBatchResponse, Erc1155BalanceOfResponse, Erc1155IsApprovedForAllResponse,
+    Erc1155RoyaltyInfoResponse, Erc1155UriResponse, Erc20AllowanceResponse, Erc721ApprovedResponse,
+    Erc721BalanceOfResponse, Erc721IsApprovedForAllResponse, Erc721NameSymbolResponse,
+    Erc721OwnerResponse, Erc721RoyaltyInfoResponse, Erc721TokenByIndexResponse,
+    Erc721TokenOfOwnerByIndexResponse, Erc721TotalSupplyResponse, Erc721UriResponse,
+    ErcPayloadResponse, EvmAddressResponse, ExchangeRatesResponse, GetHistoricalPricesResponse,
+    GetLatestPriceResponse, GetOrderByIdResponse, GetOrdersResponse, GetRegisteredPairsResponse,
+    MarketSummaryResponse, OracleTwapsResponse, OrderBookDepthResponse, OrderSimulationResponse,
+    PriceCandlestick, PriceLevel, PriceResponse, SeiAddressResponse, SeiQuery, SeiQueryWrapper,
+    StaticCallResponse,
+};
+pub use reply::{
+    parse_reply_burn_tokens, parse_reply_call_evm, parse_reply_cancel_orders,
+    parse_reply_change_admin, parse_reply_create_denom, parse_reply_mint_tokens,
+    parse_reply_place_orders, parse_reply_set_metadata,
 };
 pub use route::SeiRoute;
 pub use sei_types::{
-    BulkOrderPlacementsResponse, Cancellation, DenomUnit, DepositInfo, Metadata, Order,
-    OrderResponse, OrderStatus, OrderType, PositionDirection, SettlementEntry,
+    BulkOrderPlacementsResponse, Cancellation, DenomAuthorityMetadata, DenomUnit, DepositInfo,
+    Metadata, Order, OrderResponse, OrderStatus, OrderType, PositionDirection, SettlementEntry,
+    UnsuccessfulOrder,
+};
+pub use time::{format_rfc3339, parse_rfc3339};
+pub use tx::{
+    MsgBurnResponse, MsgCancelOrdersResponse, MsgChangeAdminResponse, MsgCreateDenomResponse,
+    MsgInternalEVMCallResponse, MsgMintResponse, MsgPlaceOrdersResponse,
+    MsgSetDenomMetadataResponse,
 };
-pub use tx::MsgPlaceOrdersResponse;
 
 // This export is added to all contracts that import this package, signifying that they require
 // "sei" support on the chain they run on.
@@ -1,5 +1,9 @@
+use crate::abi::{encode_function_call, AbiToken};
+use crate::error::{SeiError, SeiResult};
+use crate::order::check_funds;
+use crate::proto_structs::DexPair;
 use crate::sei_types::{Cancellation, DepositInfo, Metadata, Order, SettlementEntry};
-use cosmwasm_std::{Addr, Coin, CosmosMsg, CustomMsg, Uint128};
+use cosmwasm_std::{Addr, Binary, Coin, CosmosMsg, CustomMsg, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -25,6 +29,39 @@ pub enum SeiMsg {
         cancellations: Vec<Cancellation>,
         contract_address: Addr,
     },
+    /// Registers `contract_address`, an instance of `code_id`, with the dex module and deposits
+    /// `rent` usei from the sender to pay for its execution. The sender becomes the creator of
+    /// the contract registration and can re-register it to change its settings.
+    RegisterContract {
+        contract_address: Addr,
+        code_id: u64,
+        /// Whether the dex calls the contract's end block sudo hooks
+        need_hook: bool,
+        /// Whether the dex matches the orders placed with the contract
+        need_order_matching: bool,
+        rent: Uint128,
+    },
+    /// Registers `pairs` for a contract registered by the sender, replacing the tick sizes of
+    /// pairs already registered
+    RegisterPairs {
+        contract_address: Addr,
+        pairs: Vec<DexPair>,
+    },
+    /// Sets the price and quantity tick sizes of registered pairs of a contract registered by
+    /// the sender
+    UpdateTickSizes {
+        contract_address: Addr,
+        pairs: Vec<DexPair>,
+    },
+    /// Tops up the rent of a registered contract with `amount` usei from the sender
+    DepositRent {
+        contract_address: Addr,
+        amount: Uint128,
+    },
+    /// Unregisters a contract registered by the sender, refunding its remaining rent
+    UnregisterContract {
+        contract_address: Addr,
+    },
     CreateDenom {
         subdenom: String,
     },
@@ -67,6 +104,69 @@ pub enum SeiMsg {
     },
 }
 
+impl SeiMsg {
+    /// Builds a [`SeiMsg::PlaceOrders`] after checking that each order is for one of the
+    /// `pairs` of the dex contract, with prices and quantities on the pair's ticks and the
+    /// fields of its order type (see [`Order::check_order_type`]), and that
+    /// `funds` cover the [`required_funds`](crate::required_funds) of the orders.
+    pub fn place_orders(
+        orders: Vec<Order>,
+        funds: Vec<Coin>,
+        contract_address: Addr,
+        pairs: &[DexPair],
+    ) -> SeiResult<Self> {
+        for order in &orders {
+            let pair = pairs
+                .iter()
+                .find(|p| p.price_denom == order.price_denom && p.asset_denom == order.asset_denom)
+                .ok_or_else(|| SeiError::PairNotRegistered {
+                    contract_address: contract_address.to_string(),
+                    price_denom: order.price_denom.clone(),
+                    asset_denom: order.asset_denom.clone(),
+                })?;
+            pair.check_ticks(order)?;
+            order.check_order_type()?;
+        }
+        check_funds(&orders, &funds)?;
+        Ok(SeiMsg::PlaceOrders {
+            orders,
+            funds,
+            contract_address,
+        })
+    }
+
+    /// Builds a [`SeiMsg::CallEvm`] calling the function with the canonical `signature`
+    /// (e.g. `transfer(address,uint256)`) on the EVM contract at `to`. The arguments are ABI
+    /// encoded and base64 encoded as expected by the chain. Fails if the arguments cannot be
+    /// encoded.
+    pub fn call_evm(
+        value: Uint128,
+        to: impl Into<String>,
+        signature: &str,
+        args: &[AbiToken],
+    ) -> SeiResult<Self> {
+        Ok(SeiMsg::CallEvm {
+            value,
+            to: to.into(),
+            data: Binary::from(encode_function_call(signature, args)?).to_base64(),
+        })
+    }
+
+    /// Builds a [`SeiMsg::DelegateCallEvm`] calling the function with the canonical `signature`
+    /// on the EVM contract at `to` with ABI encoded `args`. Fails if the arguments cannot be
+    /// encoded.
+    pub fn delegate_call_evm(
+        to: impl Into<String>,
+        signature: &str,
+        args: &[AbiToken],
+    ) -> SeiResult<Self> {
+        Ok(SeiMsg::DelegateCallEvm {
+            to: to.into(),
+            data: Binary::from(encode_function_call(signature, args)?).to_base64(),
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SudoMsg {
@@ -0,0 +1,93 @@
+use cosmwasm_std::{Decimal, StdError, Timestamp};
+
+use crate::error::{SeiError, SeiResult};
+use crate::proto_structs::{DenomOracleExchangeRatePair, OracleExchangeRate, OracleTwap};
+
+/// Freshness requirements of the prices returned by `SeiQuerier::query_oracle_price` and
+/// `SeiQuerier::query_checked_price_in`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OraclePriceOptions {
+    /// Current block time, i.e. `env.block.time`
+    pub block_time: Timestamp,
+    /// Maximum age of the latest exchange rate
+    pub max_age_seconds: u64,
+    /// Lookback of the TWAP used instead of a stale exchange rate, no fallback if `None`
+    pub twap_lookback_seconds: Option<u64>,
+}
+
+impl OraclePriceOptions {
+    pub fn new(block_time: Timestamp, max_age_seconds: u64) -> Self {
+        OraclePriceOptions {
+            block_time,
+            max_age_seconds,
+            twap_lookback_seconds: None,
+        }
+    }
+
+    /// Falls back to the TWAP over `lookback_seconds` when the latest exchange rate is stale
+    pub fn with_twap_fallback(mut self, lookback_seconds: u64) -> Self {
+        self.twap_lookback_seconds = Some(lookback_seconds);
+        self
+    }
+}
+
+/// Returns the exchange rate of `denom` in `rates`
+pub(crate) fn find_exchange_rate(
+    rates: &[DenomOracleExchangeRatePair],
+    denom: &str,
+) -> SeiResult<OracleExchangeRate> {
+    rates
+        .iter()
+        .find(|r| r.denom == denom)
+        .map(|r| r.oracle_exchange_rate.clone())
+        .ok_or_else(|| SeiError::ExchangeRateNotFound {
+            denom: denom.to_string(),
+        })
+}
+
+/// Returns the TWAP of `denom` in `twaps`
+pub(crate) fn find_oracle_twap(twaps: &[OracleTwap], denom: &str) -> SeiResult<Decimal> {
+    twaps
+        .iter()
+        .find(|t| t.denom == denom)
+        .map(|t| t.twap)
+        .ok_or_else(|| SeiError::ExchangeRateNotFound {
+            denom: denom.to_string(),
+        })
+}
+
+/// Fails with `SeiError::StaleExchangeRate` if the exchange rate of `denom` was last updated
+/// more than `max_age_seconds` before `block_time`. `last_update_timestamp` is in milliseconds,
+/// as set by the oracle module.
+pub fn check_exchange_rate_age(
+    denom: &str,
+    rate: &OracleExchangeRate,
+    block_time: Timestamp,
+    max_age_seconds: u64,
+) -> SeiResult<()> {
+    let now_millis = block_time.nanos() / 1_000_000;
+    let age_millis = now_millis.saturating_sub(rate.last_update_timestamp);
+    if age_millis > max_age_seconds.saturating_mul(1_000) {
+        return Err(SeiError::StaleExchangeRate {
+            denom: denom.to_string(),
+            last_update_timestamp: rate.last_update_timestamp,
+            max_age_seconds,
+        });
+    }
+    Ok(())
+}
+
+/// Price of one unit of `base` in units of `quote`, given their exchange rates against the
+/// oracle's reference currency
+pub(crate) fn cross_rate(
+    base_rate: Decimal,
+    quote: &str,
+    quote_rate: Decimal,
+) -> SeiResult<Decimal> {
+    base_rate.checked_div(quote_rate).map_err(|_| {
+        SeiError::Std(StdError::generic_err(format!(
+            "zero exchange rate for denom {}",
+            quote
+        )))
+    })
+}
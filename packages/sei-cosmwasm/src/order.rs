@@ -0,0 +1,284 @@
+use cosmwasm_std::{to_json_string, Coin, Decimal, StdResult, Uint128};
+use serde::Serialize;
+
+use crate::error::{SeiError, SeiResult};
+use crate::proto_structs::DexPair;
+use crate::sei_types::{Order, OrderType, PositionDirection};
+
+/// Builds an `Order` for `SeiMsg::PlaceOrders`, long by default:
+///
+/// ```
+/// # use cosmwasm_std::Decimal;
+/// # use sei_cosmwasm::{OrderBuilder, PositionDirection};
+/// let order = OrderBuilder::limit("USDC", "ATOM", Decimal::percent(1050), Decimal::one())
+///     .short()
+///     .data(&("open", 1))
+///     .build()
+///     .unwrap();
+/// assert_eq!(order.position_direction, PositionDirection::Short);
+/// assert_eq!(order.data, r#"["open",1]"#);
+/// ```
+#[derive(Debug)]
+pub struct OrderBuilder {
+    order: Order,
+    data: StdResult<String>,
+}
+
+impl OrderBuilder {
+    /// Order for `quantity` of `asset_denom` at `price`, in `price_denom` per unit
+    pub fn limit(
+        price_denom: impl Into<String>,
+        asset_denom: impl Into<String>,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> Self {
+        Self::new(OrderType::Limit, price_denom, asset_denom, price, quantity)
+    }
+
+    /// Order for `quantity` of `asset_denom` at the best price in the book, `price` being the
+    /// worst price accepted
+    pub fn market(
+        price_denom: impl Into<String>,
+        asset_denom: impl Into<String>,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> Self {
+        Self::new(OrderType::Market, price_denom, asset_denom, price, quantity)
+    }
+
+    /// Market order cancelled unless all of `quantity` can be filled, at no worse than `price`
+    /// (zero for any price)
+    pub fn fok_market(
+        price_denom: impl Into<String>,
+        asset_denom: impl Into<String>,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> Self {
+        Self::new(
+            OrderType::FokMarket,
+            price_denom,
+            asset_denom,
+            price,
+            quantity,
+        )
+    }
+
+    /// Market order for the `nominal` value in `price_denom`, cancelled unless it can be
+    /// completely filled at no worse than `price` (zero for any price)
+    pub fn fok_market_by_value(
+        price_denom: impl Into<String>,
+        asset_denom: impl Into<String>,
+        price: Decimal,
+        nominal: Decimal,
+    ) -> Self {
+        let mut builder = Self::new(
+            OrderType::FokMarketByValue,
+            price_denom,
+            asset_denom,
+            price,
+            Decimal::zero(),
+        );
+        builder.order.nominal = nominal;
+        builder
+    }
+
+    /// Market order placed once the last traded price reaches `trigger_price`
+    pub fn stop_loss(
+        price_denom: impl Into<String>,
+        asset_denom: impl Into<String>,
+        trigger_price: Decimal,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> Self {
+        let mut builder = Self::new(
+            OrderType::StopLoss,
+            price_denom,
+            asset_denom,
+            price,
+            quantity,
+        );
+        builder.order.trigger_price = Some(trigger_price);
+        builder
+    }
+
+    /// Limit order at `price` placed once the last traded price reaches `trigger_price`
+    pub fn stop_limit(
+        price_denom: impl Into<String>,
+        asset_denom: impl Into<String>,
+        trigger_price: Decimal,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> Self {
+        let mut builder = Self::new(
+            OrderType::StopLimit,
+            price_denom,
+            asset_denom,
+            price,
+            quantity,
+        );
+        builder.order.trigger_price = Some(trigger_price);
+        builder
+    }
+
+    fn new(
+        order_type: OrderType,
+        price_denom: impl Into<String>,
+        asset_denom: impl Into<String>,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> Self {
+        OrderBuilder {
+            order: Order {
+                price,
+                quantity,
+                price_denom: price_denom.into(),
+                asset_denom: asset_denom.into(),
+                order_type,
+                position_direction: PositionDirection::Long,
+                data: String::new(),
+                status_description: String::new(),
+                nominal: Decimal::zero(),
+                trigger_price: None,
+            },
+            data: Ok(String::new()),
+        }
+    }
+
+    pub fn long(self) -> Self {
+        self.direction(PositionDirection::Long)
+    }
+
+    pub fn short(self) -> Self {
+        self.direction(PositionDirection::Short)
+    }
+
+    pub fn direction(mut self, position_direction: PositionDirection) -> Self {
+        self.order.position_direction = position_direction;
+        self
+    }
+
+    /// Sets the order data passed to the dex contract, serialized as JSON
+    pub fn data<T: Serialize>(mut self, data: &T) -> Self {
+        self.data = to_json_string(data);
+        self
+    }
+
+    /// Sets the order data passed to the dex contract as is
+    pub fn raw_data(mut self, data: impl Into<String>) -> Self {
+        self.data = Ok(data.into());
+        self
+    }
+
+    /// Returns the order, failing if its data could not be serialized or it lacks the fields
+    /// of its order type, see `Order::check_order_type`
+    pub fn build(self) -> SeiResult<Order> {
+        let order = Order {
+            data: self.data?,
+            ..self.order
+        };
+        order.check_order_type()?;
+        Ok(order)
+    }
+}
+
+impl Order {
+    /// Fails with `SeiError::InvalidOrder` if the order lacks a field required by its order
+    /// type: the `trigger_price` of stop orders and the `nominal` of `FokMarketByValue` orders.
+    /// `Liquidation` orders can only be placed by the chain.
+    pub fn check_order_type(&self) -> SeiResult<()> {
+        let reason = match self.order_type {
+            OrderType::Liquidation => "liquidation orders are placed by the chain",
+            OrderType::StopLoss | OrderType::StopLimit
+                if self.trigger_price.map_or(true, |price| price.is_zero()) =>
+            {
+                "stop orders need a trigger price"
+            }
+            OrderType::FokMarketByValue if self.nominal.is_zero() => {
+                "fill-or-kill by value orders need a nominal"
+            }
+            _ => return Ok(()),
+        };
+        Err(SeiError::InvalidOrder {
+            reason: reason.to_string(),
+        })
+    }
+}
+
+impl DexPair {
+    /// Fails with `SeiError::InvalidTickSize` unless the price, trigger price and quantity of
+    /// `order` are multiples of the tick sizes of the pair
+    pub fn check_ticks(&self, order: &Order) -> SeiResult<()> {
+        if !is_multiple_of(order.price, self.price_tick_size) {
+            return Err(SeiError::InvalidTickSize {
+                field: "price".to_string(),
+                value: order.price,
+                tick_size: self.price_tick_size,
+            });
+        }
+        if !is_multiple_of(order.quantity, self.quantity_tick_size) {
+            return Err(SeiError::InvalidTickSize {
+                field: "quantity".to_string(),
+                value: order.quantity,
+                tick_size: self.quantity_tick_size,
+            });
+        }
+        if let Some(trigger_price) = order.trigger_price {
+            if !is_multiple_of(trigger_price, self.price_tick_size) {
+                return Err(SeiError::InvalidTickSize {
+                    field: "trigger_price".to_string(),
+                    value: trigger_price,
+                    tick_size: self.price_tick_size,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Funds to send with `orders`: the price times the quantity of long orders (the nominal of
+/// `FokMarketByValue` orders) in the price denom, and the quantity of short orders in the asset
+/// denom, rounded up. Sorted by denom.
+pub fn required_funds(orders: &[Order]) -> Vec<Coin> {
+    let mut funds: Vec<Coin> = vec![];
+    for order in orders {
+        let (denom, amount) = match (order.position_direction, order.order_type) {
+            (PositionDirection::Long, OrderType::FokMarketByValue) => {
+                (&order.price_denom, order.nominal)
+            }
+            (PositionDirection::Long, _) => (&order.price_denom, order.price * order.quantity),
+            (PositionDirection::Short, _) => (&order.asset_denom, order.quantity),
+        };
+        let amount = amount.to_uint_ceil();
+        match funds.iter_mut().find(|c| &c.denom == denom) {
+            Some(coin) => coin.amount += amount,
+            None => funds.push(Coin::new(amount.u128(), denom.clone())),
+        }
+    }
+    funds.retain(|c| !c.amount.is_zero());
+    funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+    funds
+}
+
+/// Fails with `SeiError::InsufficientFunds` if `funds` do not cover the `required_funds` of
+/// `orders`
+pub(crate) fn check_funds(orders: &[Order], funds: &[Coin]) -> SeiResult<()> {
+    for required in required_funds(orders) {
+        let provided: Uint128 = funds
+            .iter()
+            .filter(|c| c.denom == required.denom)
+            .map(|c| c.amount)
+            .sum();
+        if provided < required.amount {
+            return Err(SeiError::InsufficientFunds {
+                denom: required.denom,
+                required: required.amount,
+                provided,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn is_multiple_of(value: Decimal, tick_size: Decimal) -> bool {
+    tick_size.is_zero() || (value.atomics() % tick_size.atomics()).is_zero()
+}
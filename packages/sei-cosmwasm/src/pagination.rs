@@ -0,0 +1,52 @@
+use std::vec::IntoIter;
+
+use crate::error::SeiResult;
+
+/// Page of a paginated query: the items and the `start_after` key of the next page, `None` on
+/// the last page
+pub type Page<T, K> = (Vec<T>, Option<K>);
+
+type FetchPage<'a, T, K> = Box<dyn FnMut(Option<K>) -> SeiResult<Page<T, K>> + 'a>;
+
+/// Iterator over the items of a paginated query, fetching the pages lazily as the items are
+/// consumed. Returned by the `SeiQuerier::*_iter` methods.
+///
+/// A failed page query is returned as an item, after which the iteration ends.
+pub struct PageIter<'a, T, K> {
+    fetch: FetchPage<'a, T, K>,
+    items: IntoIter<T>,
+    /// `start_after` of the next page to fetch, `None` once the last page was fetched
+    next_page: Option<Option<K>>,
+}
+
+impl<'a, T, K> PageIter<'a, T, K> {
+    /// Iterates over the pages returned by `fetch`, called with the `start_after` key of the
+    /// page to fetch
+    pub fn new(fetch: impl FnMut(Option<K>) -> SeiResult<Page<T, K>> + 'a) -> Self {
+        PageIter {
+            fetch: Box::new(fetch),
+            items: Vec::new().into_iter(),
+            next_page: Some(None),
+        }
+    }
+}
+
+impl<'a, T, K> Iterator for PageIter<'a, T, K> {
+    type Item = SeiResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.items.next() {
+                return Some(Ok(item));
+            }
+            let start_after = self.next_page.take()?;
+            match (self.fetch)(start_after) {
+                Ok((items, next_key)) => {
+                    self.items = items.into_iter();
+                    self.next_page = next_key.map(Some);
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
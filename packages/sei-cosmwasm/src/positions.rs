@@ -0,0 +1,206 @@
+use cosmwasm_std::{Decimal, SignedDecimal, StdError, StdResult, Storage};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::sei_types::{PositionDirection, SettlementEntry};
+
+/// Position of an account in a (price denom, asset denom) pair, built from the fills of its
+/// orders
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct Position {
+    pub account: String,
+    pub price_denom: String,
+    pub asset_denom: String,
+    /// Open quantity of the asset, positive when long and negative when short
+    pub quantity: SignedDecimal,
+    /// Average price the open quantity was entered at, zero without an open quantity
+    pub entry_price: Decimal,
+    /// Profit and loss of the closed quantity, in the price denom
+    pub realized_pnl: SignedDecimal,
+    /// Difference between the cost or proceeds expected at the order prices, which the deposits
+    /// of the orders are based on, and those of the executions, in the price denom. Positive
+    /// when fills paid less or received more than expected, i.e. the deposit left to return.
+    pub deposit_surplus: SignedDecimal,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PositionsResponse {
+    pub positions: Vec<Position>,
+}
+
+impl Position {
+    pub fn new(
+        account: impl Into<String>,
+        price_denom: impl Into<String>,
+        asset_denom: impl Into<String>,
+    ) -> Self {
+        Position {
+            account: account.into(),
+            price_denom: price_denom.into(),
+            asset_denom: asset_denom.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_flat(&self) -> bool {
+        self.quantity.is_zero()
+    }
+
+    /// Unrealized profit and loss of the open quantity at `price`
+    pub fn unrealized_pnl(&self, price: Decimal) -> StdResult<SignedDecimal> {
+        let diff = signed(price)?.checked_sub(signed(self.entry_price)?)?;
+        Ok(diff.checked_mul(self.quantity)?)
+    }
+
+    /// Applies the fill of `entry` at its `execution_cost_or_proceed` price. Fills in the
+    /// direction of the position add to it at the average price, fills against it realize the
+    /// PnL of the quantity they close, and the rest opens a position in the other direction.
+    /// Zero quantity fills leave the position unchanged, and amounts out of the `SignedDecimal`
+    /// range fail the whole fill.
+    pub fn apply(&mut self, entry: &SettlementEntry) -> StdResult<()> {
+        if entry.quantity.is_zero() {
+            return Ok(());
+        }
+        let price = signed(entry.execution_cost_or_proceed)?;
+        let expected = signed(entry.expected_cost_or_proceed)?;
+        let fill = match entry.position_direction {
+            PositionDirection::Long => signed(entry.quantity)?,
+            PositionDirection::Short => -signed(entry.quantity)?,
+        };
+        let deposit_surplus = self
+            .deposit_surplus
+            .checked_add(expected.checked_sub(price)?.checked_mul(fill)?)?;
+
+        let open = self.quantity;
+        let entry_price = signed(self.entry_price)?;
+        let quantity = open.checked_add(fill)?;
+        if open.is_zero() || open.is_negative() == fill.is_negative() {
+            let cost = entry_price
+                .checked_mul(open)?
+                .checked_add(price.checked_mul(fill)?)?;
+            self.entry_price = unsigned(
+                cost.checked_div(quantity)
+                    .map_err(|err| StdError::generic_err(err.to_string()))?,
+            )?;
+            self.quantity = quantity;
+            self.deposit_surplus = deposit_surplus;
+            return Ok(());
+        }
+
+        let zero = SignedDecimal::zero();
+        let closed = if fill.abs_diff(zero) < open.abs_diff(zero) {
+            -fill
+        } else {
+            open
+        };
+        self.realized_pnl = self
+            .realized_pnl
+            .checked_add(price.checked_sub(entry_price)?.checked_mul(closed)?)?;
+        self.quantity = quantity;
+        self.deposit_surplus = deposit_surplus;
+        if self.quantity.is_zero() {
+            self.entry_price = Decimal::zero();
+        } else if self.quantity.is_negative() != open.is_negative() {
+            self.entry_price = entry.execution_cost_or_proceed;
+        }
+        Ok(())
+    }
+}
+
+/// Positions of the accounts trading through a dex contract, stored per account and pair under
+/// `namespace`.
+///
+/// ```
+/// # use cosmwasm_std::testing::MockStorage;
+/// # use cosmwasm_std::{Decimal, SignedDecimal};
+/// # use sei_cosmwasm::{OrderType, PositionBook, PositionDirection, SettlementEntry};
+/// const POSITIONS: PositionBook = PositionBook::new("positions");
+///
+/// # let mut storage = MockStorage::new();
+/// let entry = SettlementEntry {
+///     account: "alice".to_string(),
+///     price_denom: "USDC".to_string(),
+///     asset_denom: "ATOM".to_string(),
+///     quantity: Decimal::percent(200),
+///     execution_cost_or_proceed: Decimal::percent(1000),
+///     expected_cost_or_proceed: Decimal::percent(1100),
+///     position_direction: PositionDirection::Long,
+///     order_type: OrderType::Limit,
+///     order_id: 1,
+///     timestamp: 0,
+///     height: 0,
+///     settlement_id: 1,
+/// };
+/// POSITIONS.apply_settlement(&mut storage, &[entry]).unwrap();
+///
+/// let position = POSITIONS.position(&storage, "alice", "USDC", "ATOM").unwrap();
+/// assert_eq!(position.quantity, SignedDecimal::percent(200));
+/// assert_eq!(position.deposit_surplus, SignedDecimal::percent(200));
+/// ```
+pub struct PositionBook<'a> {
+    positions: Map<'a, (&'a str, &'a str, &'a str), Position>,
+}
+
+impl<'a> PositionBook<'a> {
+    pub const fn new(namespace: &'a str) -> Self {
+        PositionBook {
+            positions: Map::new(namespace),
+        }
+    }
+
+    /// Applies the entries of a `SudoMsg::Settlement` to the positions of their accounts, in
+    /// order. Flat positions are kept for their realized PnL.
+    pub fn apply_settlement(
+        &self,
+        storage: &mut dyn Storage,
+        entries: &[SettlementEntry],
+    ) -> StdResult<()> {
+        for entry in entries {
+            let key = (
+                entry.account.as_str(),
+                entry.price_denom.as_str(),
+                entry.asset_denom.as_str(),
+            );
+            let mut position = self.positions.may_load(storage, key)?.unwrap_or_else(|| {
+                Position::new(&entry.account, &entry.price_denom, &entry.asset_denom)
+            });
+            position.apply(entry)?;
+            self.positions.save(storage, key, &position)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the position of `account` in the pair, flat if it never traded it
+    pub fn position(
+        &self,
+        storage: &dyn Storage,
+        account: &str,
+        price_denom: &str,
+        asset_denom: &str,
+    ) -> StdResult<Position> {
+        Ok(self
+            .positions
+            .may_load(storage, (account, price_denom, asset_denom))?
+            .unwrap_or_else(|| Position::new(account, price_denom, asset_denom)))
+    }
+
+    /// Returns the positions of `account` in every pair it traded, by price and asset denom
+    pub fn positions(&self, storage: &dyn Storage, account: &str) -> StdResult<PositionsResponse> {
+        let positions = self
+            .positions
+            .sub_prefix(account)
+            .range(storage, None, None, cosmwasm_std::Order::Ascending)
+            .map(|item| item.map(|(_, position)| position))
+            .collect::<StdResult<Vec<_>>>()?;
+        Ok(PositionsResponse { positions })
+    }
+}
+
+fn signed(value: Decimal) -> StdResult<SignedDecimal> {
+    SignedDecimal::try_from(value).map_err(|err| StdError::generic_err(err.to_string()))
+}
+
+fn unsigned(value: SignedDecimal) -> StdResult<Decimal> {
+    Decimal::try_from(value).map_err(|err| StdError::generic_err(err.to_string()))
+}
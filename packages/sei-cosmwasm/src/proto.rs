@@ -0,0 +1,141 @@
+//! Minimal protobuf wire format codec for the responses of the Sei messages, see `tx.proto`.
+//! Only the scalar types used by the responses are supported, there is no reflection.
+
+use cosmwasm_std::StdError;
+
+use crate::error::{SeiError, SeiResult};
+
+const WIRE_VARINT: u32 = 0;
+const WIRE_FIXED64: u32 = 1;
+const WIRE_LEN: u32 = 2;
+const WIRE_FIXED32: u32 = 5;
+
+/// Protobuf message returned as the data of a Sei message, see `parse_reply_*`
+pub trait MsgResponse: Sized {
+    /// Decodes the message from its protobuf encoding. Unknown fields are skipped.
+    fn decode(data: &[u8]) -> SeiResult<Self>;
+
+    /// Returns the protobuf encoding of the message
+    fn encode(&self) -> Vec<u8>;
+}
+
+/// Decodes the message `type_name` with `decode_fields`, called with a reader over `data`
+pub(crate) fn decode_message<T>(
+    type_name: &str,
+    data: &[u8],
+    decode_fields: impl FnOnce(&mut ProtoReader) -> Result<T, &'static str>,
+) -> SeiResult<T> {
+    decode_fields(&mut ProtoReader { data })
+        .map_err(|err| SeiError::Std(StdError::parse_err(type_name, err)))
+}
+
+/// Reads the fields of a protobuf encoded message
+pub(crate) struct ProtoReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> ProtoReader<'a> {
+    /// Returns the number and wire type of the next field, `None` at the end of the message
+    pub fn read_key(&mut self) -> Result<Option<(u32, u32)>, &'static str> {
+        if self.data.is_empty() {
+            return Ok(None);
+        }
+        let key = self.read_varint()?;
+        let field = u32::try_from(key >> 3).map_err(|_| "invalid field number")?;
+        if field == 0 {
+            return Err("invalid field number");
+        }
+        Ok(Some((field, (key & 7) as u32)))
+    }
+
+    pub fn read_varint(&mut self) -> Result<u64, &'static str> {
+        let mut value = 0u64;
+        for i in 0..10 {
+            let (&byte, rest) = self.data.split_first().ok_or("unexpected end of data")?;
+            self.data = rest;
+            value |= u64::from(byte & 0x7f) << (7 * i);
+            if byte < 0x80 {
+                return Ok(value);
+            }
+        }
+        Err("varint overflow")
+    }
+
+    pub fn read_bytes(&mut self) -> Result<&'a [u8], &'static str> {
+        let len = usize::try_from(self.read_varint()?).map_err(|_| "invalid length")?;
+        if len > self.data.len() {
+            return Err("unexpected end of data");
+        }
+        let (bytes, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(bytes)
+    }
+
+    pub fn read_string(&mut self) -> Result<String, &'static str> {
+        String::from_utf8(self.read_bytes()?.to_vec()).map_err(|_| "invalid utf-8 string")
+    }
+
+    /// Reads a repeated `uint64` field with wire type `wire_type`, packed or not
+    pub fn read_repeated_uint64(
+        &mut self,
+        wire_type: u32,
+        values: &mut Vec<u64>,
+    ) -> Result<(), &'static str> {
+        match wire_type {
+            WIRE_VARINT => values.push(self.read_varint()?),
+            WIRE_LEN => {
+                let mut packed = ProtoReader {
+                    data: self.read_bytes()?,
+                };
+                while !packed.data.is_empty() {
+                    values.push(packed.read_varint()?);
+                }
+            }
+            _ => return Err("invalid wire type"),
+        }
+        Ok(())
+    }
+
+    /// Skips the value of a field with wire type `wire_type`
+    pub fn skip(&mut self, wire_type: u32) -> Result<(), &'static str> {
+        let len = match wire_type {
+            WIRE_VARINT => return self.read_varint().map(|_| ()),
+            WIRE_LEN => return self.read_bytes().map(|_| ()),
+            WIRE_FIXED64 => 8,
+            WIRE_FIXED32 => 4,
+            _ => return Err("invalid wire type"),
+        };
+        if len > self.data.len() {
+            return Err("unexpected end of data");
+        }
+        self.data = &self.data[len..];
+        Ok(())
+    }
+}
+
+pub(crate) fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+/// Writes a length delimited field, omitted if empty as in proto3
+pub(crate) fn write_bytes_field(buf: &mut Vec<u8>, field: u32, value: &[u8]) {
+    if value.is_empty() {
+        return;
+    }
+    write_varint(buf, u64::from(field << 3 | WIRE_LEN));
+    write_varint(buf, value.len() as u64);
+    buf.extend_from_slice(value);
+}
+
+/// Writes a packed repeated `uint64` field
+pub(crate) fn write_packed_uint64_field(buf: &mut Vec<u8>, field: u32, values: &[u64]) {
+    let mut packed = vec![];
+    for value in values {
+        write_varint(&mut packed, *value);
+    }
+    write_bytes_field(buf, field, &packed);
+}
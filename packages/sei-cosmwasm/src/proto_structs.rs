@@ -1,7 +1,9 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cosmwasm_std::{Decimal, Uint64};
+use cosmwasm_std::{Decimal, StdError, StdResult, Timestamp, Uint64};
+
+use crate::time::parse_rfc3339;
 
 // ExchangeRateItem is data format returned from OracleRequest::ExchangeRates query
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -49,3 +51,34 @@ pub struct Epoch {
     pub current_epoch_start_time: String, // represented as ISO8601 UTC
     pub current_epoch_height: i64,
 }
+
+impl Epoch {
+    pub fn genesis_timestamp(&self) -> StdResult<Timestamp> {
+        parse_rfc3339(&self.genesis_time)
+    }
+
+    pub fn start_timestamp(&self) -> StdResult<Timestamp> {
+        parse_rfc3339(&self.current_epoch_start_time)
+    }
+
+    /// Time the next epoch starts at, one `duration` after the start of the current epoch
+    pub fn next_epoch_time(&self) -> StdResult<Timestamp> {
+        Ok(self.start_timestamp()?.plus_nanos(self.duration))
+    }
+
+    /// Number of the epoch running at `time`, counting epochs of `duration` from the start of the
+    /// current epoch. Fails for times before the start of the current epoch.
+    pub fn epoch_at(&self, time: Timestamp) -> StdResult<u64> {
+        let start = self.start_timestamp()?;
+        if time < start {
+            return Err(StdError::generic_err(format!(
+                "{} is before the start of epoch {}",
+                time, self.current_epoch
+            )));
+        }
+        if self.duration == 0 {
+            return Err(StdError::generic_err("epoch duration is zero"));
+        }
+        Ok(self.current_epoch + (time.nanos() - start.nanos()) / self.duration)
+    }
+}
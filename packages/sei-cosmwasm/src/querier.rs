@@ -1,18 +1,28 @@
-use cosmwasm_std::{Addr, QuerierWrapper, StdResult, Uint128};
+use cosmwasm_std::{Addr, Binary, Decimal, QuerierWrapper, Uint128};
 use cw20::{BalanceResponse, TokenInfoResponse};
 
+use crate::abi::{encode_function_call, AbiToken, AbiType};
+use crate::error::{SeiError, SeiResult};
+use crate::oracle::{
+    check_exchange_rate_age, cross_rate, find_exchange_rate, find_oracle_twap, OraclePriceOptions,
+};
+use crate::pagination::PageIter;
 use crate::query::{
     DenomAuthorityMetadataResponse, DenomsFromCreatorResponse, DexTwapsResponse, EpochResponse,
-    Erc20AllowanceResponse, Erc721ApprovedResponse, Erc721IsApprovedForAllResponse,
-    Erc721NameSymbolResponse, Erc721OwnerResponse, Erc721UriResponse, ErcPayloadResponse,
-    EvmAddressResponse, ExchangeRatesResponse, GetLatestPriceResponse, GetOrderByIdResponse,
-    GetOrdersResponse, OracleTwapsResponse, OrderSimulationResponse, SeiAddressResponse, SeiQuery,
-    SeiQueryWrapper, StaticCallResponse,
+    Erc1155BalanceOfBatchResponse, Erc1155BalanceOfResponse, Erc1155IsApprovedForAllResponse,
+    Erc1155RoyaltyInfoResponse, Erc1155UriResponse, Erc20AllowanceResponse, Erc721ApprovedResponse,
+    Erc721BalanceOfResponse, Erc721IsApprovedForAllResponse, Erc721NameSymbolResponse,
+    Erc721OwnerResponse, Erc721RoyaltyInfoResponse, Erc721TokenByIndexResponse,
+    Erc721TokenOfOwnerByIndexResponse, Erc721TotalSupplyResponse, Erc721UriResponse,
+    ErcPayloadResponse, EvmAddressResponse, ExchangeRatesResponse, GetHistoricalPricesResponse,
+    GetLatestPriceResponse, GetOrderByIdResponse, GetOrdersResponse, GetRegisteredPairsResponse,
+    MarketSummaryResponse, OracleTwapsResponse, OrderBookDepthResponse, OrderSimulationResponse,
+    SeiAddressResponse, SeiQuery, SeiQueryWrapper, StaticCallResponse,
 };
-use crate::route::SeiRoute;
-use crate::Order;
+use crate::{DenomOracleExchangeRatePair, OracleExchangeRate, Order, OrderResponse};
 
 /// This is a helper wrapper to easily use our custom queries
+#[derive(Clone, Copy)]
 pub struct SeiQuerier<'a> {
     querier: &'a QuerierWrapper<'a, SeiQueryWrapper>,
 }
@@ -25,24 +35,118 @@ impl<'a> SeiQuerier<'a> {
     /*
     query oracle module
     */
-    pub fn query_exchange_rates(&self) -> StdResult<ExchangeRatesResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Oracle,
-            query_data: SeiQuery::ExchangeRates {},
-        }
-        .into();
+    pub fn query_exchange_rates(&self) -> SeiResult<ExchangeRatesResponse> {
+        self.query_exchange_rates_page(None, None)
+    }
+
+    /// Queries at most `limit` exchange rates of the denoms after `start_after`
+    pub fn query_exchange_rates_page(
+        &self,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> SeiResult<ExchangeRatesResponse> {
+        let request = SeiQuery::ExchangeRates { start_after, limit }.into();
 
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
-    pub fn query_oracle_twaps(&self, lookback_seconds: u64) -> StdResult<OracleTwapsResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Oracle,
-            query_data: SeiQuery::OracleTwaps { lookback_seconds },
-        }
-        .into();
+    /// Iterates over the exchange rates, querying `page_size` of them at a time
+    pub fn exchange_rates_iter(
+        &self,
+        page_size: u32,
+    ) -> PageIter<'a, DenomOracleExchangeRatePair, String> {
+        let querier = *self;
+        PageIter::new(move |start_after| {
+            let res = querier.query_exchange_rates_page(start_after, Some(page_size))?;
+            Ok((res.denom_oracle_exchange_rate_pairs, res.next_key))
+        })
+    }
+
+    pub fn query_oracle_twaps(&self, lookback_seconds: u64) -> SeiResult<OracleTwapsResponse> {
+        let request = SeiQuery::OracleTwaps { lookback_seconds }.into();
+
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Queries the latest exchange rate of `denom`
+    ///
+    /// # Errors
+    /// This function will return `SeiError::ExchangeRateNotFound` if the oracle has no exchange
+    /// rate for `denom`, or an error if the query fails.
+    pub fn query_exchange_rate(&self, denom: &str) -> SeiResult<OracleExchangeRate> {
+        let res = self.query_exchange_rates()?;
+        find_exchange_rate(&res.denom_oracle_exchange_rate_pairs, denom)
+    }
+
+    /// Queries the TWAP of `denom` over the last `lookback_seconds`
+    ///
+    /// # Errors
+    /// This function will return `SeiError::ExchangeRateNotFound` if the oracle has no TWAP for
+    /// `denom`, or an error if the query fails.
+    pub fn query_oracle_twap(&self, denom: &str, lookback_seconds: u64) -> SeiResult<Decimal> {
+        let res = self.query_oracle_twaps(lookback_seconds)?;
+        find_oracle_twap(&res.oracle_twaps, denom)
+    }
+
+    /// Queries the price of `denom` meeting the freshness requirements of `options`: the latest
+    /// exchange rate if it is recent enough, otherwise the TWAP if `options` sets a fallback.
+    ///
+    /// # Errors
+    /// This function will return `SeiError::ExchangeRateNotFound` if the oracle has no exchange
+    /// rate for `denom`, `SeiError::StaleExchangeRate` if the latest exchange rate is too old
+    /// and there is no TWAP fallback, or an error if a query fails.
+    pub fn query_oracle_price(
+        &self,
+        denom: &str,
+        options: &OraclePriceOptions,
+    ) -> SeiResult<Decimal> {
+        let res = self.query_exchange_rates()?;
+        self.fresh_price(&res.denom_oracle_exchange_rate_pairs, denom, options)
+    }
+
+    /// Queries the price of one unit of `base` in units of `quote` from their latest exchange
+    /// rates, e.g. the price of `uatom` in `usei`.
+    ///
+    /// # Errors
+    /// This function will return `SeiError::ExchangeRateNotFound` if the oracle has no exchange
+    /// rate for either denom, or an error if the query fails or the `quote` exchange rate is zero.
+    pub fn query_price_in(&self, base: &str, quote: &str) -> SeiResult<Decimal> {
+        let res = self.query_exchange_rates()?;
+        let rates = res.denom_oracle_exchange_rate_pairs;
+        let base_rate = find_exchange_rate(&rates, base)?.exchange_rate;
+        let quote_rate = find_exchange_rate(&rates, quote)?.exchange_rate;
+        cross_rate(base_rate, quote, quote_rate)
+    }
 
-        self.querier.query(&request)
+    /// Like `query_price_in`, with both prices meeting the freshness requirements of `options`,
+    /// see `query_oracle_price`
+    pub fn query_checked_price_in(
+        &self,
+        base: &str,
+        quote: &str,
+        options: &OraclePriceOptions,
+    ) -> SeiResult<Decimal> {
+        let res = self.query_exchange_rates()?;
+        let rates = res.denom_oracle_exchange_rate_pairs;
+        let base_rate = self.fresh_price(&rates, base, options)?;
+        let quote_rate = self.fresh_price(&rates, quote, options)?;
+        cross_rate(base_rate, quote, quote_rate)
+    }
+
+    fn fresh_price(
+        &self,
+        rates: &[DenomOracleExchangeRatePair],
+        denom: &str,
+        options: &OraclePriceOptions,
+    ) -> SeiResult<Decimal> {
+        let rate = find_exchange_rate(rates, denom)?;
+        match check_exchange_rate_age(denom, &rate, options.block_time, options.max_age_seconds) {
+            Ok(()) => Ok(rate.exchange_rate),
+            Err(err) => match options.twap_lookback_seconds {
+                Some(lookback_seconds) => self.query_oracle_twap(denom, lookback_seconds),
+                None => Err(err),
+            },
+        }
     }
 
     /*
@@ -52,50 +156,73 @@ impl<'a> SeiQuerier<'a> {
         &self,
         lookback_seconds: u64,
         contract_address: Addr,
-    ) -> StdResult<DexTwapsResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Dex,
-            query_data: SeiQuery::DexTwaps {
-                contract_address,
-                lookback_seconds,
-            },
+    ) -> SeiResult<DexTwapsResponse> {
+        let request = SeiQuery::DexTwaps {
+            contract_address,
+            lookback_seconds,
         }
         .into();
 
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     pub fn query_order_simulation(
         &self,
         order: Order,
         contract_address: Addr,
-    ) -> StdResult<OrderSimulationResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Dex,
-            query_data: SeiQuery::OrderSimulation {
-                contract_address,
-                order,
-            },
+    ) -> SeiResult<OrderSimulationResponse> {
+        let request = SeiQuery::OrderSimulation {
+            contract_address,
+            order,
         }
         .into();
 
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     pub fn query_get_orders(
         &self,
         contract_address: Addr,
         account: Addr,
-    ) -> StdResult<GetOrdersResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Dex,
-            query_data: SeiQuery::GetOrders {
-                contract_address,
-                account,
-            },
+    ) -> SeiResult<GetOrdersResponse> {
+        self.query_get_orders_page(contract_address, account, None, None)
+    }
+
+    /// Queries at most `limit` orders of `account` with an id after `start_after`
+    pub fn query_get_orders_page(
+        &self,
+        contract_address: Addr,
+        account: Addr,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> SeiResult<GetOrdersResponse> {
+        let request = SeiQuery::GetOrders {
+            contract_address,
+            account,
+            start_after,
+            limit,
         }
         .into();
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Iterates over the orders of `account`, querying `page_size` of them at a time
+    pub fn orders_iter(
+        &self,
+        contract_address: Addr,
+        account: Addr,
+        page_size: u32,
+    ) -> PageIter<'a, OrderResponse, u64> {
+        let querier = *self;
+        PageIter::new(move |start_after| {
+            let res = querier.query_get_orders_page(
+                contract_address.clone(),
+                account.clone(),
+                start_after,
+                Some(page_size),
+            )?;
+            Ok((res.orders, res.next_key))
+        })
     }
 
     pub fn query_get_order_by_id(
@@ -104,48 +231,102 @@ impl<'a> SeiQuerier<'a> {
         price_denom: String,
         asset_denom: String,
         order_id: u64,
-    ) -> StdResult<GetOrderByIdResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Dex,
-            query_data: SeiQuery::GetOrderById {
-                contract_address,
-                price_denom,
-                asset_denom,
-                id: order_id,
-            },
+    ) -> SeiResult<GetOrderByIdResponse> {
+        let request = SeiQuery::GetOrderById {
+            contract_address,
+            price_denom,
+            asset_denom,
+            id: order_id,
         }
         .into();
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     /*
     query epoch module
     */
-    pub fn query_epoch(&self) -> StdResult<EpochResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Epoch,
-            query_data: SeiQuery::Epoch {},
+    pub fn query_epoch(&self) -> SeiResult<EpochResponse> {
+        let request = SeiQuery::Epoch {}.into();
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    pub fn query_get_latest_price(
+        &self,
+        contract_address: Addr,
+        price_denom: String,
+        asset_denom: String,
+    ) -> SeiResult<GetLatestPriceResponse> {
+        let request = SeiQuery::GetLatestPrice {
+            contract_address,
+            price_denom,
+            asset_denom,
         }
         .into();
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
-    pub fn query_get_latest_price(
+    /// Queries the resting orders of the pair per price level, at most `limit` levels per side
+    pub fn query_order_book_depth(
         &self,
         contract_address: Addr,
         price_denom: String,
         asset_denom: String,
-    ) -> StdResult<GetLatestPriceResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Dex,
-            query_data: SeiQuery::GetLatestPrice {
-                contract_address,
-                price_denom,
-                asset_denom,
-            },
+        limit: Option<u32>,
+    ) -> SeiResult<OrderBookDepthResponse> {
+        let request = SeiQuery::OrderBookDepth {
+            contract_address,
+            price_denom,
+            asset_denom,
+            limit,
         }
         .into();
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    pub fn query_registered_pairs(
+        &self,
+        contract_address: Addr,
+    ) -> SeiResult<GetRegisteredPairsResponse> {
+        let request = SeiQuery::GetRegisteredPairs { contract_address }.into();
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Queries the high, low and last price and the volume of the pair over `lookback_seconds`
+    pub fn query_market_summary(
+        &self,
+        contract_address: Addr,
+        price_denom: String,
+        asset_denom: String,
+        lookback_seconds: u64,
+    ) -> SeiResult<MarketSummaryResponse> {
+        let request = SeiQuery::GetMarketSummary {
+            contract_address,
+            price_denom,
+            asset_denom,
+            lookback_seconds,
+        }
+        .into();
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Queries the candlesticks of the pair over the last `number_of_periods` periods
+    pub fn query_historical_prices(
+        &self,
+        contract_address: Addr,
+        price_denom: String,
+        asset_denom: String,
+        period_length_in_seconds: u64,
+        number_of_periods: u64,
+    ) -> SeiResult<GetHistoricalPricesResponse> {
+        let request = SeiQuery::GetHistoricalPrices {
+            contract_address,
+            price_denom,
+            asset_denom,
+            period_length_in_seconds,
+            number_of_periods,
+        }
+        .into();
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     /*
@@ -154,22 +335,46 @@ impl<'a> SeiQuerier<'a> {
     pub fn query_denom_authority_metadata(
         &self,
         denom: String,
-    ) -> StdResult<DenomAuthorityMetadataResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Tokenfactory,
-            query_data: SeiQuery::DenomAuthorityMetadata { denom },
-        }
-        .into();
-        self.querier.query(&request)
+    ) -> SeiResult<DenomAuthorityMetadataResponse> {
+        let request = SeiQuery::DenomAuthorityMetadata { denom }.into();
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    pub fn query_denoms_from_creator(&self, creator: Addr) -> SeiResult<DenomsFromCreatorResponse> {
+        self.query_denoms_from_creator_page(creator, None, None)
     }
 
-    pub fn query_denoms_from_creator(&self, creator: Addr) -> StdResult<DenomsFromCreatorResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Tokenfactory,
-            query_data: SeiQuery::DenomsFromCreator { creator },
+    /// Queries at most `limit` denoms of `creator` after `start_after`
+    pub fn query_denoms_from_creator_page(
+        &self,
+        creator: Addr,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> SeiResult<DenomsFromCreatorResponse> {
+        let request = SeiQuery::DenomsFromCreator {
+            creator,
+            start_after,
+            limit,
         }
         .into();
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Iterates over the denoms created by `creator`, querying `page_size` of them at a time
+    pub fn denoms_from_creator_iter(
+        &self,
+        creator: Addr,
+        page_size: u32,
+    ) -> PageIter<'a, String, String> {
+        let querier = *self;
+        PageIter::new(move |start_after| {
+            let res = querier.query_denoms_from_creator_page(
+                creator.clone(),
+                start_after,
+                Some(page_size),
+            )?;
+            Ok((res.denoms, res.next_key))
+        })
     }
 
     /// Calls the EVM contract deployed at the `to` address with the given `data`.
@@ -186,24 +391,53 @@ impl<'a> SeiQuerier<'a> {
     ///
     /// # Returns
     ///
-    /// * `StdResult<StaticCallResponse>` - A standard result that wraps the `StaticCallResponse`
-    /// struct.
+    /// * `SeiResult<StaticCallResponse>` - A result that wraps the `StaticCallResponse`
+    ///   struct.
     ///
     /// # Errors
-    /// This function will return an error if the query to the EVM fails.
+    /// This function will return an error if the query to the EVM fails. Reverts are reported as
+    /// `SeiError::EvmRevert` with the reason decoded from the revert data, see
+    /// `SeiError::from_evm_query`.
     pub fn static_call(
         &self,
         from: String,
         to: String,
         data: String,
-    ) -> StdResult<StaticCallResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Evm,
-            query_data: SeiQuery::StaticCall { from, to, data },
-        }
-        .into();
+    ) -> SeiResult<StaticCallResponse> {
+        let request = SeiQuery::StaticCall { from, to, data }.into();
 
-        self.querier.query(&request)
+        self.querier
+            .query(&request)
+            .map_err(SeiError::from_evm_query)
+    }
+
+    /// Calls the view function with the canonical `signature` (e.g. `balanceOf(address)`) on the
+    /// EVM contract deployed at the `to` address and decodes the returned data.
+    ///
+    /// # Arguments
+    /// * `from` - Sei native (bech32-encoded 'sei*') address of the caller.
+    /// * `to` - The address of the EVM contract to call.
+    /// * `signature` - The canonical Solidity function signature.
+    /// * `args` - The function arguments, ABI encoded after the function selector.
+    /// * `output_types` - The ABI types of the function return values.
+    ///
+    /// # Returns
+    ///
+    /// * `SeiResult<Vec<AbiToken>>` - The decoded return values.
+    ///
+    /// # Errors
+    /// This function will return an error if the `args` cannot be encoded, the query to the EVM
+    /// fails or the returned data cannot be decoded as `output_types`.
+    pub fn static_call_typed(
+        &self,
+        from: String,
+        to: String,
+        signature: &str,
+        args: &[AbiToken],
+        output_types: &[AbiType],
+    ) -> SeiResult<Vec<AbiToken>> {
+        let data = Binary::from(encode_function_call(signature, args)?).to_base64();
+        Ok(self.static_call(from, to, data)?.decode(output_types)?)
     }
 
     /// Query to get hex payload for the ERC-20 `transfer` function
@@ -214,8 +448,8 @@ impl<'a> SeiQuerier<'a> {
     ///
     /// # Returns
     ///
-    /// * `StdResult<ErcPayloadResponse>` - A standard result that wraps the `ErcPayloadResponse`
-    /// struct. The `ErcPayloadResponse` struct contains the base64-encoded bytes.
+    /// * `SeiResult<ErcPayloadResponse>` - A result that wraps the `ErcPayloadResponse`
+    ///   struct. The `ErcPayloadResponse` struct contains the base64-encoded bytes.
     ///
     /// # Errors
     /// This function will return an error if the query fails.
@@ -223,14 +457,10 @@ impl<'a> SeiQuerier<'a> {
         &self,
         recipient: String,
         amount: Uint128,
-    ) -> StdResult<ErcPayloadResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Evm,
-            query_data: SeiQuery::Erc20TransferPayload { recipient, amount },
-        }
-        .into();
+    ) -> SeiResult<ErcPayloadResponse> {
+        let request = SeiQuery::Erc20TransferPayload { recipient, amount }.into();
 
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     /// Query to get hex payload for the ERC-20 `transferFrom` function
@@ -242,8 +472,8 @@ impl<'a> SeiQuerier<'a> {
     ///
     /// # Returns
     ///
-    /// * `StdResult<ErcPayloadResponse>` - A standard result that wraps the `ErcPayloadResponse`
-    /// struct. The `ErcPayloadResponse` struct contains the base64-encoded bytes.
+    /// * `SeiResult<ErcPayloadResponse>` - A result that wraps the `ErcPayloadResponse`
+    ///   struct. The `ErcPayloadResponse` struct contains the base64-encoded bytes.
     ///
     /// # Errors
     /// This function will return an error if the query fails.
@@ -252,18 +482,15 @@ impl<'a> SeiQuerier<'a> {
         owner: String,
         recipient: String,
         amount: Uint128,
-    ) -> StdResult<ErcPayloadResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Evm,
-            query_data: SeiQuery::Erc20TransferFromPayload {
-                owner,
-                recipient,
-                amount,
-            },
+    ) -> SeiResult<ErcPayloadResponse> {
+        let request = SeiQuery::Erc20TransferFromPayload {
+            owner,
+            recipient,
+            amount,
         }
         .into();
 
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     /// Query to get hex payload for the ERC-20 `approve` function
@@ -273,8 +500,8 @@ impl<'a> SeiQuerier<'a> {
     /// * `amount` - The amount to approve.
     ///
     /// # Returns
-    /// * `StdResult<ErcPayloadResponse>` - A standard result that wraps the `ErcPayloadResponse`
-    /// struct. The `ErcPayloadResponse` struct contains the base64-encoded bytes.
+    /// * `SeiResult<ErcPayloadResponse>` - A result that wraps the `ErcPayloadResponse`
+    ///   struct. The `ErcPayloadResponse` struct contains the base64-encoded bytes.
     ///
     /// # Errors
     /// This function will return an error if the query fails.
@@ -282,14 +509,10 @@ impl<'a> SeiQuerier<'a> {
         &self,
         spender: String,
         amount: Uint128,
-    ) -> StdResult<ErcPayloadResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Evm,
-            query_data: SeiQuery::Erc20ApprovePayload { spender, amount },
-        }
-        .into();
+    ) -> SeiResult<ErcPayloadResponse> {
+        let request = SeiQuery::Erc20ApprovePayload { spender, amount }.into();
 
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     /// Query to get the remaining number of tokens that spender will be allowed to spend on behalf
@@ -301,9 +524,9 @@ impl<'a> SeiQuerier<'a> {
     /// * `spender` - Spender Sei native (bech32-encoded 'sei*') address.
     ///
     /// # Returns
-    /// * `StdResult<Erc20AllowanceResponse>` - A standard result that wraps the
-    /// `Erc20AllowanceResponse`. `Erc20AllowanceResponse` contains the amount which spender
-    /// is still allowed to withdraw from owner
+    /// * `SeiResult<Erc20AllowanceResponse>` - A result that wraps the
+    ///   `Erc20AllowanceResponse`. `Erc20AllowanceResponse` contains the amount which spender
+    ///   is still allowed to withdraw from owner
     ///
     /// # Errors
     /// This function will return an error if the query fails.
@@ -312,18 +535,15 @@ impl<'a> SeiQuerier<'a> {
         contract_address: String,
         owner: String,
         spender: String,
-    ) -> StdResult<Erc20AllowanceResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Evm,
-            query_data: SeiQuery::Erc20Allowance {
-                contract_address,
-                owner,
-                spender,
-            },
+    ) -> SeiResult<Erc20AllowanceResponse> {
+        let request = SeiQuery::Erc20Allowance {
+            contract_address,
+            owner,
+            spender,
         }
         .into();
 
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     /// Query to get the token info, including the name, symbol, decimals and total supply
@@ -333,7 +553,7 @@ impl<'a> SeiQuerier<'a> {
     /// * `caller` - Caller Sei native (bech32-encoded 'sei*') address.
     ///
     /// # Returns
-    /// * `StdResult<TokenInfoResponse>` - A standard result that wraps the `TokenInfoResponse`.
+    /// * `SeiResult<TokenInfoResponse>` - A result that wraps the `TokenInfoResponse`.
     ///
     /// # Errors
     /// This function will return an error if the query fails.
@@ -341,17 +561,14 @@ impl<'a> SeiQuerier<'a> {
         &self,
         contract_address: String,
         caller: String,
-    ) -> StdResult<TokenInfoResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Evm,
-            query_data: SeiQuery::Erc20TokenInfo {
-                contract_address,
-                caller,
-            },
+    ) -> SeiResult<TokenInfoResponse> {
+        let request = SeiQuery::Erc20TokenInfo {
+            contract_address,
+            caller,
         }
         .into();
 
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     /// Query to get the balance of the account with the given address.
@@ -362,7 +579,7 @@ impl<'a> SeiQuerier<'a> {
     /// * `account` - Sei native (bech32-encoded 'sei*') account address.
     ///
     /// # Returns
-    /// * `StdResult<BalanceResponse>` - A standard result that wraps the `BalanceResponse`.
+    /// * `SeiResult<BalanceResponse>` - A result that wraps the `BalanceResponse`.
     ///
     /// # Errors
     /// This function will return an error if the query fails.
@@ -370,17 +587,14 @@ impl<'a> SeiQuerier<'a> {
         &self,
         contract_address: String,
         account: String,
-    ) -> StdResult<BalanceResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Evm,
-            query_data: SeiQuery::Erc20Balance {
-                contract_address,
-                account,
-            },
+    ) -> SeiResult<BalanceResponse> {
+        let request = SeiQuery::Erc20Balance {
+            contract_address,
+            account,
         }
         .into();
 
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     /// Query to get the address of the owner of the NFT.
@@ -392,7 +606,7 @@ impl<'a> SeiQuerier<'a> {
     /// * `token_id` -  The identifier for an NFT. String representation of the token ID.
     ///
     /// # Returns
-    /// * `StdResult<Erc721OwnerResponse>` - A standard result that wraps the `Erc721OwnerResponse`.
+    /// * `SeiResult<Erc721OwnerResponse>` - A result that wraps the `Erc721OwnerResponse`.
     ///
     /// # Errors
     /// This function will return an error if the query fails.
@@ -401,18 +615,15 @@ impl<'a> SeiQuerier<'a> {
         caller: String,
         contract_address: String,
         token_id: String,
-    ) -> StdResult<Erc721OwnerResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Evm,
-            query_data: SeiQuery::Erc721Owner {
-                caller,
-                contract_address,
-                token_id,
-            },
+    ) -> SeiResult<Erc721OwnerResponse> {
+        let request = SeiQuery::Erc721Owner {
+            caller,
+            contract_address,
+            token_id,
         }
         .into();
 
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     /// Query to get the approved address for a single NFT. Executes ERC-721 `getApproved` function.
@@ -423,8 +634,8 @@ impl<'a> SeiQuerier<'a> {
     /// * `token_id` -  The identifier for an NFT. String representation of the token ID.
     ///
     /// # Returns
-    /// * `StdResult<Erc721ApprovedResponse>` - A standard result that wraps the
-    /// `Erc721ApprovedResponse`.
+    /// * `SeiResult<Erc721ApprovedResponse>` - A result that wraps the
+    ///   `Erc721ApprovedResponse`.
     ///
     /// # Errors
     /// This function will return an error if the query fails.
@@ -433,18 +644,15 @@ impl<'a> SeiQuerier<'a> {
         caller: String,
         contract_address: String,
         token_id: String,
-    ) -> StdResult<Erc721ApprovedResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Evm,
-            query_data: SeiQuery::Erc721Approved {
-                caller,
-                contract_address,
-                token_id,
-            },
+    ) -> SeiResult<Erc721ApprovedResponse> {
+        let request = SeiQuery::Erc721Approved {
+            caller,
+            contract_address,
+            token_id,
         }
         .into();
 
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     /// Query if an address is an authorized operator for another address. Executes ERC-721
@@ -457,8 +665,8 @@ impl<'a> SeiQuerier<'a> {
     /// * `operator` - The operator Sei address that acts on behalf of the owner
     ///
     /// # Returns
-    /// * `StdResult<Erc721IsApprovedForAllResponse>` - A standard result that wraps the
-    /// `Erc721IsApprovedForAllResponse`.
+    /// * `SeiResult<Erc721IsApprovedForAllResponse>` - A result that wraps the
+    ///   `Erc721IsApprovedForAllResponse`.
     ///
     /// # Errors
     /// This function will return an error if the query fails.
@@ -468,19 +676,16 @@ impl<'a> SeiQuerier<'a> {
         contract_address: String,
         owner: String,
         operator: String,
-    ) -> StdResult<Erc721IsApprovedForAllResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Evm,
-            query_data: SeiQuery::Erc721IsApprovedForAll {
-                caller,
-                contract_address,
-                owner,
-                operator,
-            },
+    ) -> SeiResult<Erc721IsApprovedForAllResponse> {
+        let request = SeiQuery::Erc721IsApprovedForAll {
+            caller,
+            contract_address,
+            owner,
+            operator,
         }
         .into();
 
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     /// Query to get the name and symbol of the ERC-721 contract. Executes ERC-721 `name` and
@@ -491,8 +696,8 @@ impl<'a> SeiQuerier<'a> {
     /// * `contract_address` - The contract address of the ERC-721 token.
     ///
     /// # Returns
-    /// * `StdResult<Erc721NameSymbolResponse>` - A standard result that wraps the
-    /// `Erc721NameSymbolResponse`.
+    /// * `SeiResult<Erc721NameSymbolResponse>` - A result that wraps the
+    ///   `Erc721NameSymbolResponse`.
     ///
     /// # Errors
     /// This function will return an error if the query fails.
@@ -500,17 +705,14 @@ impl<'a> SeiQuerier<'a> {
         &self,
         caller: String,
         contract_address: String,
-    ) -> StdResult<Erc721NameSymbolResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Evm,
-            query_data: SeiQuery::Erc721NameSymbol {
-                caller,
-                contract_address,
-            },
+    ) -> SeiResult<Erc721NameSymbolResponse> {
+        let request = SeiQuery::Erc721NameSymbol {
+            caller,
+            contract_address,
         }
         .into();
 
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     /// Query to get the URI for a given NFT. Executes ERC-721 `tokenURI` function under the hood.
@@ -521,7 +723,7 @@ impl<'a> SeiQuerier<'a> {
     /// * `token_id` - The identifier for an NFT. String representation of the token ID.
     ///
     /// # Returns
-    /// * `StdResult<Erc721UriResponse>` - A standard result that wraps the `Erc721UriResponse`.
+    /// * `SeiResult<Erc721UriResponse>` - A result that wraps the `Erc721UriResponse`.
     ///
     /// # Errors
     /// This function will return an error if the query fails.
@@ -530,18 +732,168 @@ impl<'a> SeiQuerier<'a> {
         caller: String,
         contract_address: String,
         token_id: String,
-    ) -> StdResult<Erc721UriResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Evm,
-            query_data: SeiQuery::Erc721Uri {
-                caller,
-                contract_address,
-                token_id,
-            },
+    ) -> SeiResult<Erc721UriResponse> {
+        let request = SeiQuery::Erc721Uri {
+            caller,
+            contract_address,
+            token_id,
+        }
+        .into();
+
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Query to get the number of NFTs tracked by the ERC-721 contract. Executes ERC-721
+    /// `totalSupply` function under the hood.
+    ///
+    /// # Arguments
+    /// * `caller` - Caller Sei native (bech32-encoded 'sei*') address.
+    /// * `contract_address` - The contract address of the ERC-721 token.
+    ///
+    /// # Returns
+    /// * `SeiResult<Erc721TotalSupplyResponse>` - A result that wraps the
+    ///   `Erc721TotalSupplyResponse`.
+    ///
+    /// # Errors
+    /// This function will return an error if the query fails.
+    pub fn erc721_total_supply(
+        &self,
+        caller: String,
+        contract_address: String,
+    ) -> SeiResult<Erc721TotalSupplyResponse> {
+        let request = SeiQuery::Erc721TotalSupply {
+            caller,
+            contract_address,
+        }
+        .into();
+
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Query to get the identifier of the NFT at `index` of all the NFTs. Executes ERC-721
+    /// `tokenByIndex` function under the hood.
+    ///
+    /// # Arguments
+    /// * `caller` - Caller Sei native (bech32-encoded 'sei*') address.
+    /// * `contract_address` - The contract address of the ERC-721 token.
+    /// * `index` - Index of the NFT, less than the total supply.
+    ///
+    /// # Returns
+    /// * `SeiResult<Erc721TokenByIndexResponse>` - A result that wraps the
+    ///   `Erc721TokenByIndexResponse`.
+    ///
+    /// # Errors
+    /// This function will return an error if the query fails.
+    pub fn erc721_token_by_index(
+        &self,
+        caller: String,
+        contract_address: String,
+        index: u64,
+    ) -> SeiResult<Erc721TokenByIndexResponse> {
+        let request = SeiQuery::Erc721TokenByIndex {
+            caller,
+            contract_address,
+            index,
         }
         .into();
 
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Query to get the identifier of the NFT at `index` of the NFTs of `owner`. Executes ERC-721
+    /// `tokenOfOwnerByIndex` function under the hood.
+    ///
+    /// # Arguments
+    /// * `caller` - Caller Sei native (bech32-encoded 'sei*') address.
+    /// * `contract_address` - The contract address of the ERC-721 token.
+    /// * `owner` - The owner of the NFTs Sei native (bech32-encoded 'sei*') address.
+    /// * `index` - Index of the NFT, less than the balance of `owner`.
+    ///
+    /// # Returns
+    /// * `SeiResult<Erc721TokenOfOwnerByIndexResponse>` - A result that wraps the
+    ///   `Erc721TokenOfOwnerByIndexResponse`.
+    ///
+    /// # Errors
+    /// This function will return an error if the query fails.
+    pub fn erc721_token_of_owner_by_index(
+        &self,
+        caller: String,
+        contract_address: String,
+        owner: String,
+        index: u64,
+    ) -> SeiResult<Erc721TokenOfOwnerByIndexResponse> {
+        let request = SeiQuery::Erc721TokenOfOwnerByIndex {
+            caller,
+            contract_address,
+            owner,
+            index,
+        }
+        .into();
+
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Query to get the number of NFTs owned by an address. Executes ERC-721 `balanceOf`
+    /// function under the hood.
+    ///
+    /// # Arguments
+    /// * `caller` - Caller Sei native (bech32-encoded 'sei*') address.
+    /// * `contract_address` - The contract address of the ERC-721 token.
+    /// * `owner` - The owner of the NFTs Sei native (bech32-encoded 'sei*') address.
+    ///
+    /// # Returns
+    /// * `SeiResult<Erc721BalanceOfResponse>` - A result that wraps the
+    ///   `Erc721BalanceOfResponse`.
+    ///
+    /// # Errors
+    /// This function will return an error if the query fails.
+    pub fn erc721_balance_of(
+        &self,
+        caller: String,
+        contract_address: String,
+        owner: String,
+    ) -> SeiResult<Erc721BalanceOfResponse> {
+        let request = SeiQuery::Erc721BalanceOf {
+            caller,
+            contract_address,
+            owner,
+        }
+        .into();
+
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Query to get the royalty receiver and amount owed for a sale of an NFT. Executes ERC-2981
+    /// `royaltyInfo` function under the hood.
+    ///
+    /// # Arguments
+    /// * `caller` - Caller Sei native (bech32-encoded 'sei*') address.
+    /// * `contract_address` - The contract address of the ERC-721 token.
+    /// * `token_id` - The identifier for an NFT. String representation of the token ID.
+    /// * `sale_price` - The sale price of the NFT.
+    ///
+    /// # Returns
+    /// * `SeiResult<Erc721RoyaltyInfoResponse>` - A result that wraps the
+    ///   `Erc721RoyaltyInfoResponse`.
+    ///
+    /// # Errors
+    /// This function will return an error if the query fails.
+    pub fn erc721_royalty_info(
+        &self,
+        caller: String,
+        contract_address: String,
+        token_id: String,
+        sale_price: Uint128,
+    ) -> SeiResult<Erc721RoyaltyInfoResponse> {
+        let request = SeiQuery::Erc721RoyaltyInfo {
+            caller,
+            contract_address,
+            token_id,
+            sale_price,
+        }
+        .into();
+
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     /// Query to get the hex payload for the ERC-721 `transferFrom` function
@@ -552,8 +904,8 @@ impl<'a> SeiQuerier<'a> {
     /// * `token_id` - The identifier for an NFT. String representation of the token ID.
     ///
     /// # Returns
-    /// * `StdResult<ErcPayloadResponse>` - A standard result that wraps the `ErcPayloadResponse`.
-    /// `ErcPayloadResponse` contains the base64-encoded bytes.
+    /// * `SeiResult<ErcPayloadResponse>` - A result that wraps the `ErcPayloadResponse`.
+    ///   `ErcPayloadResponse` contains the base64-encoded bytes.
     ///
     /// # Errors
     /// This function will return an error if the query fails.
@@ -562,18 +914,15 @@ impl<'a> SeiQuerier<'a> {
         from: String,
         recipient: String,
         token_id: String,
-    ) -> StdResult<ErcPayloadResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Evm,
-            query_data: SeiQuery::Erc721TransferPayload {
-                from,
-                recipient,
-                token_id,
-            },
+    ) -> SeiResult<ErcPayloadResponse> {
+        let request = SeiQuery::Erc721TransferPayload {
+            from,
+            recipient,
+            token_id,
         }
         .into();
 
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     /// Query to get the hex payload for the ERC-721 `approve` function
@@ -583,8 +932,8 @@ impl<'a> SeiQuerier<'a> {
     /// * `token_id` - The identifier for an NFT. String representation of the token ID.
     ///
     /// # Returns
-    /// * `StdResult<ErcPayloadResponse>` - A standard result that wraps the `ErcPayloadResponse`.
-    /// `ErcPayloadResponse` contains the base64-encoded bytes.
+    /// * `SeiResult<ErcPayloadResponse>` - A result that wraps the `ErcPayloadResponse`.
+    ///   `ErcPayloadResponse` contains the base64-encoded bytes.
     ///
     /// # Errors
     /// This function will return an error if the query fails.
@@ -592,14 +941,10 @@ impl<'a> SeiQuerier<'a> {
         &self,
         spender: String,
         token_id: String,
-    ) -> StdResult<ErcPayloadResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Evm,
-            query_data: SeiQuery::Erc721ApprovePayload { spender, token_id },
-        }
-        .into();
+    ) -> SeiResult<ErcPayloadResponse> {
+        let request = SeiQuery::Erc721ApprovePayload { spender, token_id }.into();
 
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     /// Query to get the hex payload for the ERC-721 `setApprovalForAll` function.
@@ -609,8 +954,8 @@ impl<'a> SeiQuerier<'a> {
     /// * `approved` - Boolean representing the status to set
     ///
     /// # Returns
-    /// * `StdResult<ErcPayloadResponse>` - A standard result that wraps the `ErcPayloadResponse`.
-    /// `ErcPayloadResponse` contains the base64-encoded bytes.
+    /// * `SeiResult<ErcPayloadResponse>` - A result that wraps the `ErcPayloadResponse`.
+    ///   `ErcPayloadResponse` contains the base64-encoded bytes.
     ///
     /// # Errors
     /// This function will return an error if the query fails.
@@ -618,22 +963,265 @@ impl<'a> SeiQuerier<'a> {
         &self,
         to: String,
         approved: bool,
-    ) -> StdResult<ErcPayloadResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Evm,
-            query_data: SeiQuery::Erc721SetApprovalAllPayload { to, approved },
+    ) -> SeiResult<ErcPayloadResponse> {
+        let request = SeiQuery::Erc721SetApprovalAllPayload { to, approved }.into();
+
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Query to get the hex payload for the ERC-1155 `safeTransferFrom` function
+    ///
+    /// # Arguments
+    /// * `from` - Sender Sei native (bech32-encoded 'sei*') address.
+    /// * `recipient` - Recipient Sei native (bech32-encoded 'sei*') address.
+    /// * `token_id` - The identifier for a token type. String representation of the token ID.
+    /// * `amount` - The amount to transfer.
+    ///
+    /// # Returns
+    /// * `SeiResult<ErcPayloadResponse>` - A result that wraps the `ErcPayloadResponse`.
+    ///   `ErcPayloadResponse` contains the base64-encoded bytes.
+    ///
+    /// # Errors
+    /// This function will return an error if the query fails.
+    pub fn erc1155_transfer_payload(
+        &self,
+        from: String,
+        recipient: String,
+        token_id: String,
+        amount: Uint128,
+    ) -> SeiResult<ErcPayloadResponse> {
+        let request = SeiQuery::Erc1155TransferPayload {
+            from,
+            recipient,
+            token_id,
+            amount,
+        }
+        .into();
+
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Query to get the hex payload for the ERC-1155 `safeBatchTransferFrom` function
+    ///
+    /// # Arguments
+    /// * `from` - Sender Sei native (bech32-encoded 'sei*') address.
+    /// * `recipient` - Recipient Sei native (bech32-encoded 'sei*') address.
+    /// * `token_ids` - The identifiers of the token types. String representations of the token
+    ///   IDs.
+    /// * `amounts` - The amount to transfer of each token type, in the order of `token_ids`.
+    ///
+    /// # Returns
+    /// * `SeiResult<ErcPayloadResponse>` - A result that wraps the `ErcPayloadResponse`.
+    ///   `ErcPayloadResponse` contains the base64-encoded bytes.
+    ///
+    /// # Errors
+    /// This function will return an error if the query fails.
+    pub fn erc1155_batch_transfer_payload(
+        &self,
+        from: String,
+        recipient: String,
+        token_ids: Vec<String>,
+        amounts: Vec<Uint128>,
+    ) -> SeiResult<ErcPayloadResponse> {
+        let request = SeiQuery::Erc1155BatchTransferPayload {
+            from,
+            recipient,
+            token_ids,
+            amounts,
+        }
+        .into();
+
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Query to get the hex payload for the ERC-1155 `setApprovalForAll` function.
+    ///
+    /// # Arguments
+    /// * `to` - Sei native (bech32-encoded 'sei*') address of the operator
+    /// * `approved` - Boolean representing the status to set
+    ///
+    /// # Returns
+    /// * `SeiResult<ErcPayloadResponse>` - A result that wraps the `ErcPayloadResponse`.
+    ///   `ErcPayloadResponse` contains the base64-encoded bytes.
+    ///
+    /// # Errors
+    /// This function will return an error if the query fails.
+    pub fn erc1155_set_approval_all_payload(
+        &self,
+        to: String,
+        approved: bool,
+    ) -> SeiResult<ErcPayloadResponse> {
+        let request = SeiQuery::Erc1155SetApprovalAllPayload { to, approved }.into();
+
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Query to get the balance of a token type of an account. Executes ERC-1155 `balanceOf`
+    /// function under the hood.
+    ///
+    /// # Arguments
+    /// * `caller` - Caller Sei native (bech32-encoded 'sei*') address.
+    /// * `contract_address` - The contract address of the ERC-1155 token.
+    /// * `account` - Sei native (bech32-encoded 'sei*') account address.
+    /// * `token_id` - The identifier for a token type. String representation of the token ID.
+    ///
+    /// # Returns
+    /// * `SeiResult<Erc1155BalanceOfResponse>` - A result that wraps the
+    ///   `Erc1155BalanceOfResponse`.
+    ///
+    /// # Errors
+    /// This function will return an error if the query fails.
+    pub fn erc1155_balance_of(
+        &self,
+        caller: String,
+        contract_address: String,
+        account: String,
+        token_id: String,
+    ) -> SeiResult<Erc1155BalanceOfResponse> {
+        let request = SeiQuery::Erc1155BalanceOf {
+            caller,
+            contract_address,
+            account,
+            token_id,
+        }
+        .into();
+
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Query to get the balances of multiple (account, token type) pairs. Executes ERC-1155
+    /// `balanceOfBatch` function under the hood.
+    ///
+    /// # Arguments
+    /// * `caller` - Caller Sei native (bech32-encoded 'sei*') address.
+    /// * `contract_address` - The contract address of the ERC-1155 token.
+    /// * `accounts` - Sei native (bech32-encoded 'sei*') account addresses.
+    /// * `token_ids` - The identifiers of the token types, in the order of `accounts`.
+    ///
+    /// # Returns
+    /// * `SeiResult<Erc1155BalanceOfBatchResponse>` - A result that wraps the
+    ///   `Erc1155BalanceOfBatchResponse`.
+    ///
+    /// # Errors
+    /// This function will return an error if the query fails.
+    pub fn erc1155_balance_of_batch(
+        &self,
+        caller: String,
+        contract_address: String,
+        accounts: Vec<String>,
+        token_ids: Vec<String>,
+    ) -> SeiResult<Erc1155BalanceOfBatchResponse> {
+        let request = SeiQuery::Erc1155BalanceOfBatch {
+            caller,
+            contract_address,
+            accounts,
+            token_ids,
+        }
+        .into();
+
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Query if an address is an authorized operator for another address. Executes ERC-1155
+    /// `isApprovedForAll` function.
+    ///
+    /// # Arguments
+    /// * `caller` - Caller Sei native (bech32-encoded 'sei*') address.
+    /// * `contract_address` - The contract address of the ERC-1155 token.
+    /// * `owner` - The owner of the tokens Sei native (bech32-encoded 'sei*') address
+    /// * `operator` - The operator Sei address that acts on behalf of the owner
+    ///
+    /// # Returns
+    /// * `SeiResult<Erc1155IsApprovedForAllResponse>` - A result that wraps the
+    ///   `Erc1155IsApprovedForAllResponse`.
+    ///
+    /// # Errors
+    /// This function will return an error if the query fails.
+    pub fn erc1155_is_approved_for_all(
+        &self,
+        caller: String,
+        contract_address: String,
+        owner: String,
+        operator: String,
+    ) -> SeiResult<Erc1155IsApprovedForAllResponse> {
+        let request = SeiQuery::Erc1155IsApprovedForAll {
+            caller,
+            contract_address,
+            owner,
+            operator,
+        }
+        .into();
+
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Query to get the URI for a given token type. Executes ERC-1155 `uri` function under the
+    /// hood.
+    ///
+    /// # Arguments
+    /// * `caller` - Caller Sei native (bech32-encoded 'sei*') address.
+    /// * `contract_address` - The contract address of the ERC-1155 token.
+    /// * `token_id` - The identifier for a token type. String representation of the token ID.
+    ///
+    /// # Returns
+    /// * `SeiResult<Erc1155UriResponse>` - A result that wraps the `Erc1155UriResponse`.
+    ///
+    /// # Errors
+    /// This function will return an error if the query fails.
+    pub fn erc1155_uri(
+        &self,
+        caller: String,
+        contract_address: String,
+        token_id: String,
+    ) -> SeiResult<Erc1155UriResponse> {
+        let request = SeiQuery::Erc1155Uri {
+            caller,
+            contract_address,
+            token_id,
         }
         .into();
 
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Query to get the royalty receiver and amount owed for a sale of a token. Executes ERC-2981
+    /// `royaltyInfo` function under the hood.
+    ///
+    /// # Arguments
+    /// * `caller` - Caller Sei native (bech32-encoded 'sei*') address.
+    /// * `contract_address` - The contract address of the ERC-1155 token.
+    /// * `token_id` - The identifier for a token type. String representation of the token ID.
+    /// * `sale_price` - The sale price of the token.
+    ///
+    /// # Returns
+    /// * `SeiResult<Erc1155RoyaltyInfoResponse>` - A result that wraps the
+    ///   `Erc1155RoyaltyInfoResponse`.
+    ///
+    /// # Errors
+    /// This function will return an error if the query fails.
+    pub fn erc1155_royalty_info(
+        &self,
+        caller: String,
+        contract_address: String,
+        token_id: String,
+        sale_price: Uint128,
+    ) -> SeiResult<Erc1155RoyaltyInfoResponse> {
+        let request = SeiQuery::Erc1155RoyaltyInfo {
+            caller,
+            contract_address,
+            token_id,
+            sale_price,
+        }
+        .into();
+
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     /// Queries the EVM (Ethereum Virtual Machine) address associated with a given Sei address.
     ///
     /// This function takes a `sei_address` as a parameter, which is a `String` representing the
-    /// SEI address. It returns a `StdResult<EvmAddressResponse>`, which is a standard result type
-    /// in the `cosmwasm_std` library. The `EvmAddressResponse` struct contains the EVM address and
-    /// a boolean indicating whether the EVM address is associated.
+    /// SEI address. It returns a `SeiResult<EvmAddressResponse>`. The `EvmAddressResponse` struct
+    /// contains the EVM address and a boolean indicating whether the EVM address is associated.
     ///
     /// # Arguments
     ///
@@ -641,32 +1229,27 @@ impl<'a> SeiQuerier<'a> {
     ///
     /// # Returns
     ///
-    /// * `StdResult<EvmAddressResponse>` - A standard result that wraps the `EvmAddressResponse`
-    /// struct. `EvmAddressResponse` contains the EVM address and a boolean indicating whether the
-    /// EVM address is associated. If the Sei address is not associated with any EVM address,
-    /// the EVM address will be an empty string.
+    /// * `SeiResult<EvmAddressResponse>` - A result that wraps the `EvmAddressResponse`
+    ///   struct. `EvmAddressResponse` contains the EVM address and a boolean indicating whether the
+    ///   EVM address is associated. If the Sei address is not associated with any EVM address,
+    ///   the EVM address will be an empty string.
     ///
     ///
     /// # Errors
     ///
     /// This function will return an error if the query to the EVM fails.
-    pub fn get_evm_address(&self, sei_address: String) -> StdResult<EvmAddressResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Evm,
-            query_data: SeiQuery::GetEvmAddress { sei_address },
-        }
-        .into();
+    pub fn get_evm_address(&self, sei_address: String) -> SeiResult<EvmAddressResponse> {
+        let request = SeiQuery::GetEvmAddress { sei_address }.into();
 
-        self.querier.query(&request)
+        self.querier.query(&request).map_err(SeiError::from)
     }
 
     /// Queries the Sei address associated with a given EVM address.
     ///
     /// This function takes an `evm_address` as a parameter, which is a `String` representing the
-    /// EVM address. It returns a `StdResult<SeiAddressResponse>`, which is a standard result type
-    /// in the `cosmwasm_std` library. The `SeiAddressResponse` struct contains the Sei address.
-    /// If the EVM address is not associated with any Sei address, the Sei address will be an empty
-    /// string.
+    /// EVM address. It returns a `SeiResult<SeiAddressResponse>`. The `SeiAddressResponse` struct
+    /// contains the Sei address. If the EVM address is not associated with any Sei address, the Sei
+    /// address will be an empty string.
     ///
     /// # Arguments
     ///
@@ -674,20 +1257,47 @@ impl<'a> SeiQuerier<'a> {
     ///
     /// # Returns
     ///
-    /// * `StdResult<SeiAddressResponse>` - A standard result that wraps the `SeiAddressResponse`
-    /// struct. `SeiAddressResponse` contains the Sei address. If the EVM address is not associated
-    /// with any Sei address, the Sei address will be an empty string.
+    /// * `SeiResult<SeiAddressResponse>` - A result that wraps the `SeiAddressResponse`
+    ///   struct. `SeiAddressResponse` contains the Sei address. If the EVM address is not associated
+    ///   with any Sei address, the Sei address will be an empty string.
     ///
     /// # Errors
     ///
     /// This function will return an error if the query to the EVM fails.
-    pub fn get_sei_address(&self, evm_address: String) -> StdResult<SeiAddressResponse> {
-        let request = SeiQueryWrapper {
-            route: SeiRoute::Evm,
-            query_data: SeiQuery::GetSeiAddress { evm_address },
+    pub fn get_sei_address(&self, evm_address: String) -> SeiResult<SeiAddressResponse> {
+        let request = SeiQuery::GetSeiAddress { evm_address }.into();
+
+        self.querier.query(&request).map_err(SeiError::from)
+    }
+
+    /// Returns the EVM address associated with the Sei native (bech32-encoded 'sei*')
+    /// `sei_address`.
+    ///
+    /// # Errors
+    /// This function will return `SeiError::AddressNotAssociated` if the Sei address is not
+    /// associated with any EVM address, or an error if the query fails.
+    pub fn associated_evm_address(&self, sei_address: String) -> SeiResult<String> {
+        let res = self.get_evm_address(sei_address.clone())?;
+        if !res.associated {
+            return Err(SeiError::AddressNotAssociated {
+                address: sei_address,
+            });
         }
-        .into();
+        Ok(res.evm_address)
+    }
 
-        self.querier.query(&request)
+    /// Returns the Sei native (bech32-encoded 'sei*') address associated with the `evm_address`.
+    ///
+    /// # Errors
+    /// This function will return `SeiError::AddressNotAssociated` if the EVM address is not
+    /// associated with any Sei address, or an error if the query fails.
+    pub fn associated_sei_address(&self, evm_address: String) -> SeiResult<String> {
+        let res = self.get_sei_address(evm_address.clone())?;
+        if !res.associated {
+            return Err(SeiError::AddressNotAssociated {
+                address: evm_address,
+            });
+        }
+        Ok(res.sei_address)
     }
 }
@@ -1,7 +1,9 @@
-use cosmwasm_std::{Addr, CustomQuery, Decimal, Uint128};
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, Binary, CustomQuery, Decimal, QueryRequest, StdResult, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::abi::{abi_decode, AbiToken, AbiType};
 use crate::proto_structs::{DenomOracleExchangeRatePair, DexPair, DexTwap, Epoch, OracleTwap};
 use crate::route::SeiRoute;
 use crate::sei_types::{DenomAuthorityMetadata, OrderResponse};
@@ -18,48 +20,123 @@ pub struct SeiQueryWrapper {
 // implement custom query
 impl CustomQuery for SeiQueryWrapper {}
 
+/// Wraps the query with the route of its module
+impl From<SeiQuery> for SeiQueryWrapper {
+    fn from(query_data: SeiQuery) -> Self {
+        SeiQueryWrapper {
+            route: query_data.route(),
+            query_data,
+        }
+    }
+}
+
+impl From<SeiQuery> for QueryRequest<SeiQueryWrapper> {
+    fn from(query_data: SeiQuery) -> Self {
+        QueryRequest::Custom(query_data.into())
+    }
+}
+
 /// SeiQuery is defines available query datas
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, QueryResponses)]
 #[serde(rename_all = "snake_case")]
 pub enum SeiQuery {
-    ExchangeRates {},
-    OracleTwaps {
-        lookback_seconds: u64,
+    /// Latest exchange rate of each denom, ordered by denom
+    #[returns(ExchangeRatesResponse)]
+    ExchangeRates {
+        /// Returns the denoms after this denom
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        start_after: Option<String>,
+        /// Maximum number of exchange rates to return, all if not set
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit: Option<u32>,
     },
+    #[returns(OracleTwapsResponse)]
+    OracleTwaps { lookback_seconds: u64 },
+    #[returns(DexTwapsResponse)]
     DexTwaps {
         contract_address: Addr,
         lookback_seconds: u64,
     },
+    #[returns(EpochResponse)]
     Epoch {},
+    /// Orders of `account`, ordered by id
+    #[returns(GetOrdersResponse)]
     GetOrders {
         contract_address: Addr,
         account: Addr,
+        /// Returns the orders after this order id
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        start_after: Option<u64>,
+        /// Maximum number of orders to return, all if not set
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit: Option<u32>,
     },
+    #[returns(GetOrderByIdResponse)]
     GetOrderById {
         contract_address: Addr,
         price_denom: String,
         asset_denom: String,
         id: u64,
     },
+    #[returns(GetLatestPriceResponse)]
     GetLatestPrice {
         contract_address: Addr,
         price_denom: String,
         asset_denom: String,
     },
+    /// Resting limit orders of the pair aggregated per price level, best price first
+    #[returns(OrderBookDepthResponse)]
+    OrderBookDepth {
+        contract_address: Addr,
+        price_denom: String,
+        asset_denom: String,
+        /// Maximum number of price levels per side, all if not set
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit: Option<u32>,
+    },
+    #[returns(GetRegisteredPairsResponse)]
+    GetRegisteredPairs { contract_address: Addr },
+    /// Prices and volume of the fills of the pair over the last `lookback_seconds`
+    #[returns(MarketSummaryResponse)]
+    GetMarketSummary {
+        contract_address: Addr,
+        price_denom: String,
+        asset_denom: String,
+        lookback_seconds: u64,
+    },
+    /// Candlesticks of the fills of the pair over the last `number_of_periods` periods of
+    /// `period_length_in_seconds`, the last period ending at the current block time
+    #[returns(GetHistoricalPricesResponse)]
+    GetHistoricalPrices {
+        contract_address: Addr,
+        price_denom: String,
+        asset_denom: String,
+        period_length_in_seconds: u64,
+        number_of_periods: u64,
+    },
+    #[returns(OrderSimulationResponse)]
     OrderSimulation {
         contract_address: Addr,
         order: Order,
     },
-    DenomAuthorityMetadata {
-        denom: String,
-    },
+    #[returns(DenomAuthorityMetadataResponse)]
+    DenomAuthorityMetadata { denom: String },
+    /// Denoms created by `creator`, ordered by denom
+    #[returns(DenomsFromCreatorResponse)]
     DenomsFromCreator {
         creator: Addr,
+        /// Returns the denoms after this denom
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        start_after: Option<String>,
+        /// Maximum number of denoms to return, all if not set
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit: Option<u32>,
     },
 
     /// Query to for static call to EVM contract.
     /// StaticCall executes the contract associated deployed at `to` address with the given `data`
     /// as parameters while disallowing any modifications to the state during the call.
+    #[returns(StaticCallResponse)]
     StaticCall {
         /// Sei native (bech32-encoded 'sei*') address calling the contract
         from: String,
@@ -69,6 +146,7 @@ pub enum SeiQuery {
         data: String, // base64
     },
     /// Query to get hex payload for the ERC-20 `transfer` function
+    #[returns(ErcPayloadResponse)]
     Erc20TransferPayload {
         /// Recipient Sei native (bech32-encoded 'sei*') address
         recipient: String,
@@ -76,6 +154,7 @@ pub enum SeiQuery {
         amount: Uint128,
     },
     /// Query to get hex payload for the ERC-20 `transferFrom` function
+    #[returns(ErcPayloadResponse)]
     Erc20TransferFromPayload {
         /// Owner Sei native (bech32-encoded 'sei*') address
         owner: String,
@@ -85,6 +164,7 @@ pub enum SeiQuery {
         amount: Uint128,
     },
     /// Query to get hex payload for the ERC-20 `approve` function
+    #[returns(ErcPayloadResponse)]
     Erc20ApprovePayload {
         /// Spender Sei native (bech32-encoded 'sei*') address
         spender: String,
@@ -93,6 +173,7 @@ pub enum SeiQuery {
     },
     /// Query to get the remaining number of tokens that spender will be allowed to spend on behalf
     /// of owner through
+    #[returns(Erc20AllowanceResponse)]
     Erc20Allowance {
         /// ERC-20 contract address
         contract_address: String,
@@ -102,6 +183,7 @@ pub enum SeiQuery {
         spender: String,
     },
     /// Query to get the token info, including the name, symbol, decimals and total supply
+    #[returns(cw20::TokenInfoResponse)]
     Erc20TokenInfo {
         /// ERC-20 contract address
         contract_address: String,
@@ -110,6 +192,7 @@ pub enum SeiQuery {
     },
     /// Query to get the balance of the account with the given Sei native (bech32-encoded 'sei*') address.
     /// Executes the `balanceOf` ERC-20 function under the hood.
+    #[returns(cw20::BalanceResponse)]
     Erc20Balance {
         /// ERC-20 contract address
         contract_address: String,
@@ -117,6 +200,7 @@ pub enum SeiQuery {
         account: String,
     },
     /// Query to get the hex payload for the ERC-721 `transferFrom` function
+    #[returns(ErcPayloadResponse)]
     Erc721TransferPayload {
         /// Sei native (bech32-encoded 'sei*') address of the sender
         from: String,
@@ -126,6 +210,7 @@ pub enum SeiQuery {
         token_id: String,
     },
     /// Query to get the hex payload for the ERC-721 `approve` function
+    #[returns(ErcPayloadResponse)]
     Erc721ApprovePayload {
         /// Sei native (bech32-encoded 'sei*') address of the spender
         spender: String,
@@ -134,6 +219,7 @@ pub enum SeiQuery {
     },
     /// Query to get the Sei native (bech32-encoded 'sei*') address of the owner of the NFT.
     /// Executes ERC-721 `ownerOf` function under the hood.
+    #[returns(Erc721OwnerResponse)]
     Erc721Owner {
         /// Caller Sei native (bech32-encoded 'sei*') address
         caller: String,
@@ -143,6 +229,7 @@ pub enum SeiQuery {
         token_id: String,
     },
     /// Query to get the approved address for a single NFT. Executes ERC-721 `getApproved` function
+    #[returns(Erc721ApprovedResponse)]
     Erc721Approved {
         /// Caller Sei native (bech32-encoded 'sei*') address
         caller: String,
@@ -153,6 +240,7 @@ pub enum SeiQuery {
     },
     /// Query if an address is an authorized operator for another address. Executes ERC-721
     /// `isApprovedForAll` function.
+    #[returns(Erc721IsApprovedForAllResponse)]
     Erc721IsApprovedForAll {
         /// Caller Sei native (bech32-encoded 'sei*') address
         caller: String,
@@ -164,6 +252,7 @@ pub enum SeiQuery {
         operator: String,
     },
     /// Query to get the hex payload for the ERC-721 `setApprovalForAll` function.
+    #[returns(ErcPayloadResponse)]
     Erc721SetApprovalAllPayload {
         /// Sei native (bech32-encoded 'sei*') address of the operator
         to: String,
@@ -172,6 +261,7 @@ pub enum SeiQuery {
     },
     /// Query to get the name and symbol of the ERC-721 contract. Executes ERC-721 `name` and
     /// `symbol` functions under the hood.
+    #[returns(Erc721NameSymbolResponse)]
     Erc721NameSymbol {
         /// Caller Sei native (bech32-encoded 'sei*') address
         caller: String,
@@ -179,6 +269,7 @@ pub enum SeiQuery {
         contract_address: String,
     },
     /// Query to get the URI for a given NFT. Executes ERC-721 `tokenURI` function under the hood.
+    #[returns(Erc721UriResponse)]
     Erc721Uri {
         /// Caller Sei native (bech32-encoded 'sei*') address
         caller: String,
@@ -187,20 +278,225 @@ pub enum SeiQuery {
 
         token_id: String,
     },
-    /// Query to get the EVM address associated with the given SEI address.
-    GetEvmAddress {
-        sei_address: String,
+    /// Query to get the number of NFTs tracked by the ERC-721 contract. Executes ERC-721
+    /// `totalSupply` function under the hood.
+    #[returns(Erc721TotalSupplyResponse)]
+    Erc721TotalSupply {
+        /// Caller Sei native (bech32-encoded 'sei*') address
+        caller: String,
+        /// ERC-721 contract address
+        contract_address: String,
     },
-    /// Query to get the SEI address associated with the given EVM address.
-    GetSeiAddress {
-        evm_address: String,
+    /// Query to get the identifier of the NFT at `index` of all the NFTs. Executes ERC-721
+    /// `tokenByIndex` function under the hood.
+    #[returns(Erc721TokenByIndexResponse)]
+    Erc721TokenByIndex {
+        /// Caller Sei native (bech32-encoded 'sei*') address
+        caller: String,
+        /// ERC-721 contract address
+        contract_address: String,
+        /// Index of the NFT, less than the total supply
+        index: u64,
+    },
+    /// Query to get the identifier of the NFT at `index` of the NFTs of `owner`. Executes ERC-721
+    /// `tokenOfOwnerByIndex` function under the hood.
+    #[returns(Erc721TokenOfOwnerByIndexResponse)]
+    Erc721TokenOfOwnerByIndex {
+        /// Caller Sei native (bech32-encoded 'sei*') address
+        caller: String,
+        /// ERC-721 contract address
+        contract_address: String,
+        /// The owner of the NFTs Sei native (bech32-encoded 'sei*') address
+        owner: String,
+        /// Index of the NFT, less than the balance of `owner`
+        index: u64,
+    },
+    /// Query to get the number of NFTs owned by an address. Executes ERC-721 `balanceOf`
+    /// function under the hood.
+    #[returns(Erc721BalanceOfResponse)]
+    Erc721BalanceOf {
+        /// Caller Sei native (bech32-encoded 'sei*') address
+        caller: String,
+        /// ERC-721 contract address
+        contract_address: String,
+        /// The owner of the NFTs Sei native (bech32-encoded 'sei*') address
+        owner: String,
+    },
+    /// Query to get the royalty receiver and amount owed for a sale of an NFT. Executes ERC-2981
+    /// `royaltyInfo` function under the hood.
+    #[returns(Erc721RoyaltyInfoResponse)]
+    Erc721RoyaltyInfo {
+        /// Caller Sei native (bech32-encoded 'sei*') address
+        caller: String,
+        /// ERC-721 contract address
+        contract_address: String,
+        /// The identifier for an NFT. String representation of the token ID
+        token_id: String,
+        /// The sale price of the NFT
+        sale_price: Uint128,
+    },
+    /// Query to get the hex payload for the ERC-1155 `safeTransferFrom` function
+    #[returns(ErcPayloadResponse)]
+    Erc1155TransferPayload {
+        /// Sei native (bech32-encoded 'sei*') address of the sender
+        from: String,
+        /// Sei native (bech32-encoded 'sei*') address of the recipient
+        recipient: String,
+        /// The identifier for a token type. String representation of the token ID
+        token_id: String,
+        /// Amount of tokens to transfer
+        amount: Uint128,
+    },
+    /// Query to get the hex payload for the ERC-1155 `safeBatchTransferFrom` function
+    #[returns(ErcPayloadResponse)]
+    Erc1155BatchTransferPayload {
+        /// Sei native (bech32-encoded 'sei*') address of the sender
+        from: String,
+        /// Sei native (bech32-encoded 'sei*') address of the recipient
+        recipient: String,
+        /// The identifiers of the token types. String representations of the token IDs
+        token_ids: Vec<String>,
+        /// Amount of tokens to transfer for each token type, in the order of `token_ids`
+        amounts: Vec<Uint128>,
+    },
+    /// Query to get the hex payload for the ERC-1155 `setApprovalForAll` function.
+    #[returns(ErcPayloadResponse)]
+    Erc1155SetApprovalAllPayload {
+        /// Sei native (bech32-encoded 'sei*') address of the operator
+        to: String,
+        /// Boolean representing the status to set
+        approved: bool,
+    },
+    /// Query to get the balance of a token type of an account. Executes ERC-1155 `balanceOf`
+    /// function under the hood.
+    #[returns(Erc1155BalanceOfResponse)]
+    Erc1155BalanceOf {
+        /// Caller Sei native (bech32-encoded 'sei*') address
+        caller: String,
+        /// ERC-1155 contract address
+        contract_address: String,
+        /// Account Sei native (bech32-encoded 'sei*') address
+        account: String,
+        /// The identifier for a token type. String representation of the token ID
+        token_id: String,
+    },
+    /// Query to get the balances of multiple (account, token type) pairs. Executes ERC-1155
+    /// `balanceOfBatch` function under the hood.
+    #[returns(Erc1155BalanceOfBatchResponse)]
+    Erc1155BalanceOfBatch {
+        /// Caller Sei native (bech32-encoded 'sei*') address
+        caller: String,
+        /// ERC-1155 contract address
+        contract_address: String,
+        /// Account Sei native (bech32-encoded 'sei*') addresses
+        accounts: Vec<String>,
+        /// The identifiers of the token types, in the order of `accounts`
+        token_ids: Vec<String>,
     },
+    /// Query if an address is an authorized operator for another address. Executes ERC-1155
+    /// `isApprovedForAll` function.
+    #[returns(Erc1155IsApprovedForAllResponse)]
+    Erc1155IsApprovedForAll {
+        /// Caller Sei native (bech32-encoded 'sei*') address
+        caller: String,
+        /// ERC-1155 contract address
+        contract_address: String,
+        /// The owner of the tokens Sei native (bech32-encoded 'sei*') address
+        owner: String,
+        /// The operator Sei address that acts on behalf of the owner
+        operator: String,
+    },
+    /// Query to get the URI for a given token type. Executes ERC-1155 `uri` function under the
+    /// hood.
+    #[returns(Erc1155UriResponse)]
+    Erc1155Uri {
+        /// Caller Sei native (bech32-encoded 'sei*') address
+        caller: String,
+        /// ERC-1155 contract address
+        contract_address: String,
+        /// The identifier for a token type. String representation of the token ID
+        token_id: String,
+    },
+    /// Query to get the royalty receiver and amount owed for a sale of a token. Executes ERC-2981
+    /// `royaltyInfo` function under the hood.
+    #[returns(Erc1155RoyaltyInfoResponse)]
+    Erc1155RoyaltyInfo {
+        /// Caller Sei native (bech32-encoded 'sei*') address
+        caller: String,
+        /// ERC-1155 contract address
+        contract_address: String,
+        /// The identifier for a token type. String representation of the token ID
+        token_id: String,
+        /// The sale price of the token
+        sale_price: Uint128,
+    },
+    /// Query to get the EVM address associated with the given SEI address.
+    #[returns(EvmAddressResponse)]
+    GetEvmAddress { sei_address: String },
+    /// Query to get the SEI address associated with the given EVM address.
+    #[returns(SeiAddressResponse)]
+    GetSeiAddress { evm_address: String },
+}
+
+impl SeiQuery {
+    /// The route of the module handling the query
+    pub fn route(&self) -> SeiRoute {
+        match self {
+            SeiQuery::ExchangeRates { .. } | SeiQuery::OracleTwaps { .. } => SeiRoute::Oracle,
+            SeiQuery::DexTwaps { .. }
+            | SeiQuery::GetOrders { .. }
+            | SeiQuery::GetOrderById { .. }
+            | SeiQuery::GetLatestPrice { .. }
+            | SeiQuery::OrderBookDepth { .. }
+            | SeiQuery::GetRegisteredPairs { .. }
+            | SeiQuery::GetMarketSummary { .. }
+            | SeiQuery::GetHistoricalPrices { .. }
+            | SeiQuery::OrderSimulation { .. } => SeiRoute::Dex,
+            SeiQuery::Epoch { .. } => SeiRoute::Epoch,
+            SeiQuery::DenomAuthorityMetadata { .. } | SeiQuery::DenomsFromCreator { .. } => {
+                SeiRoute::Tokenfactory
+            }
+            SeiQuery::StaticCall { .. }
+            | SeiQuery::Erc20TransferPayload { .. }
+            | SeiQuery::Erc20TransferFromPayload { .. }
+            | SeiQuery::Erc20ApprovePayload { .. }
+            | SeiQuery::Erc20Allowance { .. }
+            | SeiQuery::Erc20TokenInfo { .. }
+            | SeiQuery::Erc20Balance { .. }
+            | SeiQuery::Erc721TransferPayload { .. }
+            | SeiQuery::Erc721ApprovePayload { .. }
+            | SeiQuery::Erc721Owner { .. }
+            | SeiQuery::Erc721Approved { .. }
+            | SeiQuery::Erc721IsApprovedForAll { .. }
+            | SeiQuery::Erc721SetApprovalAllPayload { .. }
+            | SeiQuery::Erc721NameSymbol { .. }
+            | SeiQuery::Erc721Uri { .. }
+            | SeiQuery::Erc721TotalSupply { .. }
+            | SeiQuery::Erc721TokenByIndex { .. }
+            | SeiQuery::Erc721TokenOfOwnerByIndex { .. }
+            | SeiQuery::Erc721BalanceOf { .. }
+            | SeiQuery::Erc721RoyaltyInfo { .. }
+            | SeiQuery::Erc1155TransferPayload { .. }
+            | SeiQuery::Erc1155BatchTransferPayload { .. }
+            | SeiQuery::Erc1155SetApprovalAllPayload { .. }
+            | SeiQuery::Erc1155BalanceOf { .. }
+            | SeiQuery::Erc1155BalanceOfBatch { .. }
+            | SeiQuery::Erc1155IsApprovedForAll { .. }
+            | SeiQuery::Erc1155Uri { .. }
+            | SeiQuery::Erc1155RoyaltyInfo { .. }
+            | SeiQuery::GetEvmAddress { .. }
+            | SeiQuery::GetSeiAddress { .. } => SeiRoute::Evm,
+        }
+    }
 }
 
 /// ExchangeRatesResponse is data format returned from OracleRequest::ExchangeRates query
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct ExchangeRatesResponse {
     pub denom_oracle_exchange_rate_pairs: Vec<DenomOracleExchangeRatePair>,
+    /// `start_after` of the next page, `None` on the last page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_key: Option<String>,
 }
 
 /// OracleTwapsResponse is data format returned from OracleRequest::OracleTwaps query
@@ -225,6 +521,9 @@ pub struct EpochResponse {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct GetOrdersResponse {
     pub orders: Vec<OrderResponse>,
+    /// `start_after` of the next page, `None` on the last page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_key: Option<u64>,
 }
 
 /// GetOrderdByIdResponse is data format returned from GetOrderById query
@@ -248,6 +547,60 @@ pub struct GetLatestPriceResponse {
     pub price: PriceResponse,
 }
 
+/// Total quantity of the resting orders at a price
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PriceLevel {
+    pub price: Decimal,
+    pub quantity: Decimal,
+}
+
+/// OrderBookDepthResponse is data format returned from OrderBookDepth query
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OrderBookDepthResponse {
+    /// Highest price first
+    pub longs: Vec<PriceLevel>,
+    /// Lowest price first
+    pub shorts: Vec<PriceLevel>,
+}
+
+/// GetRegisteredPairsResponse is data format returned from GetRegisteredPairs query
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GetRegisteredPairsResponse {
+    pub pairs: Vec<DexPair>,
+}
+
+/// MarketSummaryResponse is data format returned from GetMarketSummary query. The prices are
+/// `None` without fills in the window.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MarketSummaryResponse {
+    pub high_price: Option<Decimal>,
+    pub low_price: Option<Decimal>,
+    pub last_price: Option<Decimal>,
+    /// Filled quantity of the asset
+    pub total_volume: Decimal,
+    /// Value of the filled quantity in the price denom
+    pub total_volume_notional: Decimal,
+}
+
+/// Open, high, low and close prices and volume of the fills in a period
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PriceCandlestick {
+    pub begin_timestamp: u64,
+    pub end_timestamp: u64,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    pub volume: Decimal,
+}
+
+/// GetHistoricalPricesResponse is data format returned from GetHistoricalPrices query
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GetHistoricalPricesResponse {
+    /// Periods with fills, oldest first
+    pub prices: Vec<PriceCandlestick>,
+}
+
 /// OrderSimulationResponse is data format returned from OrderSimulation query
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct OrderSimulationResponse {
@@ -264,6 +617,9 @@ pub struct DenomAuthorityMetadataResponse {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct DenomsFromCreatorResponse {
     pub denoms: Vec<String>,
+    /// `start_after` of the next page, `None` on the last page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_key: Option<String>,
 }
 
 /// `StaticCallResponse` is a struct that represents a response containing the result of a static
@@ -275,6 +631,15 @@ pub struct StaticCallResponse {
     pub encoded_data: String, // base64
 }
 
+impl StaticCallResponse {
+    /// Decodes the base64 encoded return data of the static call as ABI encoded values of the
+    /// given `types`.
+    pub fn decode(&self, types: &[AbiType]) -> StdResult<Vec<AbiToken>> {
+        let data = Binary::from_base64(&self.encoded_data)?;
+        abi_decode(types, data.as_slice())
+    }
+}
+
 /// `ErcPayloadResponse` is a struct that represents a response containing the encoded payload for
 /// payload generation queries.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -332,6 +697,92 @@ pub struct Erc721UriResponse {
     pub uri: String,
 }
 
+/// `Erc721TotalSupplyResponse` is a struct that represents a response containing the number of
+/// NFTs tracked by the ERC-721 contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Erc721TotalSupplyResponse {
+    /// The number of NFTs with a valid owner
+    pub supply: Uint128,
+}
+
+/// `Erc721TokenByIndexResponse` is a struct that represents a response containing the identifier
+/// of the NFT at an index of all the NFTs.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Erc721TokenByIndexResponse {
+    /// String representation of the token ID
+    pub token_id: String,
+}
+
+/// `Erc721TokenOfOwnerByIndexResponse` is a struct that represents a response containing the
+/// identifier of the NFT at an index of the NFTs of an owner.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Erc721TokenOfOwnerByIndexResponse {
+    /// String representation of the token ID
+    pub token_id: String,
+}
+
+/// `Erc721BalanceOfResponse` is a struct that represents a response containing the number of NFTs
+/// owned by an address.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Erc721BalanceOfResponse {
+    /// The number of NFTs owned by the address
+    pub balance: Uint128,
+}
+
+/// `Erc721RoyaltyInfoResponse` is a struct that represents a response containing the royalty owed
+/// for a sale of an NFT.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Erc721RoyaltyInfoResponse {
+    /// The Sei native (bech32-encoded 'sei*') address of the royalty receiver, or the zero address
+    /// if there is no royalty
+    pub receiver: String,
+    /// The royalty amount owed for the sale price
+    pub royalty_amount: Uint128,
+}
+
+/// `Erc1155BalanceOfResponse` is a struct that represents a response containing the balance of a
+/// token type of an account.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Erc1155BalanceOfResponse {
+    /// The balance of the token type
+    pub balance: Uint128,
+}
+
+/// `Erc1155BalanceOfBatchResponse` is a struct that represents a response containing the balances
+/// of multiple (account, token type) pairs.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Erc1155BalanceOfBatchResponse {
+    /// The balance of each (account, token type) pair, in the order of the query
+    pub balances: Vec<Uint128>,
+}
+
+/// `Erc1155IsApprovedForAllResponse` is a struct that represents a response containing a boolean
+/// value indicating if an address is an authorized operator for another address
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Erc1155IsApprovedForAllResponse {
+    /// True if `operator` is an approved operator for `owner`, false otherwise
+    pub is_approved: bool,
+}
+
+/// `Erc1155UriResponse` is a struct that represents a response containing the URI for a given
+/// token type.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Erc1155UriResponse {
+    /// The URI for the given token type
+    pub uri: String,
+}
+
+/// `Erc1155RoyaltyInfoResponse` is a struct that represents a response containing the royalty
+/// owed for a sale of a token.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Erc1155RoyaltyInfoResponse {
+    /// The Sei native (bech32-encoded 'sei*') address of the royalty receiver, or the zero address
+    /// if there is no royalty
+    pub receiver: String,
+    /// The royalty amount owed for the sale price
+    pub royalty_amount: Uint128,
+}
+
 /// `EvmAddressResponse` is a struct that represents a response containing an EVM address.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct EvmAddressResponse {
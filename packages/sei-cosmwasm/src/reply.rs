@@ -0,0 +1,61 @@
+use cosmwasm_std::{Reply, StdError};
+
+use crate::error::{SeiError, SeiResult};
+use crate::proto::MsgResponse;
+use crate::tx::{
+    MsgBurnResponse, MsgCancelOrdersResponse, MsgChangeAdminResponse, MsgCreateDenomResponse,
+    MsgInternalEVMCallResponse, MsgMintResponse, MsgPlaceOrdersResponse,
+    MsgSetDenomMetadataResponse,
+};
+
+/// Parses the reply to a `SeiMsg::PlaceOrders` submessage, returning the ids of the placed
+/// orders. Failed submessages are returned as errors, see `SeiError::from`.
+pub fn parse_reply_place_orders(reply: Reply) -> SeiResult<MsgPlaceOrdersResponse> {
+    parse_reply(reply)
+}
+
+/// Parses the reply to a `SeiMsg::CancelOrders` submessage
+pub fn parse_reply_cancel_orders(reply: Reply) -> SeiResult<MsgCancelOrdersResponse> {
+    parse_reply(reply)
+}
+
+/// Parses the reply to a `SeiMsg::CreateDenom` submessage, returning the new denom
+pub fn parse_reply_create_denom(reply: Reply) -> SeiResult<MsgCreateDenomResponse> {
+    parse_reply(reply)
+}
+
+/// Parses the reply to a `SeiMsg::MintTokens` submessage
+pub fn parse_reply_mint_tokens(reply: Reply) -> SeiResult<MsgMintResponse> {
+    parse_reply(reply)
+}
+
+/// Parses the reply to a `SeiMsg::BurnTokens` submessage
+pub fn parse_reply_burn_tokens(reply: Reply) -> SeiResult<MsgBurnResponse> {
+    parse_reply(reply)
+}
+
+/// Parses the reply to a `SeiMsg::ChangeAdmin` submessage
+pub fn parse_reply_change_admin(reply: Reply) -> SeiResult<MsgChangeAdminResponse> {
+    parse_reply(reply)
+}
+
+/// Parses the reply to a `SeiMsg::SetMetadata` submessage
+pub fn parse_reply_set_metadata(reply: Reply) -> SeiResult<MsgSetDenomMetadataResponse> {
+    parse_reply(reply)
+}
+
+/// Parses the reply to a `SeiMsg::CallEvm` or `SeiMsg::DelegateCallEvm` submessage, returning
+/// the data returned by the EVM contract
+pub fn parse_reply_call_evm(reply: Reply) -> SeiResult<MsgInternalEVMCallResponse> {
+    parse_reply(reply)
+}
+
+// Decodes the protobuf encoded response data of a successful submessage, missing data is
+// decoded as an empty message
+fn parse_reply<T: MsgResponse>(reply: Reply) -> SeiResult<T> {
+    let res = reply
+        .result
+        .into_result()
+        .map_err(|err| SeiError::from(StdError::generic_err(err)))?;
+    T::decode(res.data.unwrap_or_default().as_slice())
+}
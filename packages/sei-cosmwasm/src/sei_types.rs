@@ -39,7 +39,36 @@ pub enum PositionDirection {
 #[repr(i32)]
 pub enum OrderType {
     Limit = 0,
+    /// Immediate-or-cancel order at the best prices in the book, `price` being the worst price
+    /// accepted (zero for any price). The part not filled in the block is cancelled.
     Market = 1,
+    /// Market order placed by the chain to close a liquidated position
+    Liquidation = 2,
+    /// Fill-or-kill market order, cancelled unless it can be completely filled in the block
+    FokMarket = 3,
+    /// Fill-or-kill market order for the `nominal` value in the price denom instead of a quantity
+    FokMarketByValue = 4,
+    /// Market order placed once the last traded price reaches the `trigger_price`
+    StopLoss = 5,
+    /// Limit order placed once the last traded price reaches the `trigger_price`
+    StopLimit = 6,
+}
+
+impl OrderType {
+    /// Whether the order fills at the best prices in the book and is not kept in the book
+    pub fn is_market(self) -> bool {
+        !matches!(self, OrderType::Limit | OrderType::StopLimit)
+    }
+
+    /// Whether the order is placed once the last traded price reaches its `trigger_price`
+    pub fn is_triggered(self) -> bool {
+        matches!(self, OrderType::StopLoss | OrderType::StopLimit)
+    }
+
+    /// Whether the order is cancelled unless it can be completely filled
+    pub fn is_fill_or_kill(self) -> bool {
+        matches!(self, OrderType::FokMarket | OrderType::FokMarketByValue)
+    }
 }
 
 #[derive(Serialize_repr, Deserialize_repr, Copy, Clone, Debug, PartialEq, Eq, Hash, JsonSchema)]
@@ -63,6 +92,10 @@ pub struct Order {
     pub data: String, // serialized order data, defined by the specific target contract
     pub status_description: String,
     pub nominal: Decimal, // only needed for Fokmarketbyvalue order
+    /// Last traded price placing a stop loss or stop limit order: long stop orders are placed
+    /// once the price rises to it, short stop orders once the price falls to it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trigger_price: Option<Decimal>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -117,12 +150,12 @@ pub struct DepositInfo {
     pub amount: Decimal,
 }
 
-#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct BulkOrderPlacementsResponse {
     pub unsuccessful_orders: Vec<UnsuccessfulOrder>,
 }
 
-#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct UnsuccessfulOrder {
     pub id: u64,
     pub reason: String,
@@ -0,0 +1,138 @@
+use cosmwasm_std::{StdError, StdResult, Timestamp};
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+/// Parses an RFC3339 date-time, e.g. `2022-06-14T18:43:34.963452Z` or
+/// `2022-06-14T20:43:34+02:00`, into a timestamp. Fractional seconds beyond nanoseconds are
+/// truncated. Times before the Unix epoch or after the largest `Timestamp`, in 2554, are
+/// rejected.
+pub fn parse_rfc3339(value: &str) -> StdResult<Timestamp> {
+    let err = |reason: &str| StdError::parse_err("Timestamp", format!("{}: {}", reason, value));
+    let bytes = value.as_bytes();
+    if bytes.len() < 20
+        || bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || !matches!(bytes[10], b'T' | b't' | b' ')
+        || bytes[13] != b':'
+        || bytes[16] != b':'
+    {
+        return Err(err("invalid RFC3339 date-time"));
+    }
+    let number = |range: std::ops::Range<usize>| -> StdResult<i64> {
+        let digits = &bytes[range];
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return Err(err("invalid RFC3339 date-time"));
+        }
+        Ok(digits.iter().fold(0, |n, d| n * 10 + i64::from(d - b'0')))
+    };
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    if !(1..=12).contains(&month)
+        || day < 1
+        || day > days_in_month(year, month)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return Err(err("date-time out of range"));
+    }
+
+    let mut rest = &bytes[19..];
+    let mut nanos = 0u64;
+    if let Some(fraction) = rest.strip_prefix(b".") {
+        let digits = fraction.iter().take_while(|b| b.is_ascii_digit()).count();
+        if digits == 0 {
+            return Err(err("invalid RFC3339 date-time"));
+        }
+        nanos = fraction[..digits]
+            .iter()
+            .chain(std::iter::repeat(&b'0'))
+            .take(9)
+            .fold(0, |n, d| n * 10 + u64::from(d - b'0'));
+        rest = &fraction[digits..];
+    }
+    let offset = match rest {
+        [b'Z' | b'z'] => 0,
+        [sign @ (b'+' | b'-'), h1, h2, b':', m1, m2] => {
+            let digits = [*h1, *h2, *m1, *m2];
+            if !digits.iter().all(u8::is_ascii_digit) {
+                return Err(err("invalid RFC3339 offset"));
+            }
+            let hours = i64::from((h1 - b'0') * 10 + (h2 - b'0'));
+            let minutes = i64::from((m1 - b'0') * 10 + (m2 - b'0'));
+            if hours > 23 || minutes > 59 {
+                return Err(err("offset out of range"));
+            }
+            let offset = hours * 3_600 + minutes * 60;
+            if *sign == b'-' {
+                -offset
+            } else {
+                offset
+            }
+        }
+        _ => return Err(err("invalid RFC3339 offset")),
+    };
+
+    let seconds =
+        days_from_civil(year, month, day) * 86_400 + hour * 3_600 + minute * 60 + second - offset;
+    if seconds < 0 {
+        return Err(err("date-time before the Unix epoch"));
+    }
+    (seconds as u64)
+        .checked_mul(NANOS_PER_SECOND)
+        .and_then(|n| n.checked_add(nanos))
+        .map(Timestamp::from_nanos)
+        .ok_or_else(|| err("date-time after the largest timestamp"))
+}
+
+/// Formats a timestamp as an RFC3339 UTC date-time with nanoseconds, e.g.
+/// `2019-10-12T07:20:50.000000052Z`. The result parses back with `parse_rfc3339`.
+pub fn format_rfc3339(time: Timestamp) -> String {
+    let secs = time.seconds();
+    let (days, secs_of_day) = (secs / 86_400, secs % 86_400);
+    let (year, month, day) = civil_from_days(days as i64);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3_600,
+        secs_of_day % 3_600 / 60,
+        secs_of_day % 60,
+        time.subsec_nanos()
+    )
+}
+
+// Days from 1970-01-01 of a proleptic Gregorian date,
+// http://howardhinnant.github.io/date_algorithms.html
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+// Inverse of `days_from_civil`
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    (yoe + era * 400 + i64::from(month <= 2), month, day)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
@@ -0,0 +1,154 @@
+use cosmwasm_std::{Binary, Int256, Uint256};
+use sei_cosmwasm::{
+    abi_decode, abi_encode, encode_function_call, function_selector, AbiToken, AbiType,
+    StaticCallResponse,
+};
+
+fn hex_words(words: &[&str]) -> Vec<u8> {
+    words
+        .iter()
+        .flat_map(|w| {
+            (0..w.len())
+                .step_by(2)
+                .map(move |i| u8::from_str_radix(&w[i..i + 2], 16).unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn test_function_selector() {
+    assert_eq!(
+        function_selector("transfer(address,uint256)"),
+        [0xa9, 0x05, 0x9c, 0xbb]
+    );
+    assert_eq!(
+        function_selector("balanceOf(address)"),
+        [0x70, 0xa0, 0x82, 0x31]
+    );
+}
+
+#[test]
+fn test_encode_static_and_dynamic_params() {
+    // examples from the Solidity ABI specification
+    let data = encode_function_call(
+        "baz(uint32,bool)",
+        &[AbiToken::uint(69u128), AbiToken::Bool(true)],
+    )
+    .unwrap();
+    assert_eq!(
+        data,
+        hex_words(&[
+            "cdcd77c0",
+            "0000000000000000000000000000000000000000000000000000000000000045",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        ])
+    );
+
+    let tokens = vec![
+        AbiToken::Bytes(b"dave".to_vec()),
+        AbiToken::Bool(true),
+        AbiToken::Array(vec![
+            AbiToken::uint(1u128),
+            AbiToken::uint(2u128),
+            AbiToken::uint(3u128),
+        ]),
+    ];
+    let expected = hex_words(&[
+        "0000000000000000000000000000000000000000000000000000000000000060",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "00000000000000000000000000000000000000000000000000000000000000a0",
+        "0000000000000000000000000000000000000000000000000000000000000004",
+        "6461766500000000000000000000000000000000000000000000000000000000",
+        "0000000000000000000000000000000000000000000000000000000000000003",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "0000000000000000000000000000000000000000000000000000000000000003",
+    ]);
+    assert_eq!(abi_encode(&tokens).unwrap(), expected);
+
+    let types = AbiType::parse_list("bytes,bool,uint256[]").unwrap();
+    assert_eq!(abi_decode(&types, &expected).unwrap(), tokens);
+    assert!(abi_decode(&types, &expected[..expected.len() - 1]).is_err());
+}
+
+#[test]
+fn test_parse_types_and_values() {
+    let ty = AbiType::parse("(address,string,int8)[2]").unwrap();
+    assert_eq!(
+        ty,
+        AbiType::FixedArray(
+            Box::new(AbiType::Tuple(vec![
+                AbiType::Address,
+                AbiType::String,
+                AbiType::Int(8)
+            ])),
+            2
+        )
+    );
+    assert_eq!(ty.to_string(), "(address,string,int8)[2]");
+    assert!(AbiType::parse("uint7").is_err());
+
+    let token = AbiToken::address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").unwrap();
+    assert_eq!(
+        token.into_address().unwrap(),
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+    );
+
+    let encoded = abi_encode(&[AbiToken::Int(Int256::from(-1i128))]).unwrap();
+    assert_eq!(
+        abi_decode(&[AbiType::Int(8)], &encoded).unwrap(),
+        vec![AbiToken::Int(Int256::from(-1i128))]
+    );
+    let encoded = abi_encode(&[AbiToken::Int(Int256::from(-129i128))]).unwrap();
+    assert!(abi_decode(&[AbiType::Int(8)], &encoded).is_err());
+    let encoded = abi_encode(&[AbiToken::uint(256u128)]).unwrap();
+    assert!(abi_decode(&[AbiType::Uint(8)], &encoded).is_err());
+}
+
+#[test]
+fn test_decode_static_call_response() {
+    let response = StaticCallResponse {
+        encoded_data: Binary::from(
+            abi_encode(&[AbiToken::uint(1_000u128), AbiToken::string("SEI")]).unwrap(),
+        )
+        .to_base64(),
+    };
+    let tokens = response
+        .decode(&[AbiType::Uint(256), AbiType::String])
+        .unwrap();
+    assert_eq!(
+        tokens[0].clone().into_uint().unwrap(),
+        Uint256::from(1_000u128)
+    );
+    assert_eq!(tokens[1].clone().into_string().unwrap(), "SEI");
+}
+
+#[test]
+fn test_reject_malformed_values() {
+    // fixed bytes values hold at most 32 bytes
+    assert_eq!(
+        abi_encode(&[AbiToken::FixedBytes(vec![1; 32])]).unwrap(),
+        vec![1; 32]
+    );
+    assert!(abi_encode(&[AbiToken::FixedBytes(vec![1; 33])]).is_err());
+    assert!(encode_function_call("f(bytes32)", &[AbiToken::FixedBytes(vec![1; 33])]).is_err());
+
+    // array lengths are bounded by the data even for zero sized elements
+    let types = AbiType::parse_list("uint256[0][]").unwrap();
+    let data = hex_words(&[
+        "0000000000000000000000000000000000000000000000000000000000000020",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+    ]);
+    assert_eq!(
+        abi_decode(&types, &data).unwrap(),
+        vec![AbiToken::Array(vec![
+            AbiToken::FixedArray(vec![]),
+            AbiToken::FixedArray(vec![]),
+        ])]
+    );
+    let data = hex_words(&[
+        "0000000000000000000000000000000000000000000000000000000000000020",
+        "00000000000000000000000000000000000000000000000000ffffffffffffff",
+    ]);
+    assert!(abi_decode(&types, &data).is_err());
+}
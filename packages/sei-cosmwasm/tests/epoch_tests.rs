@@ -0,0 +1,79 @@
+use cosmwasm_std::Timestamp;
+use sei_cosmwasm::{format_rfc3339, parse_rfc3339, Epoch};
+
+#[test]
+fn test_parse_rfc3339() {
+    assert_eq!(
+        parse_rfc3339("1970-01-01T00:00:00Z").unwrap(),
+        Timestamp::from_seconds(0)
+    );
+    assert_eq!(
+        parse_rfc3339("2022-06-14T18:43:34.963452Z").unwrap(),
+        Timestamp::from_nanos(1_655_232_214_963_452_000)
+    );
+    // Offsets are converted to UTC and extra fraction digits truncated
+    assert_eq!(
+        parse_rfc3339("2022-06-14T20:43:34.9634520001+02:00").unwrap(),
+        parse_rfc3339("2022-06-14T18:43:34.963452Z").unwrap()
+    );
+    assert_eq!(
+        parse_rfc3339("2554-07-21T23:34:33.709551615Z").unwrap(),
+        Timestamp::from_nanos(u64::MAX)
+    );
+    assert_eq!(
+        parse_rfc3339("2024-02-29t23:30:00-00:30").unwrap(),
+        parse_rfc3339("2024-03-01T00:00:00Z").unwrap()
+    );
+
+    for invalid in [
+        "",
+        "2022-06-14",
+        "2022-06-14T18:43:34",
+        "2022-06-14T18:43:34.Z",
+        "2022-06-14T18:43:34+0200",
+        "2023-02-29T00:00:00Z",
+        "2022-13-01T00:00:00Z",
+        "2022-06-14T24:00:00Z",
+        "1969-12-31T23:59:59Z",
+        "2554-07-21T23:34:34Z",
+        "9999-12-31T23:59:59Z",
+    ] {
+        parse_rfc3339(invalid).unwrap_err();
+    }
+}
+
+#[test]
+fn test_format_rfc3339() {
+    let time = Timestamp::from_nanos(1_570_864_850_000_000_052);
+    assert_eq!(format_rfc3339(time), "2019-10-12T07:20:50.000000052Z");
+    assert_eq!(parse_rfc3339(&format_rfc3339(time)).unwrap(), time);
+}
+
+#[test]
+fn test_epoch_times() {
+    let epoch = Epoch {
+        genesis_time: "2022-06-14T18:00:00Z".to_string(),
+        duration: 60_000_000_000,
+        current_epoch: 10,
+        current_epoch_start_time: "2022-06-14T18:10:00Z".to_string(),
+        current_epoch_height: 120,
+    };
+    let genesis = epoch.genesis_timestamp().unwrap();
+    let start = epoch.start_timestamp().unwrap();
+    assert_eq!(start, genesis.plus_minutes(10));
+    assert_eq!(epoch.next_epoch_time().unwrap(), start.plus_minutes(1));
+
+    assert_eq!(epoch.epoch_at(start).unwrap(), 10);
+    assert_eq!(epoch.epoch_at(start.plus_seconds(59)).unwrap(), 10);
+    assert_eq!(epoch.epoch_at(start.plus_seconds(60)).unwrap(), 11);
+    assert_eq!(epoch.epoch_at(start.plus_minutes(5)).unwrap(), 15);
+    epoch.epoch_at(start.minus_seconds(1)).unwrap_err();
+
+    // Epochs without a start time, like the mock genesis epoch
+    let epoch = Epoch {
+        current_epoch_start_time: String::new(),
+        ..epoch
+    };
+    epoch.next_epoch_time().unwrap_err();
+    epoch.epoch_at(start).unwrap_err();
+}
@@ -0,0 +1,185 @@
+use cosmwasm_std::{Decimal, StdError, Uint128, Uint256};
+use sei_cosmwasm::{abi_encode, decode_revert_reason, AbiToken, SeiError};
+
+#[test]
+fn test_decode_revert_reason() {
+    let mut data = vec![0x08, 0xc3, 0x79, 0xa0];
+    data.extend(
+        abi_encode(&[AbiToken::String(
+            "ERC20: insufficient allowance".to_string(),
+        )])
+        .unwrap(),
+    );
+    assert_eq!(decode_revert_reason(&data), "ERC20: insufficient allowance");
+    assert_eq!(
+        SeiError::evm_revert(&data),
+        SeiError::EvmRevert {
+            reason: "ERC20: insufficient allowance".to_string()
+        }
+    );
+
+    let mut data = vec![0x4e, 0x48, 0x7b, 0x71];
+    data.extend(abi_encode(&[AbiToken::Uint(Uint256::from(0x11u8))]).unwrap());
+    assert_eq!(decode_revert_reason(&data), "panic code 0x11");
+
+    // custom errors are returned hex encoded
+    assert_eq!(
+        decode_revert_reason(&[0xde, 0xad, 0xbe, 0xef]),
+        "0xdeadbeef"
+    );
+    assert_eq!(decode_revert_reason(&[]), "0x");
+}
+
+#[test]
+fn test_evm_query_errors() {
+    let mut data = vec![0x08, 0xc3, 0x79, 0xa0];
+    data.extend(abi_encode(&[AbiToken::string("ERC721: invalid token ID")]).unwrap());
+    let hex: String = data.iter().map(|b| format!("{:02x}", b)).collect();
+    let query_err = |msg: String| StdError::generic_err(format!("Querier contract error: {}", msg));
+    let revert = |reason: &str| SeiError::EvmRevert {
+        reason: reason.to_string(),
+    };
+
+    // revert data is decoded, reasons reported as text are kept
+    assert_eq!(
+        SeiError::from_evm_query(query_err(format!("execution reverted: 0x{}", hex))),
+        revert("ERC721: invalid token ID")
+    );
+    assert_eq!(
+        SeiError::from_evm_query(query_err(
+            "execution reverted: ERC20: insufficient allowance".to_string()
+        )),
+        revert("ERC20: insufficient allowance")
+    );
+    assert_eq!(
+        SeiError::from_evm_query(query_err("execution reverted: 0xdeadbeef".to_string())),
+        revert("0xdeadbeef")
+    );
+    assert_eq!(
+        SeiError::from_evm_query(query_err("execution reverted".to_string())),
+        revert("0x")
+    );
+
+    let err = || query_err("out of gas".to_string());
+    assert_eq!(SeiError::from_evm_query(err()), SeiError::Std(err()));
+}
+
+fn module_errors() -> Vec<SeiError> {
+    vec![
+        SeiError::ExchangeRateNotFound {
+            denom: "usei".to_string(),
+        },
+        SeiError::StaleExchangeRate {
+            denom: "usei".to_string(),
+            last_update_timestamp: 1_571_797_419_879,
+            max_age_seconds: 60,
+        },
+        SeiError::OrdersNotFound {
+            contract_address: "contract0".to_string(),
+        },
+        SeiError::OrderNotFound { id: 7 },
+        SeiError::OrderNotPlaced { id: 7 },
+        SeiError::NotOrderOwner {
+            id: 7,
+            sender: "sei1hjfwcza3e3uzeznf3qthhakdr9juetl7g6esl4".to_string(),
+        },
+        SeiError::PairNotRegistered {
+            contract_address: "contract0".to_string(),
+            price_denom: "USDC".to_string(),
+            asset_denom: "ATOM".to_string(),
+        },
+        SeiError::PriceNotFound {
+            contract_address: "contract0".to_string(),
+            price_denom: "USDC".to_string(),
+            asset_denom: "ATOM".to_string(),
+        },
+        SeiError::ContractNotRegistered {
+            contract_address: "contract0".to_string(),
+        },
+        SeiError::NotContractCreator {
+            contract_address: "contract0".to_string(),
+            sender: "alice".to_string(),
+        },
+        SeiError::InvalidTickSize {
+            field: "quantity".to_string(),
+            value: Decimal::percent(150),
+            tick_size: Decimal::one(),
+        },
+        SeiError::InvalidOrder {
+            reason: "stop orders need a trigger price".to_string(),
+        },
+        SeiError::InsufficientFunds {
+            denom: "USDC".to_string(),
+            required: Uint128::new(1_500),
+            provided: Uint128::new(1_000),
+        },
+        SeiError::InvalidEpochTimestamp {
+            timestamp: "yesterday".to_string(),
+        },
+        SeiError::DenomAlreadyExists {
+            denom: "factory/admin/test".to_string(),
+        },
+        SeiError::DenomNotFound {
+            denom: "factory/admin/test".to_string(),
+        },
+        SeiError::NotDenomAdmin {
+            action: "mint".to_string(),
+        },
+        SeiError::InvalidMetadata {
+            reason: "name field cannot be blank".to_string(),
+        },
+        SeiError::EvmRevert {
+            reason: "ERC721: invalid token ID".to_string(),
+        },
+        SeiError::AddressNotAssociated {
+            address: "sei1hjfwcza3e3uzeznf3qthhakdr9juetl7g6esl4".to_string(),
+        },
+        SeiError::AddressAlreadyAssociated {
+            address: "sei1hjfwcza3e3uzeznf3qthhakdr9juetl7g6esl4".to_string(),
+            associated_address: "0xAb5801a7D398351b8bE11C439e05C5B3259aeC9B".to_string(),
+        },
+    ]
+}
+
+#[test]
+fn test_parse_module_errors() {
+    for (err, expected) in module_errors().into_iter().zip(module_errors()) {
+        assert_eq!(SeiError::parse(&err.to_string()), Some(expected));
+
+        // module errors reach contracts wrapped by the querier
+        let std_err = StdError::generic_err(format!("Querier contract error: {}", err));
+        assert_eq!(SeiError::from(std_err), err);
+    }
+
+    // and again when a contract forwards the error of its query
+    assert_eq!(
+        SeiError::parse("Querier contract error: Generic error: dex: order 1 not found"),
+        Some(SeiError::OrderNotFound { id: 1 })
+    );
+
+    // Module prefixes are only recognized at the start of the message
+    assert_eq!(SeiError::parse("contract: dex: order 1 not found"), None);
+    assert_eq!(
+        SeiError::parse("failed: tokenfactory: must be owner of coin factory denom to mint"),
+        None
+    );
+    assert_eq!(
+        SeiError::parse("call failed: execution reverted: nope"),
+        None
+    );
+}
+
+#[test]
+fn test_std_error_conversion() {
+    let err = || StdError::generic_err("something else failed");
+    assert_eq!(SeiError::from(err()), SeiError::Std(err()));
+    assert_eq!(StdError::from(SeiError::Std(err())), err());
+
+    let err = || StdError::not_found("state");
+    assert_eq!(SeiError::from(err()), SeiError::Std(err()));
+
+    assert_eq!(
+        StdError::from(SeiError::OrderNotFound { id: 1 }),
+        StdError::generic_err("dex: order 1 not found")
+    );
+}
@@ -0,0 +1,152 @@
+use std::marker::PhantomData;
+
+use cosmwasm_std::testing::{mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{from_json, Decimal, DepsMut, Env, OwnedDeps, Response, StdError};
+use sei_cosmwasm::{
+    BulkOrderPlacementsResponse, DepositInfo, DexHooks, OrderBuilder, OrderPlacements,
+    SeiQueryWrapper, SudoMsg, TypedOrder, UnsuccessfulOrder,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct OrderData {
+    leverage: Decimal,
+}
+
+/// Rejects the orders with a leverage above 10
+struct Hooks;
+
+impl DexHooks for Hooks {
+    type OrderData = OrderData;
+    type Error = StdError;
+
+    fn order_placements(
+        &self,
+        _deps: DepsMut<SeiQueryWrapper>,
+        _env: Env,
+        orders: Vec<TypedOrder<OrderData>>,
+        deposits: Vec<DepositInfo>,
+    ) -> Result<OrderPlacements, StdError> {
+        let unsuccessful_orders = orders
+            .iter()
+            .filter(|o| o.data.leverage > Decimal::from_ratio(10u64, 1u64))
+            .map(|o| UnsuccessfulOrder {
+                id: o.id,
+                reason: "leverage too high".to_string(),
+            })
+            .collect();
+        Ok(OrderPlacements {
+            response: Response::new()
+                .add_attribute("deposits", deposits.len().to_string())
+                .add_attribute("orders", orders.len().to_string()),
+            unsuccessful_orders,
+        })
+    }
+}
+
+fn mock_deps() -> OwnedDeps<MockStorage, MockApi, MockQuerier<SeiQueryWrapper>, SeiQueryWrapper> {
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::new(&[]),
+        custom_query_type: PhantomData,
+    }
+}
+
+fn order(leverage: u64) -> sei_cosmwasm::Order {
+    OrderBuilder::limit("USDC", "ATOM", Decimal::one(), Decimal::one())
+        .data(&OrderData {
+            leverage: Decimal::from_ratio(leverage, 1u64),
+        })
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_typed_order() {
+    let typed = TypedOrder::<OrderData>::parse(3, order(2)).unwrap();
+    assert_eq!(typed.id, 3);
+    assert_eq!(typed.data.leverage, Decimal::from_ratio(2u64, 1u64));
+
+    let no_data = OrderBuilder::limit("USDC", "ATOM", Decimal::one(), Decimal::one())
+        .build()
+        .unwrap();
+    let typed = TypedOrder::<Option<OrderData>>::parse(0, no_data.clone()).unwrap();
+    assert_eq!(typed.data, None);
+    TypedOrder::<OrderData>::parse(0, no_data).unwrap_err();
+}
+
+#[test]
+fn test_dex_hooks_sudo() {
+    let mut deps = mock_deps();
+    let res = Hooks
+        .sudo(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::BulkOrderPlacements {
+                orders: vec![order(2), order(20)],
+                deposits: vec![],
+            },
+        )
+        .unwrap();
+    assert_eq!(res.attributes[0].value, "0");
+    let data: BulkOrderPlacementsResponse = from_json(res.data.unwrap()).unwrap();
+    assert_eq!(
+        data.unsuccessful_orders,
+        vec![UnsuccessfulOrder {
+            id: 1,
+            reason: "leverage too high".to_string(),
+        }]
+    );
+
+    // Orders with data of another type are rejected, the others still reach the hook
+    let bad = OrderBuilder::limit("USDC", "ATOM", Decimal::one(), Decimal::one())
+        .raw_data("not json")
+        .build()
+        .unwrap();
+    let res = Hooks
+        .sudo(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::BulkOrderPlacements {
+                orders: vec![order(2), bad, order(20), order(3)],
+                deposits: vec![],
+            },
+        )
+        .unwrap();
+    assert_eq!(res.attributes[1].value, "3");
+    let data: BulkOrderPlacementsResponse = from_json(res.data.unwrap()).unwrap();
+    assert_eq!(data.unsuccessful_orders.len(), 2);
+    assert_eq!(data.unsuccessful_orders[0].id, 1);
+    assert!(data.unsuccessful_orders[0]
+        .reason
+        .starts_with("invalid order data: "));
+    assert_eq!(
+        data.unsuccessful_orders[1],
+        UnsuccessfulOrder {
+            id: 2,
+            reason: "leverage too high".to_string(),
+        }
+    );
+
+    // The default hooks do nothing
+    let res = Hooks
+        .sudo(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::BulkOrderCancellations { ids: vec![1] },
+        )
+        .unwrap();
+    assert_eq!(res, Response::new());
+    let res = Hooks
+        .sudo(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::Settlement {
+                epoch: 1,
+                entries: vec![],
+            },
+        )
+        .unwrap();
+    assert_eq!(res, Response::new());
+}
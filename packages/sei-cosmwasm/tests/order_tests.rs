@@ -0,0 +1,206 @@
+use cosmwasm_std::{coin, from_json, to_json_string, Addr, Decimal, Uint128};
+use sei_cosmwasm::{
+    required_funds, DexPair, Order, OrderBuilder, OrderType, PositionDirection, SeiError, SeiMsg,
+};
+use serde::Serialize;
+
+fn pair() -> DexPair {
+    DexPair {
+        price_denom: "USDC".to_string(),
+        asset_denom: "ATOM".to_string(),
+        price_tick_size: Decimal::percent(10),
+        quantity_tick_size: Decimal::one(),
+    }
+}
+
+fn limit(price: Decimal, quantity: Decimal) -> OrderBuilder {
+    OrderBuilder::limit("USDC", "ATOM", price, quantity)
+}
+
+#[derive(Serialize)]
+struct OrderData {
+    leverage: Decimal,
+}
+
+#[test]
+fn test_order_builder() {
+    let order = limit(
+        Decimal::percent(1050),
+        Decimal::from_atomics(2u128, 0).unwrap(),
+    )
+    .data(&OrderData {
+        leverage: Decimal::one(),
+    })
+    .build()
+    .unwrap();
+    assert_eq!(
+        order,
+        Order {
+            price: Decimal::percent(1050),
+            quantity: Decimal::from_atomics(2u128, 0).unwrap(),
+            price_denom: "USDC".to_string(),
+            asset_denom: "ATOM".to_string(),
+            order_type: OrderType::Limit,
+            position_direction: PositionDirection::Long,
+            data: r#"{"leverage":"1"}"#.to_string(),
+            status_description: String::new(),
+            nominal: Decimal::zero(),
+            trigger_price: None,
+        }
+    );
+    // Orders without a trigger price serialize as before stop orders were supported
+    let json = to_json_string(&order).unwrap();
+    assert!(!json.contains("trigger_price"));
+    assert_eq!(from_json::<Order>(&json).unwrap(), order);
+
+    let order = OrderBuilder::market("USDC", "ATOM", Decimal::one(), Decimal::one())
+        .short()
+        .raw_data("raw")
+        .build()
+        .unwrap();
+    assert_eq!(order.order_type, OrderType::Market);
+    assert_eq!(order.position_direction, PositionDirection::Short);
+    assert_eq!(order.data, "raw");
+}
+
+#[test]
+fn test_required_funds() {
+    let orders = vec![
+        limit(
+            Decimal::percent(1050),
+            Decimal::from_atomics(2u128, 0).unwrap(),
+        )
+        .build()
+        .unwrap(),
+        limit(Decimal::percent(15), Decimal::one()).build().unwrap(),
+        limit(
+            Decimal::percent(1100),
+            Decimal::from_atomics(3u128, 0).unwrap(),
+        )
+        .short()
+        .build()
+        .unwrap(),
+    ];
+    // 21 + 0.15 rounded up
+    assert_eq!(
+        required_funds(&orders),
+        vec![coin(3, "ATOM"), coin(22, "USDC")]
+    );
+}
+
+#[test]
+fn test_place_orders_validation() {
+    let contract = Addr::unchecked("contract");
+    let place =
+        |order: Order, funds| SeiMsg::place_orders(vec![order], funds, contract.clone(), &[pair()]);
+
+    let order = limit(Decimal::percent(1050), Decimal::one())
+        .build()
+        .unwrap();
+    assert_eq!(
+        place(order.clone(), vec![coin(11, "USDC")]).unwrap(),
+        SeiMsg::PlaceOrders {
+            orders: vec![order.clone()],
+            funds: vec![coin(11, "USDC")],
+            contract_address: contract.clone(),
+        }
+    );
+    assert_eq!(
+        place(order, vec![coin(10, "USDC"), coin(100, "ATOM")]).unwrap_err(),
+        SeiError::InsufficientFunds {
+            denom: "USDC".to_string(),
+            required: Uint128::new(11),
+            provided: Uint128::new(10),
+        }
+    );
+
+    let off_tick = limit(Decimal::percent(1055), Decimal::one())
+        .build()
+        .unwrap();
+    assert_eq!(
+        place(off_tick, vec![coin(11, "USDC")]).unwrap_err(),
+        SeiError::InvalidTickSize {
+            field: "price".to_string(),
+            value: Decimal::percent(1055),
+            tick_size: Decimal::percent(10),
+        }
+    );
+
+    let other_pair = OrderBuilder::limit("USDC", "BTC", Decimal::one(), Decimal::one())
+        .build()
+        .unwrap();
+    assert_eq!(
+        place(other_pair, vec![coin(1, "USDC")]).unwrap_err(),
+        SeiError::PairNotRegistered {
+            contract_address: "contract".to_string(),
+            price_denom: "USDC".to_string(),
+            asset_denom: "BTC".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_order_types() {
+    let order = OrderBuilder::stop_limit(
+        "USDC",
+        "ATOM",
+        Decimal::percent(1200),
+        Decimal::percent(1210),
+        Decimal::one(),
+    )
+    .short()
+    .build()
+    .unwrap();
+    assert_eq!(order.order_type, OrderType::StopLimit);
+    assert_eq!(order.trigger_price, Some(Decimal::percent(1200)));
+    assert!(order.order_type.is_triggered() && !order.order_type.is_market());
+    assert_eq!(
+        pair().check_ticks(&Order {
+            trigger_price: Some(Decimal::percent(1205)),
+            ..order
+        }),
+        Err(SeiError::InvalidTickSize {
+            field: "trigger_price".to_string(),
+            value: Decimal::percent(1205),
+            tick_size: Decimal::percent(10),
+        })
+    );
+
+    let by_value =
+        OrderBuilder::fok_market_by_value("USDC", "ATOM", Decimal::zero(), Decimal::percent(2550))
+            .build()
+            .unwrap();
+    assert!(by_value.order_type.is_fill_or_kill());
+    assert_eq!(required_funds(&[by_value]), vec![coin(26, "USDC")]);
+
+    assert_eq!(
+        OrderBuilder::stop_loss(
+            "USDC",
+            "ATOM",
+            Decimal::zero(),
+            Decimal::one(),
+            Decimal::one()
+        )
+        .build()
+        .unwrap_err(),
+        SeiError::InvalidOrder {
+            reason: "stop orders need a trigger price".to_string()
+        }
+    );
+    assert_eq!(
+        OrderBuilder::fok_market_by_value("USDC", "ATOM", Decimal::one(), Decimal::zero())
+            .build()
+            .unwrap_err(),
+        SeiError::InvalidOrder {
+            reason: "fill-or-kill by value orders need a nominal".to_string()
+        }
+    );
+
+    // chain order type numbers
+    assert_eq!(
+        cosmwasm_std::to_json_string(&OrderType::FokMarketByValue).unwrap(),
+        "4"
+    );
+    let order_type: OrderType = cosmwasm_std::from_json("6").unwrap();
+    assert_eq!(order_type, OrderType::StopLimit);
+}
@@ -0,0 +1,172 @@
+use cosmwasm_std::testing::MockStorage;
+use cosmwasm_std::{Decimal, SignedDecimal};
+use sei_cosmwasm::{OrderType, Position, PositionBook, PositionDirection, SettlementEntry};
+
+const POSITIONS: PositionBook = PositionBook::new("positions");
+
+fn dec(value: u64) -> Decimal {
+    Decimal::from_ratio(value, 1u64)
+}
+
+fn signed(value: i64) -> SignedDecimal {
+    SignedDecimal::from_ratio(value, 1)
+}
+
+fn fill(
+    account: &str,
+    position_direction: PositionDirection,
+    quantity: u64,
+    price: u64,
+    expected: u64,
+) -> SettlementEntry {
+    SettlementEntry {
+        account: account.to_string(),
+        price_denom: "USDC".to_string(),
+        asset_denom: "ATOM".to_string(),
+        quantity: dec(quantity),
+        execution_cost_or_proceed: dec(price),
+        expected_cost_or_proceed: dec(expected),
+        position_direction,
+        order_type: OrderType::Limit,
+        order_id: 0,
+        timestamp: 0,
+        height: 0,
+        settlement_id: 0,
+    }
+}
+
+#[test]
+fn test_position_accounting() {
+    let mut position = Position::new("alice", "USDC", "ATOM");
+    assert!(position.is_flat());
+
+    // Long 2 at 10 and 2 at 14, averaging 12
+    position
+        .apply(&fill("alice", PositionDirection::Long, 2, 10, 11))
+        .unwrap();
+    position
+        .apply(&fill("alice", PositionDirection::Long, 2, 14, 14))
+        .unwrap();
+    assert_eq!(position.quantity, signed(4));
+    assert_eq!(position.entry_price, dec(12));
+    assert_eq!(position.deposit_surplus, signed(2));
+    assert_eq!(position.unrealized_pnl(dec(15)).unwrap(), signed(12));
+
+    // Selling 1 at 15 realizes 3
+    position
+        .apply(&fill("alice", PositionDirection::Short, 1, 15, 13))
+        .unwrap();
+    assert_eq!(position.quantity, signed(3));
+    assert_eq!(position.entry_price, dec(12));
+    assert_eq!(position.realized_pnl, signed(3));
+    assert_eq!(position.deposit_surplus, signed(4));
+
+    // Selling 5 at 10 closes the long at a loss of 6 and opens a short of 2 at 10
+    position
+        .apply(&fill("alice", PositionDirection::Short, 5, 10, 10))
+        .unwrap();
+    assert_eq!(position.quantity, signed(-2));
+    assert_eq!(position.entry_price, dec(10));
+    assert_eq!(position.realized_pnl, signed(-3));
+    assert_eq!(position.unrealized_pnl(dec(8)).unwrap(), signed(4));
+
+    // Buying back 2 at 8 closes the short with a profit of 4
+    position
+        .apply(&fill("alice", PositionDirection::Long, 2, 8, 9))
+        .unwrap();
+    assert!(position.is_flat());
+    assert_eq!(position.entry_price, Decimal::zero());
+    assert_eq!(position.realized_pnl, signed(1));
+    assert_eq!(position.deposit_surplus, signed(6));
+}
+
+#[test]
+fn test_position_book() {
+    let mut storage = MockStorage::new();
+    let mut other_pair = fill("alice", PositionDirection::Short, 3, 5, 5);
+    other_pair.price_denom = "DAI".to_string();
+    POSITIONS
+        .apply_settlement(
+            &mut storage,
+            &[
+                fill("alice", PositionDirection::Long, 2, 10, 10),
+                fill("bob", PositionDirection::Short, 2, 10, 10),
+                other_pair,
+                fill("alice", PositionDirection::Short, 1, 12, 12),
+            ],
+        )
+        .unwrap();
+
+    let position = POSITIONS
+        .position(&storage, "alice", "USDC", "ATOM")
+        .unwrap();
+    assert_eq!(position.quantity, signed(1));
+    assert_eq!(position.realized_pnl, signed(2));
+
+    let position = POSITIONS.position(&storage, "bob", "USDC", "ATOM").unwrap();
+    assert_eq!(position.quantity, signed(-2));
+    assert_eq!(position.entry_price, dec(10));
+
+    let res = POSITIONS.positions(&storage, "alice").unwrap();
+    let pairs: Vec<(&str, SignedDecimal)> = res
+        .positions
+        .iter()
+        .map(|p| (p.price_denom.as_str(), p.quantity))
+        .collect();
+    assert_eq!(pairs, vec![("DAI", signed(-3)), ("USDC", signed(1))]);
+
+    // Accounts without fills are flat
+    let position = POSITIONS
+        .position(&storage, "charlie", "USDC", "ATOM")
+        .unwrap();
+    assert_eq!(position, Position::new("charlie", "USDC", "ATOM"));
+    assert!(POSITIONS
+        .positions(&storage, "charlie")
+        .unwrap()
+        .positions
+        .is_empty());
+}
+
+#[test]
+fn test_position_edge_fills() {
+    // Zero fills leave a flat position flat
+    let mut position = Position::new("alice", "USDC", "ATOM");
+    position
+        .apply(&fill("alice", PositionDirection::Long, 0, 10, 11))
+        .unwrap();
+    assert_eq!(position, Position::new("alice", "USDC", "ATOM"));
+
+    // Flipping a short of 3 at 10 into a long of 1 at 7
+    position
+        .apply(&fill("alice", PositionDirection::Short, 3, 10, 10))
+        .unwrap();
+    position
+        .apply(&fill("alice", PositionDirection::Long, 4, 7, 7))
+        .unwrap();
+    assert_eq!(position.quantity, signed(1));
+    assert_eq!(position.entry_price, dec(7));
+    assert_eq!(position.realized_pnl, signed(9));
+
+    // Zero fills against an open position change nothing either
+    let open = position.clone();
+    position
+        .apply(&fill("alice", PositionDirection::Short, 0, 20, 5))
+        .unwrap();
+    assert_eq!(position, open);
+
+    // Closing exactly resets the entry price
+    position
+        .apply(&fill("alice", PositionDirection::Short, 1, 8, 8))
+        .unwrap();
+    assert!(position.is_flat());
+    assert_eq!(position.entry_price, Decimal::zero());
+    assert_eq!(position.realized_pnl, signed(10));
+
+    // Fills whose cost overflows fail without changing the position
+    let flat = position.clone();
+    let huge = 100_000_000_000;
+    position
+        .apply(&fill("alice", PositionDirection::Long, huge, huge, 1))
+        .unwrap_err();
+    assert_eq!(position, flat);
+}
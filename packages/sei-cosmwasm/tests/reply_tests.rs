@@ -0,0 +1,130 @@
+use cosmwasm_std::{Binary, Reply, SubMsgResponse, SubMsgResult};
+use sei_cosmwasm::{
+    abi_encode, parse_reply_call_evm, parse_reply_cancel_orders, parse_reply_create_denom,
+    parse_reply_mint_tokens, parse_reply_place_orders, AbiToken, AbiType, MsgCancelOrdersResponse,
+    MsgCreateDenomResponse, MsgInternalEVMCallResponse, MsgMintResponse, MsgPlaceOrdersResponse,
+    MsgResponse, SeiError,
+};
+
+fn reply(data: Option<Vec<u8>>) -> Reply {
+    Reply {
+        id: 1,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: data.map(Binary::from),
+        }),
+    }
+}
+
+// Length delimited field 1
+fn bytes_field(value: &[u8]) -> Vec<u8> {
+    let mut data = vec![0x0a, value.len() as u8];
+    data.extend_from_slice(value);
+    data
+}
+
+#[test]
+fn test_parse_reply_place_orders() {
+    // packed repeated uint64 order_ids = 1
+    let res = parse_reply_place_orders(reply(Some(vec![0x0a, 0x03, 0x01, 0x96, 0x01]))).unwrap();
+    assert_eq!(res.order_ids, vec![1, 150]);
+
+    let res = parse_reply_place_orders(reply(None)).unwrap();
+    assert!(res.order_ids.is_empty());
+}
+
+#[test]
+fn test_parse_reply_create_denom() {
+    let res = parse_reply_create_denom(reply(Some(bytes_field(b"factory/sei1creator/test"))));
+    assert_eq!(
+        res.unwrap(),
+        MsgCreateDenomResponse {
+            new_token_denom: "factory/sei1creator/test".to_string()
+        }
+    );
+
+    // unknown fields are skipped
+    let mut data = vec![0x10, 0x2a];
+    data.extend(bytes_field(b"factory/sei1creator/test"));
+    assert_eq!(
+        parse_reply_create_denom(reply(Some(data)))
+            .unwrap()
+            .new_token_denom,
+        "factory/sei1creator/test"
+    );
+
+    // truncated data
+    assert!(matches!(
+        parse_reply_create_denom(reply(Some(vec![0x0a, 0x10, b'f']))).unwrap_err(),
+        SeiError::Std(_)
+    ));
+}
+
+#[test]
+fn test_parse_reply_empty_responses() {
+    assert_eq!(
+        parse_reply_cancel_orders(reply(None)).unwrap(),
+        MsgCancelOrdersResponse {}
+    );
+    assert_eq!(
+        parse_reply_mint_tokens(reply(Some(vec![]))).unwrap(),
+        MsgMintResponse {}
+    );
+}
+
+#[test]
+fn test_parse_reply_call_evm() {
+    let output = abi_encode(&[AbiToken::Bool(true)]).unwrap();
+    let res = parse_reply_call_evm(reply(Some(bytes_field(&output)))).unwrap();
+    assert_eq!(res.data, output);
+    assert_eq!(
+        res.decode_output(&[AbiType::Bool]).unwrap(),
+        vec![AbiToken::Bool(true)]
+    );
+}
+
+#[test]
+fn test_parse_reply_error() {
+    let reply = Reply {
+        id: 1,
+        result: SubMsgResult::Err("dex: order 7 not found".to_string()),
+    };
+    assert_eq!(
+        parse_reply_cancel_orders(reply).unwrap_err(),
+        SeiError::OrderNotFound { id: 7 }
+    );
+}
+
+#[test]
+fn test_msg_response_encoding() {
+    let res = MsgPlaceOrdersResponse {
+        order_ids: vec![0, 1, 300, u64::MAX],
+    };
+    assert_eq!(MsgPlaceOrdersResponse::decode(&res.encode()).unwrap(), res);
+    // unpacked repeated fields are accepted as well
+    assert_eq!(
+        MsgPlaceOrdersResponse::decode(&[0x08, 0x01, 0x08, 0xac, 0x02])
+            .unwrap()
+            .order_ids,
+        vec![1, 300]
+    );
+
+    let res = MsgCreateDenomResponse {
+        new_token_denom: "factory/sei1creator/test".to_string(),
+    };
+    assert_eq!(MsgCreateDenomResponse::decode(&res.encode()).unwrap(), res);
+
+    let res = MsgInternalEVMCallResponse { data: vec![] };
+    assert!(res.encode().is_empty());
+    assert_eq!(MsgMintResponse {}.encode(), Vec::<u8>::new());
+
+    // fixed width fields of newer chain versions are skipped
+    assert_eq!(
+        MsgMintResponse::decode(&[0x09, 1, 2, 3, 4, 5, 6, 7, 8, 0x15, 1, 2, 3, 4]).unwrap(),
+        MsgMintResponse {}
+    );
+    assert!(MsgMintResponse::decode(&[0x09, 1, 2]).is_err());
+    let mut overflow = vec![0x08];
+    overflow.extend([0xff; 10]);
+    assert!(MsgPlaceOrdersResponse::decode(&overflow).is_err());
+}
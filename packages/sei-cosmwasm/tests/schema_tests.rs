@@ -0,0 +1,33 @@
+use cosmwasm_schema::{schema_for, QueryResponses};
+use sei_cosmwasm::{ErcPayloadResponse, ExchangeRatesResponse, SeiAddressResponse, SeiQuery};
+
+#[test]
+fn test_query_responses() {
+    let responses = SeiQuery::response_schemas().unwrap();
+    assert_eq!(
+        responses["exchange_rates"],
+        schema_for!(ExchangeRatesResponse)
+    );
+    assert_eq!(
+        responses["erc1155_batch_transfer_payload"],
+        schema_for!(ErcPayloadResponse)
+    );
+    assert_eq!(
+        responses["erc20_balance"],
+        schema_for!(cw20::BalanceResponse)
+    );
+    assert_eq!(
+        responses["get_sei_address"],
+        schema_for!(SeiAddressResponse)
+    );
+
+    // Every variant of the query schema has a response
+    let query = schema_for!(SeiQuery).schema;
+    let variants = query.subschemas.unwrap().one_of.unwrap();
+    assert_eq!(variants.len(), responses.len());
+    for variant in variants {
+        let variant = variant.into_object();
+        let name = variant.object.unwrap().required.into_iter().next().unwrap();
+        assert!(responses.contains_key(&name), "no response for {}", name);
+    }
+}
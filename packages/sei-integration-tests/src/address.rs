@@ -0,0 +1,126 @@
+use anyhow::{bail, Result as AnyResult};
+use bech32::{ToBase32, Variant};
+use cosmwasm_std::Storage;
+use k256::{elliptic_curve::sec1::ToEncodedPoint, PublicKey};
+use ripemd::Ripemd160;
+use sei_cosmwasm::{keccak256, parse_evm_address, to_checksum_address, SeiError};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+const SEI_BECH32_PREFIX: &str = "sei";
+
+/// Sei <-> EVM address associations known to the mock chain.
+///
+/// Associations given when building the `SeiModule` live here, associations made during a test
+/// (e.g. through `SeiModule::associate_address`) are kept in the app storage.
+#[derive(Clone, Debug, Default)]
+pub struct AddressAssociations {
+    sei_to_evm: HashMap<String, String>,
+    evm_to_sei: HashMap<String, String>,
+}
+
+impl AddressAssociations {
+    /// Associates `sei_address` with `evm_address`, replacing the previous associations of both
+    pub fn insert(&mut self, sei_address: String, evm_address: String) {
+        if let Some(previous) = self.sei_to_evm.remove(&sei_address) {
+            self.evm_to_sei.remove(&previous.to_lowercase());
+        }
+        if let Some(previous) = self.evm_to_sei.remove(&evm_address.to_lowercase()) {
+            self.sei_to_evm.remove(&previous);
+        }
+        self.evm_to_sei
+            .insert(evm_address.to_lowercase(), sei_address.clone());
+        self.sei_to_evm.insert(sei_address, evm_address);
+    }
+
+    pub fn evm_address(&self, storage: &dyn Storage, sei_address: &str) -> Option<String> {
+        match storage.get(evm_association_key(sei_address).as_bytes()) {
+            Some(evm_address) => String::from_utf8(evm_address).ok(),
+            None => self.sei_to_evm.get(sei_address).cloned(),
+        }
+    }
+
+    pub fn sei_address(&self, storage: &dyn Storage, evm_address: &str) -> Option<String> {
+        let evm_address = evm_address.to_lowercase();
+        match storage.get(sei_association_key(&evm_address).as_bytes()) {
+            Some(sei_address) => String::from_utf8(sei_address).ok(),
+            None => self.evm_to_sei.get(&evm_address).cloned(),
+        }
+    }
+}
+
+// Storage:
+// "EvmAddressAssociation-" + Sei address -> EVM address
+// "SeiAddressAssociation-" + lowercase EVM address -> Sei address
+
+// Like the chain, an address can only be associated once: associating either address with
+// another one fails with `SeiError::AddressAlreadyAssociated`, repeating an association is a
+// no-op.
+pub(crate) fn associate_address(
+    storage: &mut dyn Storage,
+    associations: &AddressAssociations,
+    sei_address: &str,
+    evm_address: &str,
+) -> AnyResult<()> {
+    parse_evm_address(evm_address)?;
+    let associated_evm = associations.evm_address(storage, sei_address);
+    let associated_sei = associations.sei_address(storage, evm_address);
+    if let Some(associated) = &associated_evm {
+        if !associated.eq_ignore_ascii_case(evm_address) {
+            bail!(SeiError::AddressAlreadyAssociated {
+                address: sei_address.to_string(),
+                associated_address: associated.clone(),
+            });
+        }
+    }
+    if let Some(associated) = associated_sei {
+        if associated != sei_address {
+            bail!(SeiError::AddressAlreadyAssociated {
+                address: evm_address.to_string(),
+                associated_address: associated,
+            });
+        }
+    }
+    if associated_evm.is_some() {
+        return Ok(());
+    }
+    storage.set(
+        evm_association_key(sei_address).as_bytes(),
+        evm_address.as_bytes(),
+    );
+    storage.set(
+        sei_association_key(&evm_address.to_lowercase()).as_bytes(),
+        sei_address.as_bytes(),
+    );
+    Ok(())
+}
+
+/// Derives the Sei and EVM addresses of a secp256k1 public key the way the chain does: the Sei
+/// address is the bech32 encoded RIPEMD160(SHA256(compressed key)), the EVM address the last 20
+/// bytes of the keccak256 hash of the uncompressed key. Compressed (33 bytes) and uncompressed
+/// (65 bytes) keys are accepted.
+pub fn addresses_from_pubkey(pubkey: &[u8]) -> AnyResult<(String, String)> {
+    let pubkey = match PublicKey::from_sec1_bytes(pubkey) {
+        Ok(pubkey) => pubkey,
+        Err(_) => bail!("invalid secp256k1 public key"),
+    };
+
+    let compressed = pubkey.to_encoded_point(true);
+    let hash = Ripemd160::digest(Sha256::digest(compressed.as_bytes()));
+    let sei_address = bech32::encode(SEI_BECH32_PREFIX, hash.to_base32(), Variant::Bech32)?;
+
+    let uncompressed = pubkey.to_encoded_point(false);
+    let hash = keccak256(&uncompressed.as_bytes()[1..]);
+    let mut evm_address = [0u8; 20];
+    evm_address.copy_from_slice(&hash[12..]);
+
+    Ok((sei_address, to_checksum_address(&evm_address)))
+}
+
+fn evm_association_key(sei_address: &str) -> String {
+    format!("EvmAddressAssociation-{}", sei_address)
+}
+
+fn sei_association_key(evm_address: &str) -> String {
+    format!("SeiAddressAssociation-{}", evm_address)
+}
@@ -0,0 +1,144 @@
+use anyhow::{bail, Result as AnyResult};
+use cosmwasm_std::{
+    to_json_binary, Addr, AllDenomMetadataResponse, Api, BankMsg, BankQuery, Binary, BlockInfo,
+    Coin, CustomQuery, DenomMetadata, DenomMetadataResponse, DenomUnit, Querier, Storage,
+};
+use cw_multi_test::{AppResponse, Bank, BankKeeper, BankSudo, CosmosRouter, Module};
+use schemars::JsonSchema;
+use sei_cosmwasm::Metadata;
+use serde::de::DeserializeOwned;
+use std::fmt::Debug;
+
+use crate::tokenfactory::{all_denom_metadata, denom_metadata};
+
+/// Bank module of the mock chain: cw-multi-test's `BankKeeper` extended with the
+/// `DenomMetadata` and `AllDenomMetadata` queries, answered from the metadata set through
+/// `SeiMsg::SetMetadata`
+#[derive(Default)]
+pub struct SeiBank {
+    keeper: BankKeeper,
+}
+
+impl SeiBank {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the balance of `account`, see `BankKeeper::init_balance`
+    pub fn init_balance(
+        &self,
+        storage: &mut dyn Storage,
+        account: &Addr,
+        amount: Vec<Coin>,
+    ) -> AnyResult<()> {
+        self.keeper.init_balance(storage, account, amount)
+    }
+}
+
+impl Bank for SeiBank {}
+
+impl Module for SeiBank {
+    type ExecT = BankMsg;
+    type QueryT = BankQuery;
+    type SudoT = BankSudo;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: Self::ExecT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        self.keeper
+            .execute(api, storage, router, block, sender, msg)
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        msg: Self::SudoT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        self.keeper.sudo(api, storage, router, block, msg)
+    }
+
+    fn query(
+        &self,
+        api: &dyn Api,
+        storage: &dyn Storage,
+        querier: &dyn Querier,
+        block: &BlockInfo,
+        request: Self::QueryT,
+    ) -> AnyResult<Binary> {
+        match request {
+            BankQuery::DenomMetadata { denom } => match denom_metadata(storage, &denom)? {
+                Some(metadata) => Ok(to_json_binary(&DenomMetadataResponse::new(
+                    to_denom_metadata(metadata),
+                ))?),
+                None => bail!("client metadata for denom {} not found", denom),
+            },
+            BankQuery::AllDenomMetadata { pagination } => {
+                let mut metadata = all_denom_metadata(storage)?;
+                let mut next_key = None;
+                if let Some(page) = pagination {
+                    if page.reverse {
+                        metadata.reverse();
+                    }
+                    if let Some(key) = page.key {
+                        let key = String::from_utf8(key.to_vec())?;
+                        metadata.retain(|m| {
+                            if page.reverse {
+                                m.base <= key
+                            } else {
+                                m.base >= key
+                            }
+                        });
+                    }
+                    let limit = page.limit as usize;
+                    if limit > 0 && metadata.len() > limit {
+                        next_key = Some(Binary::from(metadata[limit].base.as_bytes()));
+                        metadata.truncate(limit);
+                    }
+                }
+                Ok(to_json_binary(&AllDenomMetadataResponse::new(
+                    metadata.into_iter().map(to_denom_metadata).collect(),
+                    next_key,
+                ))?)
+            }
+            request => self.keeper.query(api, storage, querier, block, request),
+        }
+    }
+}
+
+fn to_denom_metadata(metadata: Metadata) -> DenomMetadata {
+    DenomMetadata {
+        description: metadata.description,
+        denom_units: metadata
+            .denom_units
+            .into_iter()
+            .map(|unit| DenomUnit {
+                denom: unit.denom,
+                exponent: unit.exponent,
+                aliases: unit.aliases,
+            })
+            .collect(),
+        base: metadata.base,
+        display: metadata.display,
+        name: metadata.name,
+        symbol: metadata.symbol,
+        uri: String::new(),
+        uri_hash: String::new(),
+    }
+}
@@ -0,0 +1,1116 @@
+use anyhow::{bail, Result as AnyResult};
+use cosmwasm_std::{Addr, BlockInfo, Coin, Decimal, Storage, Uint128};
+use sei_cosmwasm::{
+    required_funds, DepositInfo, DexPair, GetHistoricalPricesResponse, MarketSummaryResponse,
+    Order, OrderBookDepthResponse, OrderResponse, OrderStatus, OrderType, PositionDirection,
+    PriceCandlestick, PriceLevel, SeiError, SettlementEntry, UnsuccessfulOrder,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Resting order in an `OrderBook`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct BookOrder {
+    pub id: u64,
+    pub account: String,
+    pub price: Decimal,
+    /// Quantity left to be filled
+    pub quantity: Decimal,
+    pub order_type: OrderType,
+    pub position_direction: PositionDirection,
+    /// Value to fill of a `FokMarketByValue` order, in the price denom
+    #[serde(default)]
+    pub nominal: Decimal,
+    #[serde(default)]
+    pub trigger_price: Decimal,
+}
+
+/// Limit order book of a (contract, price denom, asset denom) pair with price-time priority.
+///
+/// Market orders take part in matching with their price as the worst acceptable price (a zero
+/// price accepts any price) and are cancelled if they are not completely filled by the end of
+/// the block. Fill-or-kill orders are cancelled before matching unless the limit orders of the
+/// book can fill them completely. Stop orders wait in `stops` until a fill reaches their trigger
+/// price.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct OrderBook {
+    pub price_denom: String,
+    pub asset_denom: String,
+    /// Best (highest) price first, oldest first within a price
+    pub longs: Vec<BookOrder>,
+    /// Best (lowest) price first, oldest first within a price
+    pub shorts: Vec<BookOrder>,
+    /// Stop orders not triggered yet, oldest first
+    #[serde(default)]
+    pub stops: Vec<BookOrder>,
+    /// Price of the last fill
+    #[serde(default)]
+    pub last_price: Option<Decimal>,
+}
+
+/// Orders placed and cancelled for a contract during the current block, delivered to the
+/// contract's `BulkOrderPlacements` and `BulkOrderCancellations` sudo at the end of the block
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct DexBlockBatch {
+    /// (order id, order) in placement order
+    pub orders: Vec<(u64, Order)>,
+    pub cancellations: Vec<u64>,
+    /// Funds escrowed with the orders, per account and denom
+    #[serde(default)]
+    pub deposits: Vec<DepositInfo>,
+}
+
+/// Fill of a pair, kept for the price queries
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Trade {
+    pub timestamp: u64,
+    pub price: Decimal,
+    pub quantity: Decimal,
+}
+
+/// Registration of a contract with the dex, see `SeiMsg::RegisterContract`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DexContractInfo {
+    pub code_id: u64,
+    pub creator: String,
+    pub need_hook: bool,
+    pub need_order_matching: bool,
+    /// Rent in usei held by the dex module for the contract
+    pub rent_balance: Uint128,
+}
+
+/// Address of the dex module account holding the funds sent with `SeiMsg::PlaceOrders`
+pub const DEX_MODULE_ADDRESS: &str = "dex";
+
+/// Funds escrowed by a `SeiMsg::PlaceOrders`. Each order is allotted its `required_funds` out of
+/// the deposit as far as they go. Fills move the filled share of an order's allotment to the
+/// contract, cancelled and failed orders get the rest of their allotment refunded, and the rest
+/// of the deposit is refunded once no order is open.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Escrow {
+    pub account: String,
+    /// (order id, allotted funds left) of the open orders
+    pub orders: Vec<(u64, Vec<Coin>)>,
+    /// Funds left in escrow
+    pub funds: Vec<Coin>,
+}
+
+/// (recipient, funds) to send out of the dex module account
+pub(crate) type Transfers = Vec<(Addr, Vec<Coin>)>;
+
+// Funds taken out of the escrow of `account` by `take_escrow`, and the rest of its deposit once
+// none of its orders is open
+struct EscrowTake {
+    account: Addr,
+    taken: Vec<Coin>,
+    left: Vec<Coin>,
+}
+
+/// Match of a long and a short order
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fill {
+    pub long: BookOrder,
+    pub short: BookOrder,
+    pub price: Decimal,
+    pub quantity: Decimal,
+}
+
+impl OrderBook {
+    pub fn new(price_denom: impl Into<String>, asset_denom: impl Into<String>) -> Self {
+        OrderBook {
+            price_denom: price_denom.into(),
+            asset_denom: asset_denom.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds `order` to its side of the book, or to `stops` for a stop order
+    pub fn insert(&mut self, order: BookOrder) {
+        if order.order_type.is_triggered() {
+            self.stops.push(order);
+        } else {
+            self.insert_active(order);
+        }
+    }
+
+    fn insert_active(&mut self, order: BookOrder) {
+        let limit = limit_price(&order);
+        let side = match order.position_direction {
+            PositionDirection::Long => &mut self.longs,
+            PositionDirection::Short => &mut self.shorts,
+        };
+        let pos = side.partition_point(|o| match order.position_direction {
+            PositionDirection::Long => limit_price(o) >= limit,
+            PositionDirection::Short => limit_price(o) <= limit,
+        });
+        side.insert(pos, order);
+    }
+
+    pub fn remove(&mut self, id: u64) -> Option<BookOrder> {
+        for side in [&mut self.longs, &mut self.shorts, &mut self.stops] {
+            if let Some(pos) = side.iter().position(|o| o.id == id) {
+                return Some(side.remove(pos));
+            }
+        }
+        None
+    }
+
+    /// Matches crossing orders until the book is no longer crossed. Orders fill at the price of
+    /// the resting (limit, then oldest) order. Market orders only match limit orders.
+    pub fn match_orders(&mut self) -> Vec<Fill> {
+        let mut fills = vec![];
+        while let Some((l, s)) = self.next_match() {
+            let (long, short) = (&self.longs[l], &self.shorts[s]);
+            let maker = match (long.order_type.is_market(), short.order_type.is_market()) {
+                (true, false) => short,
+                (false, true) => long,
+                _ if long.id < short.id => long,
+                _ => short,
+            };
+            let quantity = long.quantity.min(short.quantity);
+            fills.push(Fill {
+                long: long.clone(),
+                short: short.clone(),
+                price: maker.price,
+                quantity,
+            });
+            self.last_price = Some(maker.price);
+
+            self.longs[l].quantity -= quantity;
+            self.shorts[s].quantity -= quantity;
+            if self.longs[l].quantity.is_zero() {
+                self.longs.remove(l);
+            }
+            if self.shorts[s].quantity.is_zero() {
+                self.shorts.remove(s);
+            }
+        }
+        fills
+    }
+
+    // Returns the positions of the next crossing long and short. When both best orders are
+    // market orders, the older one is matched with the best limit order of the other side.
+    fn next_match(&self) -> Option<(usize, usize)> {
+        let (long, short) = (self.longs.first()?, self.shorts.first()?);
+        let crosses =
+            |l: usize, s: usize| limit_price(&self.longs[l]) >= limit_price(&self.shorts[s]);
+        if !(long.order_type.is_market() && short.order_type.is_market()) {
+            return crosses(0, 0).then_some((0, 0));
+        }
+        let first_limit = |side: &[BookOrder]| side.iter().position(|o| !o.order_type.is_market());
+        let mut candidates = [
+            (long.id, first_limit(&self.shorts).map(|s| (0, s))),
+            (short.id, first_limit(&self.longs).map(|l| (l, 0))),
+        ];
+        candidates.sort_by_key(|(id, _)| *id);
+        candidates
+            .into_iter()
+            .filter_map(|(_, candidate)| candidate)
+            .find(|(l, s)| crosses(*l, *s))
+    }
+
+    /// Matches the book at the end of a block: cancels the fill-or-kill orders that cannot be
+    /// filled, matches crossing orders, places the stop orders triggered by the fills and
+    /// matches again, then cancels the market orders left. Returns the fills and the cancelled
+    /// orders.
+    pub fn match_block(&mut self) -> (Vec<Fill>, Vec<BookOrder>) {
+        let mut cancelled = self.cancel_unfillable_orders();
+        let mut fills = self.match_orders();
+        loop {
+            let triggered = self.trigger_stops();
+            if triggered.is_empty() {
+                break;
+            }
+            for order in triggered {
+                self.insert_active(order);
+            }
+            fills.extend(self.match_orders());
+        }
+        cancelled.extend(self.cancel_market_orders());
+        (fills, cancelled)
+    }
+
+    /// Removes the fill-or-kill orders that the limit orders on the other side cannot fill
+    /// completely. `FokMarketByValue` orders left get the quantity their nominal buys.
+    pub fn cancel_unfillable_orders(&mut self) -> Vec<BookOrder> {
+        let mut cancelled = vec![];
+        for direction in [PositionDirection::Long, PositionDirection::Short] {
+            let (side, opposite) = match direction {
+                PositionDirection::Long => (&mut self.longs, &self.shorts),
+                PositionDirection::Short => (&mut self.shorts, &self.longs),
+            };
+            side.retain_mut(|o| {
+                if !o.order_type.is_fill_or_kill() {
+                    return true;
+                }
+                match fillable_quantity(o, opposite) {
+                    Some(quantity) => {
+                        o.quantity = quantity;
+                        true
+                    }
+                    None => {
+                        cancelled.push(o.clone());
+                        false
+                    }
+                }
+            });
+        }
+        cancelled
+    }
+
+    /// Removes and returns the stop orders whose trigger price was reached by the last fill
+    pub fn trigger_stops(&mut self) -> Vec<BookOrder> {
+        let last_price = match self.last_price {
+            Some(price) => price,
+            None => return vec![],
+        };
+        let (triggered, waiting) = self
+            .stops
+            .drain(..)
+            .partition(|o| match o.position_direction {
+                PositionDirection::Long => last_price >= o.trigger_price,
+                PositionDirection::Short => last_price <= o.trigger_price,
+            });
+        self.stops = waiting;
+        triggered
+    }
+
+    /// Removes the market orders left after matching
+    pub fn cancel_market_orders(&mut self) -> Vec<BookOrder> {
+        let mut cancelled = vec![];
+        for side in [&mut self.longs, &mut self.shorts] {
+            side.retain(|o| {
+                let keep = !o.order_type.is_market();
+                if !keep {
+                    cancelled.push(o.clone());
+                }
+                keep
+            });
+        }
+        cancelled
+    }
+
+    /// Returns the quantity of `order` that would be filled against the limit orders of the book
+    pub fn simulate(&self, order: &Order) -> Decimal {
+        let order = BookOrder {
+            id: u64::MAX,
+            account: String::new(),
+            price: order.price,
+            quantity: order.quantity,
+            order_type: order.order_type,
+            position_direction: order.position_direction,
+            nominal: order.nominal,
+            trigger_price: order.trigger_price.unwrap_or_default(),
+        };
+        let limit = limit_price(&order);
+        let opposite = match order.position_direction {
+            PositionDirection::Long => &self.shorts,
+            PositionDirection::Short => &self.longs,
+        };
+
+        let mut executed = Decimal::zero();
+        for resting in opposite.iter().filter(|o| !o.order_type.is_market()) {
+            let crosses = match order.position_direction {
+                PositionDirection::Long => limit >= limit_price(resting),
+                PositionDirection::Short => limit <= limit_price(resting),
+            };
+            if !crosses || executed >= order.quantity {
+                break;
+            }
+            executed += resting.quantity;
+        }
+        executed.min(order.quantity)
+    }
+}
+
+// Worst price an order accepts, a market order with a zero price accepts any price
+fn limit_price(order: &BookOrder) -> Decimal {
+    match order.position_direction {
+        PositionDirection::Long if order.order_type.is_market() && order.price.is_zero() => {
+            Decimal::MAX
+        }
+        _ => order.price,
+    }
+}
+
+// Quantity of a fill-or-kill `order` the limit orders of the `opposite` side fill, `None` if
+// they cannot fill it completely
+fn fillable_quantity(order: &BookOrder, opposite: &[BookOrder]) -> Option<Decimal> {
+    let limit = limit_price(order);
+    let by_value = order.order_type == OrderType::FokMarketByValue;
+    let (mut quantity, mut value) = (Decimal::zero(), Decimal::zero());
+    for resting in opposite.iter().filter(|o| !o.order_type.is_market()) {
+        let crosses = match order.position_direction {
+            PositionDirection::Long => limit >= resting.price,
+            PositionDirection::Short => limit <= resting.price,
+        };
+        if !crosses {
+            break;
+        }
+        let left = if by_value {
+            (order.nominal - value).checked_div(resting.price).ok()?
+        } else {
+            order.quantity - quantity
+        };
+        if left <= resting.quantity {
+            return Some(quantity + left);
+        }
+        quantity += resting.quantity;
+        value += resting.quantity * resting.price;
+    }
+    None
+}
+
+// Storage:
+// "DexContracts" -> contract addresses with order books
+// contract_address + "-" + "DexPairs" -> DexPair[]
+// contract_address + "-" + "OrderBooks" -> OrderBook[]
+// "SettlementIdCounter" -> SettlementId
+// "DexBlockBatches" -> contract_address -> DexBlockBatch of the current block
+// contract_address + "-" + "DexEscrows" -> Escrow[]
+// contract_address + "-" + "DexRegistration" -> DexContractInfo
+// contract_address + "-" + "DexTrades" + "-" + Price Denom + "-" + Asset Denom -> Trade[]
+
+pub(crate) fn register_pair(
+    storage: &mut dyn Storage,
+    contract_address: &Addr,
+    pair: DexPair,
+) -> AnyResult<()> {
+    let key = pairs_key(contract_address);
+    let mut pairs: Vec<DexPair> = load(storage, &key)?.unwrap_or_default();
+    pairs.retain(|p| p.price_denom != pair.price_denom || p.asset_denom != pair.asset_denom);
+    pairs.push(pair);
+    save(storage, &key, &pairs)
+}
+
+/// Sets the tick sizes of the registered `pairs`, failing with `SeiError::PairNotRegistered`
+/// for a pair that is not registered
+pub(crate) fn update_tick_sizes(
+    storage: &mut dyn Storage,
+    contract_address: &Addr,
+    pairs: Vec<DexPair>,
+) -> AnyResult<()> {
+    let key = pairs_key(contract_address);
+    let mut registered: Vec<DexPair> = load(storage, &key)?.unwrap_or_default();
+    for pair in pairs {
+        match registered
+            .iter_mut()
+            .find(|p| p.price_denom == pair.price_denom && p.asset_denom == pair.asset_denom)
+        {
+            Some(registered) => *registered = pair,
+            None => bail!(SeiError::PairNotRegistered {
+                contract_address: contract_address.to_string(),
+                price_denom: pair.price_denom,
+                asset_denom: pair.asset_denom,
+            }),
+        }
+    }
+    save(storage, &key, &registered)
+}
+
+pub(crate) fn load_registration(
+    storage: &dyn Storage,
+    contract_address: &Addr,
+) -> AnyResult<Option<DexContractInfo>> {
+    load(storage, &registration_key(contract_address))
+}
+
+pub(crate) fn save_registration(
+    storage: &mut dyn Storage,
+    contract_address: &Addr,
+    info: &DexContractInfo,
+) -> AnyResult<()> {
+    save(storage, &registration_key(contract_address), info)
+}
+
+/// Returns the registration of `contract_address`, failing unless `sender` created it
+pub(crate) fn creator_registration(
+    storage: &dyn Storage,
+    contract_address: &Addr,
+    sender: &Addr,
+) -> AnyResult<DexContractInfo> {
+    let info = match load_registration(storage, contract_address)? {
+        Some(info) => info,
+        None => bail!(SeiError::ContractNotRegistered {
+            contract_address: contract_address.to_string(),
+        }),
+    };
+    if info.creator != sender.as_str() {
+        bail!(SeiError::NotContractCreator {
+            contract_address: contract_address.to_string(),
+            sender: sender.to_string(),
+        });
+    }
+    Ok(info)
+}
+
+/// Removes the registration, the pairs and the order books of `contract_address`
+pub(crate) fn unregister_contract(
+    storage: &mut dyn Storage,
+    contract_address: &Addr,
+) -> AnyResult<()> {
+    let mut contracts: BTreeSet<String> = load(storage, "DexContracts")?.unwrap_or_default();
+    if contracts.remove(contract_address.as_str()) {
+        save(storage, "DexContracts", &contracts)?;
+    }
+    storage.remove(registration_key(contract_address).as_bytes());
+    storage.remove(pairs_key(contract_address).as_bytes());
+    storage.remove(order_books_key(contract_address).as_bytes());
+    Ok(())
+}
+
+pub(crate) fn load_pairs(
+    storage: &dyn Storage,
+    contract_address: &Addr,
+) -> AnyResult<Vec<DexPair>> {
+    Ok(load(storage, &pairs_key(contract_address))?.unwrap_or_default())
+}
+
+/// Validates the fields of the order type and the order against the tick sizes of its
+/// registered pair. Orders of contracts without registered pairs are not checked against ticks.
+pub(crate) fn validate_order(
+    storage: &dyn Storage,
+    contract_address: &Addr,
+    order: &Order,
+) -> AnyResult<()> {
+    order.check_order_type()?;
+    let pairs = load_pairs(storage, contract_address)?;
+    if pairs.is_empty() {
+        return Ok(());
+    }
+    let pair = match pairs
+        .iter()
+        .find(|p| p.price_denom == order.price_denom && p.asset_denom == order.asset_denom)
+    {
+        Some(pair) => pair,
+        None => bail!(SeiError::PairNotRegistered {
+            contract_address: contract_address.to_string(),
+            price_denom: order.price_denom.clone(),
+            asset_denom: order.asset_denom.clone(),
+        }),
+    };
+    pair.check_ticks(order)?;
+    Ok(())
+}
+
+pub(crate) fn load_order_books(
+    storage: &dyn Storage,
+    contract_address: &Addr,
+) -> AnyResult<Vec<OrderBook>> {
+    Ok(load(storage, &order_books_key(contract_address))?.unwrap_or_default())
+}
+
+pub(crate) fn save_order_books(
+    storage: &mut dyn Storage,
+    contract_address: &Addr,
+    books: &[OrderBook],
+) -> AnyResult<()> {
+    let mut contracts: BTreeSet<String> = load(storage, "DexContracts")?.unwrap_or_default();
+    if contracts.insert(contract_address.to_string()) {
+        save(storage, "DexContracts", &contracts)?;
+    }
+    save(storage, &order_books_key(contract_address), &books)
+}
+
+/// Adds a placed order to the book of its pair
+pub(crate) fn add_to_book(
+    storage: &mut dyn Storage,
+    contract_address: &Addr,
+    account: &str,
+    id: u64,
+    order: &Order,
+) -> AnyResult<()> {
+    let mut books = load_order_books(storage, contract_address)?;
+    let pos = match books
+        .iter()
+        .position(|b| b.price_denom == order.price_denom && b.asset_denom == order.asset_denom)
+    {
+        Some(pos) => pos,
+        None => {
+            books.push(OrderBook::new(&order.price_denom, &order.asset_denom));
+            books.len() - 1
+        }
+    };
+    books[pos].insert(BookOrder {
+        id,
+        account: account.to_string(),
+        price: order.price,
+        quantity: order.quantity,
+        order_type: order.order_type,
+        position_direction: order.position_direction,
+        nominal: order.nominal,
+        trigger_price: order.trigger_price.unwrap_or_default(),
+    });
+    save_order_books(storage, contract_address, &books)
+}
+
+pub(crate) fn record_block_orders(
+    storage: &mut dyn Storage,
+    contract_address: &Addr,
+    orders: Vec<(u64, Order)>,
+) -> AnyResult<()> {
+    let mut batches = load_block_batches(storage)?;
+    batches
+        .entry(contract_address.to_string())
+        .or_default()
+        .orders
+        .extend(orders);
+    save(storage, "DexBlockBatches", &batches)
+}
+
+/// Adds the deposit of `account` to the batch of the current block, merged per account and denom
+pub(crate) fn record_block_deposits(
+    storage: &mut dyn Storage,
+    contract_address: &Addr,
+    account: &str,
+    funds: &[Coin],
+) -> AnyResult<()> {
+    let mut batches = load_block_batches(storage)?;
+    let deposits = &mut batches
+        .entry(contract_address.to_string())
+        .or_default()
+        .deposits;
+    for coin in funds {
+        let amount = Decimal::from_atomics(coin.amount, 0)?;
+        match deposits
+            .iter_mut()
+            .find(|d| d.account == account && d.denom == coin.denom)
+        {
+            Some(deposit) => deposit.amount += amount,
+            None => deposits.push(DepositInfo {
+                account: account.to_string(),
+                denom: coin.denom.clone(),
+                amount,
+            }),
+        }
+    }
+    save(storage, "DexBlockBatches", &batches)
+}
+
+pub(crate) fn record_block_cancellations(
+    storage: &mut dyn Storage,
+    contract_address: &Addr,
+    ids: &[u64],
+) -> AnyResult<()> {
+    let mut batches = load_block_batches(storage)?;
+    batches
+        .entry(contract_address.to_string())
+        .or_default()
+        .cancellations
+        .extend_from_slice(ids);
+    save(storage, "DexBlockBatches", &batches)
+}
+
+/// Returns and clears the batches of the current block
+pub(crate) fn take_block_batches(
+    storage: &mut dyn Storage,
+) -> AnyResult<Vec<(Addr, DexBlockBatch)>> {
+    let batches = load_block_batches(storage)?;
+    storage.remove("DexBlockBatches".as_bytes());
+    Ok(batches
+        .into_iter()
+        .map(|(contract_address, batch)| (Addr::unchecked(contract_address), batch))
+        .collect())
+}
+
+/// Removes the orders the contract rejected in its `BulkOrderPlacements` sudo from the books and
+/// marks them as `FailedToPlace`. Returns the (account, funds) to refund from their escrow.
+pub(crate) fn fail_orders(
+    storage: &mut dyn Storage,
+    contract_address: &Addr,
+    unsuccessful_orders: &[UnsuccessfulOrder],
+) -> AnyResult<Transfers> {
+    let ids: Vec<u64> = unsuccessful_orders.iter().map(|o| o.id).collect();
+    remove_from_books(storage, contract_address, &ids)?;
+
+    let order_responses_key = contract_address.to_string() + "-" + "OrderResponses";
+    let mut order_responses: Vec<OrderResponse> =
+        load_json_string(storage, &order_responses_key)?.unwrap_or_default();
+    for order_response in order_responses.iter_mut() {
+        if let Some(unsuccessful) = unsuccessful_orders
+            .iter()
+            .find(|o| o.id == order_response.id)
+        {
+            order_response.status = OrderStatus::FailedToPlace;
+            order_response.status_description = unsuccessful.reason.clone();
+            save_json_string(
+                storage,
+                &order_id_key(contract_address, order_response),
+                &order_response,
+            )?;
+        }
+    }
+    save_json_string(storage, &order_responses_key, &order_responses)?;
+    release_escrow(storage, contract_address, &ids)
+}
+
+/// Escrows the `funds` `account` sent with `orders`, see `Escrow`
+pub(crate) fn escrow_funds(
+    storage: &mut dyn Storage,
+    contract_address: &Addr,
+    account: &str,
+    orders: &[(u64, Order)],
+    funds: &[Coin],
+) -> AnyResult<()> {
+    let funds: Vec<Coin> = funds
+        .iter()
+        .filter(|c| !c.amount.is_zero())
+        .cloned()
+        .collect();
+    if funds.is_empty() {
+        return Ok(());
+    }
+    let mut left = funds.clone();
+    let mut escrow = Escrow {
+        account: account.to_string(),
+        orders: vec![],
+        funds,
+    };
+    for (id, order) in orders {
+        let mut allotted = vec![];
+        for required in required_funds(std::slice::from_ref(order)) {
+            if let Some(coin) = left.iter_mut().find(|c| c.denom == required.denom) {
+                let amount = coin.amount.min(required.amount);
+                coin.amount -= amount;
+                if !amount.is_zero() {
+                    allotted.push(Coin::new(amount.u128(), required.denom));
+                }
+            }
+        }
+        escrow.orders.push((*id, allotted));
+    }
+
+    let key = escrows_key(contract_address);
+    let mut escrows: Vec<Escrow> = load(storage, &key)?.unwrap_or_default();
+    escrows.push(escrow);
+    save(storage, &key, &escrows)
+}
+
+/// Releases the funds escrowed for the orders `ids`, returning the (account, funds) to refund
+pub(crate) fn release_escrow(
+    storage: &mut dyn Storage,
+    contract_address: &Addr,
+    ids: &[u64],
+) -> AnyResult<Transfers> {
+    let takes: Vec<(u64, Decimal, Decimal)> = ids
+        .iter()
+        .map(|id| (*id, Decimal::one(), Decimal::one()))
+        .collect();
+    Ok(take_escrow(storage, contract_address, &takes)?
+        .into_iter()
+        .map(|mut take| {
+            for coin in take.left {
+                add_coin(&mut take.taken, coin);
+            }
+            (take.account, take.taken)
+        })
+        .filter(|(_, refund)| !refund.is_empty())
+        .collect())
+}
+
+/// Moves the filled share of the funds escrowed for the (order id, filled quantity, quantity
+/// before the fill) `fills` to the contract. Returns the (recipient, funds) to send: the funds
+/// taken to the contract, and the rest of the deposits without open orders to their account.
+fn settle_escrow(
+    storage: &mut dyn Storage,
+    contract_address: &Addr,
+    fills: &[(u64, Decimal, Decimal)],
+) -> AnyResult<Transfers> {
+    let mut settled = vec![];
+    let mut transfers = vec![];
+    for take in take_escrow(storage, contract_address, fills)? {
+        for coin in take.taken {
+            add_coin(&mut settled, coin);
+        }
+        if !take.left.is_empty() {
+            transfers.push((take.account, take.left));
+        }
+    }
+    if !settled.is_empty() {
+        transfers.insert(0, (contract_address.clone(), settled));
+    }
+    Ok(transfers)
+}
+
+// Takes the `filled / quantity` share of the funds allotted to each (order id, filled, quantity)
+// out of the escrows of the contract, closing the orders filled completely. Returns what was
+// taken out of each escrow changed.
+fn take_escrow(
+    storage: &mut dyn Storage,
+    contract_address: &Addr,
+    takes: &[(u64, Decimal, Decimal)],
+) -> AnyResult<Vec<EscrowTake>> {
+    let key = escrows_key(contract_address);
+    let mut escrows: Vec<Escrow> = load(storage, &key)?.unwrap_or_default();
+    let mut taken: BTreeMap<usize, Vec<Coin>> = BTreeMap::new();
+    for (id, filled, quantity) in takes {
+        let found = escrows.iter().enumerate().find_map(|(e, escrow)| {
+            let o = escrow
+                .orders
+                .iter()
+                .position(|(order_id, _)| order_id == id)?;
+            Some((e, o))
+        });
+        let (e, o) = match found {
+            Some(found) => found,
+            None => continue,
+        };
+        let escrow = &mut escrows[e];
+        let closed = filled >= quantity;
+        let taken = taken.entry(e).or_default();
+        for coin in escrow.orders[o].1.iter_mut() {
+            let amount = if closed {
+                coin.amount
+            } else {
+                coin.amount
+                    .multiply_ratio(filled.atomics(), quantity.atomics())
+            };
+            coin.amount -= amount;
+            if let Some(left) = escrow.funds.iter_mut().find(|c| c.denom == coin.denom) {
+                left.amount -= amount;
+            }
+            add_coin(taken, Coin::new(amount.u128(), coin.denom.clone()));
+        }
+        if closed {
+            escrow.orders.remove(o);
+        }
+    }
+
+    let mut changed = vec![];
+    for (e, taken) in taken {
+        let escrow = &mut escrows[e];
+        let mut left = vec![];
+        if escrow.orders.is_empty() {
+            for coin in std::mem::take(&mut escrow.funds) {
+                add_coin(&mut left, coin);
+            }
+        }
+        changed.push(EscrowTake {
+            account: Addr::unchecked(&escrow.account),
+            taken,
+            left,
+        });
+    }
+    escrows.retain(|e| !e.orders.is_empty());
+    save(storage, &key, &escrows)?;
+    Ok(changed)
+}
+
+// Adds `coin` to `coins`, skipping zero amounts
+fn add_coin(coins: &mut Vec<Coin>, coin: Coin) {
+    if coin.amount.is_zero() {
+        return;
+    }
+    match coins.iter_mut().find(|c| c.denom == coin.denom) {
+        Some(existing) => existing.amount += coin.amount,
+        None => coins.push(coin),
+    }
+}
+
+pub(crate) fn remove_from_books(
+    storage: &mut dyn Storage,
+    contract_address: &Addr,
+    ids: &[u64],
+) -> AnyResult<()> {
+    let mut books = load_order_books(storage, contract_address)?;
+    for book in books.iter_mut() {
+        for id in ids {
+            book.remove(*id);
+        }
+    }
+    save_order_books(storage, contract_address, &books)
+}
+
+/// Outcome of `end_block_matching`
+pub(crate) struct EndBlockMatching {
+    /// Settlement entries of each contract with fills
+    pub settlements: Vec<(Addr, Vec<SettlementEntry>)>,
+    /// Escrowed funds to send for the filled and cancelled orders, see `Escrow`
+    pub transfers: Transfers,
+}
+
+/// Matches the order books of all contracts. Filled orders are marked as `Fulfilled`, unfilled
+/// market and fill-or-kill orders as `Cancelled`.
+pub(crate) fn end_block_matching(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+) -> AnyResult<EndBlockMatching> {
+    let contracts: BTreeSet<String> = load(storage, "DexContracts")?.unwrap_or_default();
+    let mut settlements = vec![];
+    let mut transfers = vec![];
+    for contract in contracts {
+        let contract_address = Addr::unchecked(contract);
+        let mut books = load_order_books(storage, &contract_address)?;
+        let mut entries = vec![];
+        let mut updates: Vec<(u64, Decimal, OrderStatus)> = vec![];
+        let mut escrow_fills: Vec<(u64, Decimal, Decimal)> = vec![];
+        let mut cancelled_ids = vec![];
+
+        for book in books.iter_mut() {
+            let (fills, cancelled) = book.match_block();
+            let trades: Vec<Trade> = fills
+                .iter()
+                .map(|fill| Trade {
+                    timestamp: block.time.seconds(),
+                    price: fill.price,
+                    quantity: fill.quantity,
+                })
+                .collect();
+            record_trades(storage, &contract_address, book, trades)?;
+            for fill in fills {
+                for order in [&fill.long, &fill.short] {
+                    let settlement_id = next_settlement_id(storage);
+                    entries.push(SettlementEntry {
+                        account: order.account.clone(),
+                        price_denom: book.price_denom.clone(),
+                        asset_denom: book.asset_denom.clone(),
+                        quantity: fill.quantity,
+                        execution_cost_or_proceed: fill.price,
+                        expected_cost_or_proceed: order.price,
+                        position_direction: order.position_direction,
+                        order_type: order.order_type,
+                        order_id: order.id,
+                        timestamp: block.time.seconds(),
+                        height: block.height,
+                        settlement_id,
+                    });
+                    let remaining = order.quantity - fill.quantity;
+                    let status = if remaining.is_zero() {
+                        OrderStatus::Fulfilled
+                    } else {
+                        OrderStatus::Placed
+                    };
+                    updates.push((order.id, remaining, status));
+                    escrow_fills.push((order.id, fill.quantity, order.quantity));
+                }
+            }
+            for order in cancelled {
+                updates.push((order.id, order.quantity, OrderStatus::Cancelled));
+                cancelled_ids.push(order.id);
+            }
+        }
+
+        save_order_books(storage, &contract_address, &books)?;
+        update_order_responses(storage, &contract_address, &updates)?;
+        transfers.extend(settle_escrow(storage, &contract_address, &escrow_fills)?);
+        transfers.extend(release_escrow(storage, &contract_address, &cancelled_ids)?);
+        if !entries.is_empty() {
+            settlements.push((contract_address, entries));
+        }
+    }
+    Ok(EndBlockMatching {
+        settlements,
+        transfers,
+    })
+}
+
+fn record_trades(
+    storage: &mut dyn Storage,
+    contract_address: &Addr,
+    book: &OrderBook,
+    trades: Vec<Trade>,
+) -> AnyResult<()> {
+    if trades.is_empty() {
+        return Ok(());
+    }
+    let key = trades_key(contract_address, &book.price_denom, &book.asset_denom);
+    let mut recorded: Vec<Trade> = load(storage, &key)?.unwrap_or_default();
+    recorded.extend(trades);
+    save(storage, &key, &recorded)
+}
+
+/// Returns the fills of the pair, oldest first
+pub(crate) fn load_trades(
+    storage: &dyn Storage,
+    contract_address: &Addr,
+    price_denom: &str,
+    asset_denom: &str,
+) -> AnyResult<Vec<Trade>> {
+    Ok(load(
+        storage,
+        &trades_key(contract_address, price_denom, asset_denom),
+    )?
+    .unwrap_or_default())
+}
+
+/// Aggregates the resting limit orders of `book` per price level, at most `limit` levels per
+/// side. Stop orders waiting for their trigger are not part of the depth.
+pub(crate) fn order_book_depth(book: &OrderBook, limit: Option<u32>) -> OrderBookDepthResponse {
+    let levels = |orders: &[BookOrder]| {
+        let mut levels: Vec<PriceLevel> = vec![];
+        for order in orders {
+            match levels.last_mut() {
+                Some(level) if level.price == order.price => level.quantity += order.quantity,
+                _ => levels.push(PriceLevel {
+                    price: order.price,
+                    quantity: order.quantity,
+                }),
+            }
+        }
+        if let Some(limit) = limit {
+            levels.truncate(limit as usize);
+        }
+        levels
+    };
+    OrderBookDepthResponse {
+        longs: levels(&book.longs),
+        shorts: levels(&book.shorts),
+    }
+}
+
+/// Summarizes the `trades` at or after `since`
+pub(crate) fn market_summary(trades: &[Trade], since: u64) -> MarketSummaryResponse {
+    let trades: Vec<&Trade> = trades.iter().filter(|t| t.timestamp >= since).collect();
+    MarketSummaryResponse {
+        high_price: trades.iter().map(|t| t.price).max(),
+        low_price: trades.iter().map(|t| t.price).min(),
+        last_price: trades.last().map(|t| t.price),
+        total_volume: trades.iter().map(|t| t.quantity).sum(),
+        total_volume_notional: trades.iter().map(|t| t.price * t.quantity).sum(),
+    }
+}
+
+/// Builds the candlesticks of the `number_of_periods` periods ending at `end`, each period
+/// holding the trades after its begin and up to its end
+pub(crate) fn historical_prices(
+    trades: &[Trade],
+    end: u64,
+    period_length_in_seconds: u64,
+    number_of_periods: u64,
+) -> GetHistoricalPricesResponse {
+    let mut prices = vec![];
+    for period in (0..number_of_periods).rev() {
+        let end_timestamp = end.saturating_sub(period * period_length_in_seconds);
+        let begin_timestamp = end_timestamp.saturating_sub(period_length_in_seconds);
+        let trades: Vec<&Trade> = trades
+            .iter()
+            .filter(|t| t.timestamp > begin_timestamp && t.timestamp <= end_timestamp)
+            .collect();
+        let (Some(first), Some(last)) = (trades.first(), trades.last()) else {
+            continue;
+        };
+        prices.push(PriceCandlestick {
+            begin_timestamp,
+            end_timestamp,
+            open: first.price,
+            high: trades.iter().map(|t| t.price).max().unwrap_or_default(),
+            low: trades.iter().map(|t| t.price).min().unwrap_or_default(),
+            close: last.price,
+            volume: trades.iter().map(|t| t.quantity).sum(),
+        });
+    }
+    GetHistoricalPricesResponse { prices }
+}
+
+/// Orders placed with `contract_address` as returned by GetOrders(), `None` until the first one
+pub(crate) fn load_order_responses(
+    storage: &dyn Storage,
+    contract_address: &Addr,
+) -> AnyResult<Option<Vec<OrderResponse>>> {
+    let order_responses_key = contract_address.to_string() + "-" + "OrderResponses";
+    load_json_string(storage, &order_responses_key)
+}
+
+// Applies (id, remaining quantity, status) updates to the GetOrders() and GetOrderById() storage
+fn update_order_responses(
+    storage: &mut dyn Storage,
+    contract_address: &Addr,
+    updates: &[(u64, Decimal, OrderStatus)],
+) -> AnyResult<()> {
+    if updates.is_empty() {
+        return Ok(());
+    }
+    let order_responses_key = contract_address.to_string() + "-" + "OrderResponses";
+    let mut order_responses: Vec<OrderResponse> =
+        load_json_string(storage, &order_responses_key)?.unwrap_or_default();
+    for order_response in order_responses.iter_mut() {
+        // the last update of an order is the most recent one
+        if let Some((_, remaining, status)) =
+            updates.iter().rev().find(|u| u.0 == order_response.id)
+        {
+            order_response.status = *status;
+            if *status == OrderStatus::Placed {
+                order_response.quantity = *remaining;
+            }
+            save_json_string(
+                storage,
+                &order_id_key(contract_address, order_response),
+                &order_response,
+            )?;
+        }
+    }
+    save_json_string(storage, &order_responses_key, &order_responses)
+}
+
+fn next_settlement_id(storage: &mut dyn Storage) -> u64 {
+    let id = storage
+        .get("SettlementIdCounter".as_bytes())
+        .map(|v| u64::from_be_bytes(v.try_into().unwrap_or_default()))
+        .unwrap_or_default();
+    storage.set("SettlementIdCounter".as_bytes(), &(id + 1).to_be_bytes());
+    id
+}
+
+fn load_block_batches(storage: &dyn Storage) -> AnyResult<BTreeMap<String, DexBlockBatch>> {
+    Ok(load(storage, "DexBlockBatches")?.unwrap_or_default())
+}
+
+fn order_id_key(contract_address: &Addr, order_response: &OrderResponse) -> String {
+    contract_address.to_string()
+        + "-"
+        + "OrderResponseById"
+        + "-"
+        + &order_response.price_denom
+        + "-"
+        + &order_response.asset_denom
+        + "-"
+        + &order_response.id.to_string()
+}
+
+fn pairs_key(contract_address: &Addr) -> String {
+    contract_address.to_string() + "-" + "DexPairs"
+}
+
+fn trades_key(contract_address: &Addr, price_denom: &str, asset_denom: &str) -> String {
+    contract_address.to_string() + "-" + "DexTrades" + "-" + price_denom + "-" + asset_denom
+}
+
+fn registration_key(contract_address: &Addr) -> String {
+    contract_address.to_string() + "-" + "DexRegistration"
+}
+
+fn escrows_key(contract_address: &Addr) -> String {
+    contract_address.to_string() + "-" + "DexEscrows"
+}
+
+fn order_books_key(contract_address: &Addr) -> String {
+    contract_address.to_string() + "-" + "OrderBooks"
+}
+
+fn load<T: DeserializeOwned>(storage: &dyn Storage, key: &str) -> AnyResult<Option<T>> {
+    match storage.get(key.as_bytes()) {
+        Some(value) => Ok(Some(serde_json::from_slice(&value)?)),
+        None => Ok(None),
+    }
+}
+
+fn save<T: Serialize>(storage: &mut dyn Storage, key: &str, value: &T) -> AnyResult<()> {
+    storage.set(key.as_bytes(), &serde_json::to_vec(value)?);
+    Ok(())
+}
+
+// Order responses are stored as JSON encoded JSON strings
+fn load_json_string<T: DeserializeOwned>(storage: &dyn Storage, key: &str) -> AnyResult<Option<T>> {
+    match load::<String>(storage, key)? {
+        Some(json) => Ok(Some(serde_json::from_str(&json)?)),
+        None => Ok(None),
+    }
+}
+
+fn save_json_string<T: Serialize>(
+    storage: &mut dyn Storage,
+    key: &str,
+    value: &T,
+) -> AnyResult<()> {
+    save(storage, key, &serde_json::to_string(value)?)
+}
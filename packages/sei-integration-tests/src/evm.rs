@@ -0,0 +1,1383 @@
+use anyhow::{anyhow, bail, Result as AnyResult};
+use cosmwasm_std::{to_json_binary, Addr, Binary, Storage, Uint128, Uint256};
+use cw20::{BalanceResponse, TokenInfoResponse};
+use cw_multi_test::AppResponse;
+use sei_cosmwasm::{
+    abi_decode, abi_encode, encode_function_call, function_selector, keccak256, parse_evm_address,
+    to_checksum_address, AbiToken, AbiType, Erc1155BalanceOfBatchResponse,
+    Erc1155BalanceOfResponse, Erc1155IsApprovedForAllResponse, Erc1155RoyaltyInfoResponse,
+    Erc1155UriResponse, Erc20AllowanceResponse, Erc721ApprovedResponse, Erc721BalanceOfResponse,
+    Erc721IsApprovedForAllResponse, Erc721NameSymbolResponse, Erc721OwnerResponse,
+    Erc721RoyaltyInfoResponse, Erc721TokenByIndexResponse, Erc721TokenOfOwnerByIndexResponse,
+    Erc721TotalSupplyResponse, Erc721UriResponse, ErcPayloadResponse, MsgInternalEVMCallResponse,
+    MsgResponse, SeiError, SeiQuery, StaticCallResponse,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::address::AddressAssociations;
+
+const ZERO_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
+
+/// Mock ERC-20 contract state. Holders can be given as Sei or EVM addresses and are stored by
+/// their (associated) EVM address once registered with `SeiModule::register_erc20`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct MockErc20 {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub total_supply: Uint128,
+    pub balances: BTreeMap<String, Uint128>,
+    /// owner -> spender -> allowance
+    pub allowances: BTreeMap<String, BTreeMap<String, Uint128>>,
+}
+
+/// Mock ERC-721 contract state, also implementing the enumeration extension and ERC-2981
+/// royalties. Owners, approvals, operators and royalty receivers can be given as Sei or EVM
+/// addresses and are stored by their EVM address once registered with
+/// `SeiModule::register_erc721`. Tokens are enumerated in increasing token id order.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct MockErc721 {
+    pub name: String,
+    pub symbol: String,
+    /// token id -> owner
+    pub owners: BTreeMap<String, String>,
+    /// token id -> approved address
+    pub approvals: BTreeMap<String, String>,
+    /// owner -> operators
+    pub operators: BTreeMap<String, BTreeSet<String>>,
+    /// token id -> token URI
+    pub uris: BTreeMap<String, String>,
+    /// Royalty of the tokens without their own royalty
+    pub default_royalty: Option<MockRoyalty>,
+    /// token id -> royalty
+    pub royalties: BTreeMap<String, MockRoyalty>,
+}
+
+/// Mock ERC-1155 contract state, also implementing ERC-2981 royalties. Holders, operators and
+/// royalty receivers can be given as Sei or EVM addresses and are stored by their EVM address
+/// once registered with `SeiModule::register_erc1155`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct MockErc1155 {
+    /// token id -> holder -> balance
+    pub balances: BTreeMap<String, BTreeMap<String, Uint128>>,
+    /// owner -> operators
+    pub operators: BTreeMap<String, BTreeSet<String>>,
+    /// URI of the token types without their own URI
+    pub uri: String,
+    /// token id -> token URI
+    pub uris: BTreeMap<String, String>,
+    /// Royalty of the token types without their own royalty
+    pub default_royalty: Option<MockRoyalty>,
+    /// token id -> royalty
+    pub royalties: BTreeMap<String, MockRoyalty>,
+}
+
+/// ERC-2981 royalty of a mock token, paid to `receiver` as a fraction of the sale price
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct MockRoyalty {
+    pub receiver: String,
+    /// Royalty fraction of the sale price, in units of 0.01%
+    pub basis_points: u16,
+}
+
+impl MockRoyalty {
+    pub fn new(receiver: impl Into<String>, basis_points: u16) -> Self {
+        MockRoyalty {
+            receiver: receiver.into(),
+            basis_points,
+        }
+    }
+
+    fn resolve_address(
+        self,
+        storage: &mut dyn Storage,
+        associations: &AddressAssociations,
+    ) -> Self {
+        MockRoyalty {
+            receiver: record_evm_address(storage, associations, &self.receiver),
+            ..self
+        }
+    }
+}
+
+// Returns the receiver of the token royalty, or else of the default royalty, and the royalty
+// amount owed for `sale_price`. The receiver is the zero address if there is no royalty.
+fn royalty_info(
+    royalties: &BTreeMap<String, MockRoyalty>,
+    default_royalty: &Option<MockRoyalty>,
+    token_id: &str,
+    sale_price: Uint128,
+) -> (String, Uint128) {
+    match royalties.get(token_id).or(default_royalty.as_ref()) {
+        Some(royalty) => (
+            royalty.receiver.clone(),
+            sale_price.multiply_ratio(royalty.basis_points, 10_000u128),
+        ),
+        None => (ZERO_ADDRESS.to_string(), Uint128::zero()),
+    }
+}
+
+fn resolve_royalties(
+    storage: &mut dyn Storage,
+    associations: &AddressAssociations,
+    royalties: BTreeMap<String, MockRoyalty>,
+) -> BTreeMap<String, MockRoyalty> {
+    royalties
+        .into_iter()
+        .map(|(token_id, royalty)| (token_id, royalty.resolve_address(storage, associations)))
+        .collect()
+}
+
+impl MockErc20 {
+    pub fn new(name: impl Into<String>, symbol: impl Into<String>, decimals: u8) -> Self {
+        MockErc20 {
+            name: name.into(),
+            symbol: symbol.into(),
+            decimals,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the balance of `holder`, adjusting the total supply
+    pub fn with_balance(mut self, holder: impl Into<String>, amount: impl Into<Uint128>) -> Self {
+        let amount = amount.into();
+        if let Some(previous) = self.balances.insert(holder.into(), amount) {
+            self.total_supply -= previous;
+        }
+        self.total_supply += amount;
+        self
+    }
+
+    pub fn with_allowance(
+        mut self,
+        owner: impl Into<String>,
+        spender: impl Into<String>,
+        amount: impl Into<Uint128>,
+    ) -> Self {
+        self.allowances
+            .entry(owner.into())
+            .or_default()
+            .insert(spender.into(), amount.into());
+        self
+    }
+
+    pub fn balance_of(&self, holder: &str) -> Uint128 {
+        self.balances.get(holder).copied().unwrap_or_default()
+    }
+
+    pub fn allowance(&self, owner: &str, spender: &str) -> Uint128 {
+        self.allowances
+            .get(owner)
+            .and_then(|spenders| spenders.get(spender))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn resolve_addresses(
+        self,
+        storage: &mut dyn Storage,
+        associations: &AddressAssociations,
+    ) -> Self {
+        MockErc20 {
+            balances: self
+                .balances
+                .into_iter()
+                .map(|(holder, amount)| {
+                    (record_evm_address(storage, associations, &holder), amount)
+                })
+                .collect(),
+            allowances: self
+                .allowances
+                .into_iter()
+                .map(|(owner, spenders)| {
+                    let spenders = spenders
+                        .into_iter()
+                        .map(|(spender, amount)| {
+                            (record_evm_address(storage, associations, &spender), amount)
+                        })
+                        .collect();
+                    (record_evm_address(storage, associations, &owner), spenders)
+                })
+                .collect(),
+            ..self
+        }
+    }
+
+    fn transfer(&mut self, from: &str, to: &str, amount: Uint128) -> AnyResult<()> {
+        let balance = self.balance_of(from);
+        if balance < amount {
+            bail!(revert("ERC20: transfer amount exceeds balance"));
+        }
+        self.balances.insert(from.to_string(), balance - amount);
+        let to_balance = self.balance_of(to);
+        self.balances.insert(to.to_string(), to_balance + amount);
+        Ok(())
+    }
+
+    fn execute(&mut self, caller: &str, selector: &[u8], args: &[u8]) -> AnyResult<Vec<u8>> {
+        if selector == function_selector("transfer(address,uint256)") {
+            let args = abi_decode(&[AbiType::Address, AbiType::Uint(256)], args)?;
+            self.transfer(caller, &address_arg(&args[0]), amount_arg(&args[1])?)?;
+        } else if selector == function_selector("approve(address,uint256)") {
+            let args = abi_decode(&[AbiType::Address, AbiType::Uint(256)], args)?;
+            self.allowances
+                .entry(caller.to_string())
+                .or_default()
+                .insert(address_arg(&args[0]), amount_arg(&args[1])?);
+        } else if selector == function_selector("transferFrom(address,address,uint256)") {
+            let args = abi_decode(
+                &[AbiType::Address, AbiType::Address, AbiType::Uint(256)],
+                args,
+            )?;
+            let owner = address_arg(&args[0]);
+            let amount = amount_arg(&args[2])?;
+            let allowance = self.allowance(&owner, caller);
+            if allowance < amount {
+                bail!(revert("ERC20: insufficient allowance"));
+            }
+            self.transfer(&owner, &address_arg(&args[1]), amount)?;
+            self.allowances
+                .entry(owner)
+                .or_default()
+                .insert(caller.to_string(), allowance - amount);
+        } else {
+            bail!(revert("unknown ERC20 function selector"));
+        }
+        Ok(abi_encode(&[AbiToken::Bool(true)])?)
+    }
+
+    fn query(&self, selector: &[u8], args: &[u8]) -> AnyResult<Vec<u8>> {
+        let token = if selector == function_selector("balanceOf(address)") {
+            let args = abi_decode(&[AbiType::Address], args)?;
+            uint_token(self.balance_of(&address_arg(&args[0])))
+        } else if selector == function_selector("allowance(address,address)") {
+            let args = abi_decode(&[AbiType::Address, AbiType::Address], args)?;
+            uint_token(self.allowance(&address_arg(&args[0]), &address_arg(&args[1])))
+        } else if selector == function_selector("totalSupply()") {
+            uint_token(self.total_supply)
+        } else if selector == function_selector("name()") {
+            AbiToken::string(self.name.clone())
+        } else if selector == function_selector("symbol()") {
+            AbiToken::string(self.symbol.clone())
+        } else if selector == function_selector("decimals()") {
+            AbiToken::uint(self.decimals as u128)
+        } else {
+            bail!(revert("unknown ERC20 function selector"));
+        };
+        Ok(abi_encode(&[token])?)
+    }
+}
+
+impl MockErc721 {
+    pub fn new(name: impl Into<String>, symbol: impl Into<String>) -> Self {
+        MockErc721 {
+            name: name.into(),
+            symbol: symbol.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Mints `token_id` to `owner` with the given token URI
+    pub fn with_token(
+        mut self,
+        token_id: impl Into<String>,
+        owner: impl Into<String>,
+        uri: impl Into<String>,
+    ) -> Self {
+        let token_id = token_id.into();
+        self.owners.insert(token_id.clone(), owner.into());
+        self.uris.insert(token_id, uri.into());
+        self
+    }
+
+    pub fn with_operator(mut self, owner: impl Into<String>, operator: impl Into<String>) -> Self {
+        self.operators
+            .entry(owner.into())
+            .or_default()
+            .insert(operator.into());
+        self
+    }
+
+    pub fn with_default_royalty(mut self, royalty: MockRoyalty) -> Self {
+        self.default_royalty = Some(royalty);
+        self
+    }
+
+    pub fn with_royalty(mut self, token_id: impl Into<String>, royalty: MockRoyalty) -> Self {
+        self.royalties.insert(token_id.into(), royalty);
+        self
+    }
+
+    pub fn is_approved_for_all(&self, owner: &str, operator: &str) -> bool {
+        self.operators
+            .get(owner)
+            .map(|operators| operators.contains(operator))
+            .unwrap_or(false)
+    }
+
+    pub fn balance_of(&self, owner: &str) -> Uint128 {
+        Uint128::new(self.owners.values().filter(|o| *o == owner).count() as u128)
+    }
+
+    /// Returns the token ids of `owner`, or of all tokens, in enumeration order
+    pub fn tokens(&self, owner: Option<&str>) -> Vec<String> {
+        let mut tokens: Vec<(Uint256, &String)> = self
+            .owners
+            .iter()
+            .filter(|(_, o)| owner.map_or(true, |owner| *o == owner))
+            .map(|(token_id, _)| (token_id.parse().unwrap_or_default(), token_id))
+            .collect();
+        tokens.sort();
+        tokens
+            .into_iter()
+            .map(|(_, token_id)| token_id.clone())
+            .collect()
+    }
+
+    /// Returns the royalty receiver, the zero address if there is no royalty, and the royalty
+    /// amount owed for `sale_price`
+    pub fn royalty_info(&self, token_id: &str, sale_price: Uint128) -> (String, Uint128) {
+        royalty_info(&self.royalties, &self.default_royalty, token_id, sale_price)
+    }
+
+    fn token_by_index(&self, index: u64) -> AnyResult<String> {
+        self.tokens(None)
+            .get(index as usize)
+            .cloned()
+            .ok_or_else(|| revert("ERC721Enumerable: global index out of bounds"))
+    }
+
+    fn token_of_owner_by_index(&self, owner: &str, index: u64) -> AnyResult<String> {
+        self.tokens(Some(owner))
+            .get(index as usize)
+            .cloned()
+            .ok_or_else(|| revert("ERC721Enumerable: owner index out of bounds"))
+    }
+
+    fn owner_of(&self, token_id: &str) -> AnyResult<String> {
+        self.owners
+            .get(token_id)
+            .cloned()
+            .ok_or_else(|| revert("ERC721: invalid token ID"))
+    }
+
+    fn resolve_addresses(
+        self,
+        storage: &mut dyn Storage,
+        associations: &AddressAssociations,
+    ) -> Self {
+        let mut resolve = |map: BTreeMap<String, String>| -> BTreeMap<String, String> {
+            map.into_iter()
+                .map(|(token_id, address)| {
+                    (
+                        token_id,
+                        record_evm_address(storage, associations, &address),
+                    )
+                })
+                .collect()
+        };
+        let owners = resolve(self.owners);
+        let approvals = resolve(self.approvals);
+        let operators = self
+            .operators
+            .into_iter()
+            .map(|(owner, operators)| {
+                let operators = operators
+                    .iter()
+                    .map(|operator| record_evm_address(storage, associations, operator))
+                    .collect();
+                (record_evm_address(storage, associations, &owner), operators)
+            })
+            .collect();
+        let default_royalty = self
+            .default_royalty
+            .map(|royalty| royalty.resolve_address(storage, associations));
+        let royalties = resolve_royalties(storage, associations, self.royalties);
+        MockErc721 {
+            owners,
+            approvals,
+            operators,
+            default_royalty,
+            royalties,
+            ..self
+        }
+    }
+
+    fn execute(&mut self, caller: &str, selector: &[u8], args: &[u8]) -> AnyResult<Vec<u8>> {
+        if selector == function_selector("transferFrom(address,address,uint256)")
+            || selector == function_selector("safeTransferFrom(address,address,uint256)")
+        {
+            let args = abi_decode(
+                &[AbiType::Address, AbiType::Address, AbiType::Uint(256)],
+                args,
+            )?;
+            let token_id = token_id_arg(&args[2]);
+            let owner = self.owner_of(&token_id)?;
+            if owner != address_arg(&args[0]) {
+                bail!(revert("ERC721: transfer from incorrect owner"));
+            }
+            let approved = self.approvals.get(&token_id).map(String::as_str) == Some(caller);
+            if caller != owner && !approved && !self.is_approved_for_all(&owner, caller) {
+                bail!(revert("ERC721: caller is not token owner or approved"));
+            }
+            self.approvals.remove(&token_id);
+            self.owners.insert(token_id, address_arg(&args[1]));
+        } else if selector == function_selector("approve(address,uint256)") {
+            let args = abi_decode(&[AbiType::Address, AbiType::Uint(256)], args)?;
+            let token_id = token_id_arg(&args[1]);
+            let owner = self.owner_of(&token_id)?;
+            if caller != owner && !self.is_approved_for_all(&owner, caller) {
+                bail!(revert(
+                    "ERC721: approve caller is not token owner or approved for all"
+                ));
+            }
+            self.approvals.insert(token_id, address_arg(&args[0]));
+        } else if selector == function_selector("setApprovalForAll(address,bool)") {
+            let args = abi_decode(&[AbiType::Address, AbiType::Bool], args)?;
+            let operators = self.operators.entry(caller.to_string()).or_default();
+            if args[1].clone().into_bool().unwrap_or_default() {
+                operators.insert(address_arg(&args[0]));
+            } else {
+                operators.remove(&address_arg(&args[0]));
+            }
+        } else {
+            bail!(revert("unknown ERC721 function selector"));
+        }
+        Ok(vec![])
+    }
+
+    fn query(&self, selector: &[u8], args: &[u8]) -> AnyResult<Vec<u8>> {
+        let token = if selector == function_selector("ownerOf(uint256)") {
+            let args = abi_decode(&[AbiType::Uint(256)], args)?;
+            AbiToken::address(&self.owner_of(&token_id_arg(&args[0]))?)?
+        } else if selector == function_selector("getApproved(uint256)") {
+            let args = abi_decode(&[AbiType::Uint(256)], args)?;
+            let token_id = token_id_arg(&args[0]);
+            self.owner_of(&token_id)?;
+            AbiToken::address(self.approvals.get(&token_id).map_or(ZERO_ADDRESS, |a| a))?
+        } else if selector == function_selector("isApprovedForAll(address,address)") {
+            let args = abi_decode(&[AbiType::Address, AbiType::Address], args)?;
+            AbiToken::Bool(self.is_approved_for_all(&address_arg(&args[0]), &address_arg(&args[1])))
+        } else if selector == function_selector("balanceOf(address)") {
+            let args = abi_decode(&[AbiType::Address], args)?;
+            uint_token(self.balance_of(&address_arg(&args[0])))
+        } else if selector == function_selector("totalSupply()") {
+            AbiToken::uint(self.owners.len() as u128)
+        } else if selector == function_selector("tokenByIndex(uint256)") {
+            let args = abi_decode(&[AbiType::Uint(256)], args)?;
+            token_id_token(&self.token_by_index(index_arg(&args[0]))?)?
+        } else if selector == function_selector("tokenOfOwnerByIndex(address,uint256)") {
+            let args = abi_decode(&[AbiType::Address, AbiType::Uint(256)], args)?;
+            let token_id =
+                self.token_of_owner_by_index(&address_arg(&args[0]), index_arg(&args[1]))?;
+            token_id_token(&token_id)?
+        } else if selector == function_selector("royaltyInfo(uint256,uint256)") {
+            let args = abi_decode(&[AbiType::Uint(256), AbiType::Uint(256)], args)?;
+            let (receiver, amount) =
+                self.royalty_info(&token_id_arg(&args[0]), amount_arg(&args[1])?);
+            return Ok(abi_encode(&[
+                AbiToken::address(&receiver)?,
+                uint_token(amount),
+            ])?);
+        } else if selector == function_selector("tokenURI(uint256)") {
+            let args = abi_decode(&[AbiType::Uint(256)], args)?;
+            let token_id = token_id_arg(&args[0]);
+            self.owner_of(&token_id)?;
+            AbiToken::string(self.uris.get(&token_id).cloned().unwrap_or_default())
+        } else if selector == function_selector("name()") {
+            AbiToken::string(self.name.clone())
+        } else if selector == function_selector("symbol()") {
+            AbiToken::string(self.symbol.clone())
+        } else {
+            bail!(revert("unknown ERC721 function selector"));
+        };
+        Ok(abi_encode(&[token])?)
+    }
+}
+
+impl MockErc1155 {
+    /// Creates a contract returning `uri` for the token types without their own URI
+    pub fn new(uri: impl Into<String>) -> Self {
+        MockErc1155 {
+            uri: uri.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the balance of `holder` for the token type `token_id`
+    pub fn with_balance(
+        mut self,
+        token_id: impl Into<String>,
+        holder: impl Into<String>,
+        amount: impl Into<Uint128>,
+    ) -> Self {
+        self.balances
+            .entry(token_id.into())
+            .or_default()
+            .insert(holder.into(), amount.into());
+        self
+    }
+
+    pub fn with_operator(mut self, owner: impl Into<String>, operator: impl Into<String>) -> Self {
+        self.operators
+            .entry(owner.into())
+            .or_default()
+            .insert(operator.into());
+        self
+    }
+
+    pub fn with_uri(mut self, token_id: impl Into<String>, uri: impl Into<String>) -> Self {
+        self.uris.insert(token_id.into(), uri.into());
+        self
+    }
+
+    pub fn with_default_royalty(mut self, royalty: MockRoyalty) -> Self {
+        self.default_royalty = Some(royalty);
+        self
+    }
+
+    pub fn with_royalty(mut self, token_id: impl Into<String>, royalty: MockRoyalty) -> Self {
+        self.royalties.insert(token_id.into(), royalty);
+        self
+    }
+
+    pub fn balance_of(&self, holder: &str, token_id: &str) -> Uint128 {
+        self.balances
+            .get(token_id)
+            .and_then(|holders| holders.get(holder))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn is_approved_for_all(&self, owner: &str, operator: &str) -> bool {
+        self.operators
+            .get(owner)
+            .map(|operators| operators.contains(operator))
+            .unwrap_or(false)
+    }
+
+    pub fn uri(&self, token_id: &str) -> String {
+        self.uris
+            .get(token_id)
+            .cloned()
+            .unwrap_or_else(|| self.uri.clone())
+    }
+
+    /// Returns the royalty receiver, the zero address if there is no royalty, and the royalty
+    /// amount owed for `sale_price`
+    pub fn royalty_info(&self, token_id: &str, sale_price: Uint128) -> (String, Uint128) {
+        royalty_info(&self.royalties, &self.default_royalty, token_id, sale_price)
+    }
+
+    fn resolve_addresses(
+        self,
+        storage: &mut dyn Storage,
+        associations: &AddressAssociations,
+    ) -> Self {
+        let balances = self
+            .balances
+            .into_iter()
+            .map(|(token_id, holders)| {
+                let holders = holders
+                    .into_iter()
+                    .map(|(holder, amount)| {
+                        (record_evm_address(storage, associations, &holder), amount)
+                    })
+                    .collect();
+                (token_id, holders)
+            })
+            .collect();
+        let operators = self
+            .operators
+            .into_iter()
+            .map(|(owner, operators)| {
+                let operators = operators
+                    .iter()
+                    .map(|operator| record_evm_address(storage, associations, operator))
+                    .collect();
+                (record_evm_address(storage, associations, &owner), operators)
+            })
+            .collect();
+        let default_royalty = self
+            .default_royalty
+            .map(|royalty| royalty.resolve_address(storage, associations));
+        let royalties = resolve_royalties(storage, associations, self.royalties);
+        MockErc1155 {
+            balances,
+            operators,
+            default_royalty,
+            royalties,
+            ..self
+        }
+    }
+
+    fn transfer(
+        &mut self,
+        caller: &str,
+        from: &str,
+        to: &str,
+        transfers: &[(String, Uint128)],
+    ) -> AnyResult<()> {
+        if caller != from && !self.is_approved_for_all(from, caller) {
+            bail!(revert("ERC1155: caller is not token owner or approved"));
+        }
+        if to == ZERO_ADDRESS {
+            bail!(revert("ERC1155: transfer to the zero address"));
+        }
+        for (token_id, amount) in transfers {
+            let balance = self.balance_of(from, token_id);
+            if balance < *amount {
+                bail!(revert("ERC1155: insufficient balance for transfer"));
+            }
+            let holders = self.balances.entry(token_id.clone()).or_default();
+            holders.insert(from.to_string(), balance - amount);
+            *holders.entry(to.to_string()).or_default() += *amount;
+        }
+        Ok(())
+    }
+
+    fn execute(&mut self, caller: &str, selector: &[u8], args: &[u8]) -> AnyResult<Vec<u8>> {
+        if selector == function_selector("safeTransferFrom(address,address,uint256,uint256,bytes)")
+        {
+            let args = abi_decode(
+                &[
+                    AbiType::Address,
+                    AbiType::Address,
+                    AbiType::Uint(256),
+                    AbiType::Uint(256),
+                    AbiType::Bytes,
+                ],
+                args,
+            )?;
+            let transfer = (token_id_arg(&args[2]), amount_arg(&args[3])?);
+            self.transfer(
+                caller,
+                &address_arg(&args[0]),
+                &address_arg(&args[1]),
+                &[transfer],
+            )?;
+        } else if selector
+            == function_selector("safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)")
+        {
+            let uint_array = AbiType::Array(Box::new(AbiType::Uint(256)));
+            let args = abi_decode(
+                &[
+                    AbiType::Address,
+                    AbiType::Address,
+                    uint_array.clone(),
+                    uint_array,
+                    AbiType::Bytes,
+                ],
+                args,
+            )?;
+            let token_ids = array_arg(&args[2]);
+            let amounts = array_arg(&args[3]);
+            if token_ids.len() != amounts.len() {
+                bail!(revert("ERC1155: ids and amounts length mismatch"));
+            }
+            let transfers = token_ids
+                .iter()
+                .zip(&amounts)
+                .map(|(token_id, amount)| Ok((token_id_arg(token_id), amount_arg(amount)?)))
+                .collect::<AnyResult<Vec<_>>>()?;
+            self.transfer(
+                caller,
+                &address_arg(&args[0]),
+                &address_arg(&args[1]),
+                &transfers,
+            )?;
+        } else if selector == function_selector("setApprovalForAll(address,bool)") {
+            let args = abi_decode(&[AbiType::Address, AbiType::Bool], args)?;
+            let operators = self.operators.entry(caller.to_string()).or_default();
+            if args[1].clone().into_bool().unwrap_or_default() {
+                operators.insert(address_arg(&args[0]));
+            } else {
+                operators.remove(&address_arg(&args[0]));
+            }
+        } else {
+            bail!(revert("unknown ERC1155 function selector"));
+        }
+        Ok(vec![])
+    }
+
+    fn query(&self, selector: &[u8], args: &[u8]) -> AnyResult<Vec<u8>> {
+        let tokens = if selector == function_selector("balanceOf(address,uint256)") {
+            let args = abi_decode(&[AbiType::Address, AbiType::Uint(256)], args)?;
+            vec![uint_token(
+                self.balance_of(&address_arg(&args[0]), &token_id_arg(&args[1])),
+            )]
+        } else if selector == function_selector("balanceOfBatch(address[],uint256[])") {
+            let args = abi_decode(
+                &[
+                    AbiType::Array(Box::new(AbiType::Address)),
+                    AbiType::Array(Box::new(AbiType::Uint(256))),
+                ],
+                args,
+            )?;
+            let accounts = array_arg(&args[0]);
+            let token_ids = array_arg(&args[1]);
+            if accounts.len() != token_ids.len() {
+                bail!(revert("ERC1155: accounts and ids length mismatch"));
+            }
+            let balances = accounts
+                .iter()
+                .zip(&token_ids)
+                .map(|(account, token_id)| {
+                    uint_token(self.balance_of(&address_arg(account), &token_id_arg(token_id)))
+                })
+                .collect();
+            vec![AbiToken::Array(balances)]
+        } else if selector == function_selector("isApprovedForAll(address,address)") {
+            let args = abi_decode(&[AbiType::Address, AbiType::Address], args)?;
+            vec![AbiToken::Bool(self.is_approved_for_all(
+                &address_arg(&args[0]),
+                &address_arg(&args[1]),
+            ))]
+        } else if selector == function_selector("uri(uint256)") {
+            let args = abi_decode(&[AbiType::Uint(256)], args)?;
+            vec![AbiToken::string(self.uri(&token_id_arg(&args[0])))]
+        } else if selector == function_selector("royaltyInfo(uint256,uint256)") {
+            let args = abi_decode(&[AbiType::Uint(256), AbiType::Uint(256)], args)?;
+            let (receiver, amount) =
+                self.royalty_info(&token_id_arg(&args[0]), amount_arg(&args[1])?);
+            vec![AbiToken::address(&receiver)?, uint_token(amount)]
+        } else {
+            bail!(revert("unknown ERC1155 function selector"));
+        };
+        Ok(abi_encode(&tokens)?)
+    }
+}
+
+// Storage:
+// "EvmErc20-" + EVM address -> MockErc20
+// "EvmErc721-" + EVM address -> MockErc721
+// "EvmErc1155-" + EVM address -> MockErc1155
+// "EvmDerivedAddress-" + EVM address -> Sei address the EVM address was derived from
+
+pub(crate) fn register_erc20(
+    storage: &mut dyn Storage,
+    associations: &AddressAssociations,
+    address: &str,
+    token: MockErc20,
+) -> AnyResult<()> {
+    let address = normalize_evm_address(address)?;
+    let token = token.resolve_addresses(storage, associations);
+    save(storage, &erc20_key(&address), &token)
+}
+
+pub(crate) fn register_erc721(
+    storage: &mut dyn Storage,
+    associations: &AddressAssociations,
+    address: &str,
+    token: MockErc721,
+) -> AnyResult<()> {
+    let address = normalize_evm_address(address)?;
+    let token = token.resolve_addresses(storage, associations);
+    save(storage, &erc721_key(&address), &token)
+}
+
+pub(crate) fn register_erc1155(
+    storage: &mut dyn Storage,
+    associations: &AddressAssociations,
+    address: &str,
+    token: MockErc1155,
+) -> AnyResult<()> {
+    let address = normalize_evm_address(address)?;
+    let token = token.resolve_addresses(storage, associations);
+    save(storage, &erc1155_key(&address), &token)
+}
+
+// Execute: CallEvm() and DelegateCallEvm()
+pub(crate) fn execute_call_evm_helper(
+    storage: &mut dyn Storage,
+    associations: &AddressAssociations,
+    sender: Addr,
+    value: Uint128,
+    to: String,
+    data: String,
+) -> AnyResult<AppResponse> {
+    let calldata = Binary::from_base64(&data)?;
+    if calldata.len() < 4 {
+        bail!(revert("missing function selector"));
+    }
+    let (selector, args) = calldata.split_at(4);
+    let caller = record_evm_address(storage, associations, sender.as_str());
+    let contract = normalize_evm_address(&to)?;
+
+    let output = if let Some(mut token) = load::<MockErc20>(storage, &erc20_key(&contract))? {
+        ensure_non_payable(value)?;
+        let output = token.execute(&caller, selector, args)?;
+        save(storage, &erc20_key(&contract), &token)?;
+        output
+    } else if let Some(mut token) = load::<MockErc721>(storage, &erc721_key(&contract))? {
+        ensure_non_payable(value)?;
+        let output = token.execute(&caller, selector, args)?;
+        save(storage, &erc721_key(&contract), &token)?;
+        output
+    } else if let Some(mut token) = load::<MockErc1155>(storage, &erc1155_key(&contract))? {
+        ensure_non_payable(value)?;
+        let output = token.execute(&caller, selector, args)?;
+        save(storage, &erc1155_key(&contract), &token)?;
+        output
+    } else {
+        bail!(revert(format!("no contract deployed at {}", to)));
+    };
+
+    Ok(AppResponse {
+        events: vec![],
+        data: Some(Binary::from(
+            MsgInternalEVMCallResponse { data: output }.encode(),
+        )),
+    })
+}
+
+// Query: StaticCall(), Erc20*(), Erc721*() and Erc1155*()
+pub(crate) fn query_evm_helper(
+    storage: &dyn Storage,
+    associations: &AddressAssociations,
+    query: SeiQuery,
+) -> AnyResult<Binary> {
+    match query {
+        SeiQuery::StaticCall { to, data, .. } => {
+            let contract = normalize_evm_address(&to)?;
+            let calldata = Binary::from_base64(&data)?;
+            let output = if let Some(token) = load::<MockErc20>(storage, &erc20_key(&contract))? {
+                let (selector, args) = split_selector(&calldata)?;
+                token.query(selector, args)?
+            } else if let Some(token) = load::<MockErc721>(storage, &erc721_key(&contract))? {
+                let (selector, args) = split_selector(&calldata)?;
+                token.query(selector, args)?
+            } else if let Some(token) = load::<MockErc1155>(storage, &erc1155_key(&contract))? {
+                let (selector, args) = split_selector(&calldata)?;
+                token.query(selector, args)?
+            } else {
+                bail!(revert(format!("no contract deployed at {}", to)));
+            };
+            Ok(to_json_binary(&StaticCallResponse {
+                encoded_data: Binary::from(output).to_base64(),
+            })?)
+        }
+        SeiQuery::Erc20TransferPayload { recipient, amount } => payload_response(
+            "transfer(address,uint256)",
+            &[
+                evm_address_token(storage, associations, &recipient)?,
+                uint_token(amount),
+            ],
+        ),
+        SeiQuery::Erc20TransferFromPayload {
+            owner,
+            recipient,
+            amount,
+        } => payload_response(
+            "transferFrom(address,address,uint256)",
+            &[
+                evm_address_token(storage, associations, &owner)?,
+                evm_address_token(storage, associations, &recipient)?,
+                uint_token(amount),
+            ],
+        ),
+        SeiQuery::Erc20ApprovePayload { spender, amount } => payload_response(
+            "approve(address,uint256)",
+            &[
+                evm_address_token(storage, associations, &spender)?,
+                uint_token(amount),
+            ],
+        ),
+        SeiQuery::Erc20Allowance {
+            contract_address,
+            owner,
+            spender,
+        } => {
+            let token = load_erc20(storage, &contract_address)?;
+            Ok(to_json_binary(&Erc20AllowanceResponse {
+                allowance: token.allowance(
+                    &lookup_evm_address(storage, associations, &owner),
+                    &lookup_evm_address(storage, associations, &spender),
+                ),
+            })?)
+        }
+        SeiQuery::Erc20TokenInfo {
+            contract_address, ..
+        } => {
+            let token = load_erc20(storage, &contract_address)?;
+            Ok(to_json_binary(&TokenInfoResponse {
+                name: token.name,
+                symbol: token.symbol,
+                decimals: token.decimals,
+                total_supply: token.total_supply,
+            })?)
+        }
+        SeiQuery::Erc20Balance {
+            contract_address,
+            account,
+        } => {
+            let token = load_erc20(storage, &contract_address)?;
+            Ok(to_json_binary(&BalanceResponse {
+                balance: token.balance_of(&lookup_evm_address(storage, associations, &account)),
+            })?)
+        }
+        SeiQuery::Erc721TransferPayload {
+            from,
+            recipient,
+            token_id,
+        } => payload_response(
+            "transferFrom(address,address,uint256)",
+            &[
+                evm_address_token(storage, associations, &from)?,
+                evm_address_token(storage, associations, &recipient)?,
+                token_id_token(&token_id)?,
+            ],
+        ),
+        SeiQuery::Erc721ApprovePayload { spender, token_id } => payload_response(
+            "approve(address,uint256)",
+            &[
+                evm_address_token(storage, associations, &spender)?,
+                token_id_token(&token_id)?,
+            ],
+        ),
+        SeiQuery::Erc721SetApprovalAllPayload { to, approved } => payload_response(
+            "setApprovalForAll(address,bool)",
+            &[
+                evm_address_token(storage, associations, &to)?,
+                AbiToken::Bool(approved),
+            ],
+        ),
+        SeiQuery::Erc721Owner {
+            contract_address,
+            token_id,
+            ..
+        } => {
+            let token = load_erc721(storage, &contract_address)?;
+            let owner = token.owner_of(&token_id)?;
+            Ok(to_json_binary(&Erc721OwnerResponse {
+                owner: sei_address_for(storage, associations, &owner),
+            })?)
+        }
+        SeiQuery::Erc721Approved {
+            contract_address,
+            token_id,
+            ..
+        } => {
+            let token = load_erc721(storage, &contract_address)?;
+            token.owner_of(&token_id)?;
+            let approved = match token.approvals.get(&token_id) {
+                Some(approved) => sei_address_for(storage, associations, approved),
+                None => ZERO_ADDRESS.to_string(),
+            };
+            Ok(to_json_binary(&Erc721ApprovedResponse { approved })?)
+        }
+        SeiQuery::Erc721IsApprovedForAll {
+            contract_address,
+            owner,
+            operator,
+            ..
+        } => {
+            let token = load_erc721(storage, &contract_address)?;
+            Ok(to_json_binary(&Erc721IsApprovedForAllResponse {
+                is_approved: token.is_approved_for_all(
+                    &lookup_evm_address(storage, associations, &owner),
+                    &lookup_evm_address(storage, associations, &operator),
+                ),
+            })?)
+        }
+        SeiQuery::Erc721NameSymbol {
+            contract_address, ..
+        } => {
+            let token = load_erc721(storage, &contract_address)?;
+            Ok(to_json_binary(&Erc721NameSymbolResponse {
+                name: token.name,
+                symbol: token.symbol,
+            })?)
+        }
+        SeiQuery::Erc721Uri {
+            contract_address,
+            token_id,
+            ..
+        } => {
+            let token = load_erc721(storage, &contract_address)?;
+            token.owner_of(&token_id)?;
+            Ok(to_json_binary(&Erc721UriResponse {
+                uri: token.uris.get(&token_id).cloned().unwrap_or_default(),
+            })?)
+        }
+        SeiQuery::Erc721TotalSupply {
+            contract_address, ..
+        } => {
+            let token = load_erc721(storage, &contract_address)?;
+            Ok(to_json_binary(&Erc721TotalSupplyResponse {
+                supply: Uint128::new(token.owners.len() as u128),
+            })?)
+        }
+        SeiQuery::Erc721TokenByIndex {
+            contract_address,
+            index,
+            ..
+        } => {
+            let token = load_erc721(storage, &contract_address)?;
+            Ok(to_json_binary(&Erc721TokenByIndexResponse {
+                token_id: token.token_by_index(index)?,
+            })?)
+        }
+        SeiQuery::Erc721TokenOfOwnerByIndex {
+            contract_address,
+            owner,
+            index,
+            ..
+        } => {
+            let token = load_erc721(storage, &contract_address)?;
+            let owner = lookup_evm_address(storage, associations, &owner);
+            Ok(to_json_binary(&Erc721TokenOfOwnerByIndexResponse {
+                token_id: token.token_of_owner_by_index(&owner, index)?,
+            })?)
+        }
+        SeiQuery::Erc721BalanceOf {
+            contract_address,
+            owner,
+            ..
+        } => {
+            let token = load_erc721(storage, &contract_address)?;
+            Ok(to_json_binary(&Erc721BalanceOfResponse {
+                balance: token.balance_of(&lookup_evm_address(storage, associations, &owner)),
+            })?)
+        }
+        SeiQuery::Erc721RoyaltyInfo {
+            contract_address,
+            token_id,
+            sale_price,
+            ..
+        } => {
+            let token = load_erc721(storage, &contract_address)?;
+            let (receiver, royalty_amount) = token.royalty_info(&token_id, sale_price);
+            Ok(to_json_binary(&Erc721RoyaltyInfoResponse {
+                receiver: sei_address_for(storage, associations, &receiver),
+                royalty_amount,
+            })?)
+        }
+        SeiQuery::Erc1155TransferPayload {
+            from,
+            recipient,
+            token_id,
+            amount,
+        } => payload_response(
+            "safeTransferFrom(address,address,uint256,uint256,bytes)",
+            &[
+                evm_address_token(storage, associations, &from)?,
+                evm_address_token(storage, associations, &recipient)?,
+                token_id_token(&token_id)?,
+                uint_token(amount),
+                AbiToken::Bytes(vec![]),
+            ],
+        ),
+        SeiQuery::Erc1155BatchTransferPayload {
+            from,
+            recipient,
+            token_ids,
+            amounts,
+        } => payload_response(
+            "safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)",
+            &[
+                evm_address_token(storage, associations, &from)?,
+                evm_address_token(storage, associations, &recipient)?,
+                AbiToken::Array(
+                    token_ids
+                        .iter()
+                        .map(|token_id| token_id_token(token_id))
+                        .collect::<AnyResult<_>>()?,
+                ),
+                AbiToken::Array(amounts.into_iter().map(uint_token).collect()),
+                AbiToken::Bytes(vec![]),
+            ],
+        ),
+        SeiQuery::Erc1155SetApprovalAllPayload { to, approved } => payload_response(
+            "setApprovalForAll(address,bool)",
+            &[
+                evm_address_token(storage, associations, &to)?,
+                AbiToken::Bool(approved),
+            ],
+        ),
+        SeiQuery::Erc1155BalanceOf {
+            contract_address,
+            account,
+            token_id,
+            ..
+        } => {
+            let token = load_erc1155(storage, &contract_address)?;
+            Ok(to_json_binary(&Erc1155BalanceOfResponse {
+                balance: token.balance_of(
+                    &lookup_evm_address(storage, associations, &account),
+                    &token_id,
+                ),
+            })?)
+        }
+        SeiQuery::Erc1155BalanceOfBatch {
+            contract_address,
+            accounts,
+            token_ids,
+            ..
+        } => {
+            let token = load_erc1155(storage, &contract_address)?;
+            if accounts.len() != token_ids.len() {
+                bail!(revert("ERC1155: accounts and ids length mismatch"));
+            }
+            let balances = accounts
+                .iter()
+                .zip(&token_ids)
+                .map(|(account, token_id)| {
+                    token.balance_of(
+                        &lookup_evm_address(storage, associations, account),
+                        token_id,
+                    )
+                })
+                .collect();
+            Ok(to_json_binary(&Erc1155BalanceOfBatchResponse { balances })?)
+        }
+        SeiQuery::Erc1155IsApprovedForAll {
+            contract_address,
+            owner,
+            operator,
+            ..
+        } => {
+            let token = load_erc1155(storage, &contract_address)?;
+            Ok(to_json_binary(&Erc1155IsApprovedForAllResponse {
+                is_approved: token.is_approved_for_all(
+                    &lookup_evm_address(storage, associations, &owner),
+                    &lookup_evm_address(storage, associations, &operator),
+                ),
+            })?)
+        }
+        SeiQuery::Erc1155Uri {
+            contract_address,
+            token_id,
+            ..
+        } => {
+            let token = load_erc1155(storage, &contract_address)?;
+            Ok(to_json_binary(&Erc1155UriResponse {
+                uri: token.uri(&token_id),
+            })?)
+        }
+        SeiQuery::Erc1155RoyaltyInfo {
+            contract_address,
+            token_id,
+            sale_price,
+            ..
+        } => {
+            let token = load_erc1155(storage, &contract_address)?;
+            let (receiver, royalty_amount) = token.royalty_info(&token_id, sale_price);
+            Ok(to_json_binary(&Erc1155RoyaltyInfoResponse {
+                receiver: sei_address_for(storage, associations, &receiver),
+                royalty_amount,
+            })?)
+        }
+        _ => bail!("Unexpected EVM query msg"),
+    }
+}
+
+/// Returns the lowercase EVM address the mock EVM uses for a Sei address that has no
+/// association (e.g. contract addresses).
+///
+/// Addresses of up to 20 bytes (e.g. `MockApi` addresses like `alice` or `contract0`) are zero
+/// padded so they can be mapped back, longer ones are derived from their keccak256 hash.
+pub fn evm_address_for(address: &str) -> String {
+    if let Ok(address) = normalize_evm_address(address) {
+        return address;
+    }
+    let mut bytes = [0u8; 20];
+    if address.len() <= bytes.len() {
+        bytes[..address.len()].copy_from_slice(address.as_bytes());
+    } else {
+        bytes.copy_from_slice(&keccak256(address.as_bytes())[12..]);
+    }
+    to_checksum_address(&bytes).to_lowercase()
+}
+
+// Returns the associated EVM address of a Sei address, falling back to `evm_address_for`
+fn lookup_evm_address(
+    storage: &dyn Storage,
+    associations: &AddressAssociations,
+    address: &str,
+) -> String {
+    if let Ok(address) = normalize_evm_address(address) {
+        return address;
+    }
+    match associations.evm_address(storage, address) {
+        Some(evm_address) => evm_address.to_lowercase(),
+        None => evm_address_for(address),
+    }
+}
+
+// Returns the Sei address reported for an EVM address, reversing `lookup_evm_address`. Hash
+// derived addresses can only be reversed once the Sei address was seen by the mock EVM, unknown
+// EVM addresses are returned as is.
+fn sei_address_for(
+    storage: &dyn Storage,
+    associations: &AddressAssociations,
+    evm_address: &str,
+) -> String {
+    if let Some(sei_address) = associations.sei_address(storage, evm_address) {
+        return sei_address;
+    }
+    let evm_address = match normalize_evm_address(evm_address) {
+        Ok(address) => address,
+        Err(_) => return evm_address.to_string(),
+    };
+    if let Some(sei_address) = storage.get(derived_address_key(&evm_address).as_bytes()) {
+        return String::from_utf8(sei_address).unwrap_or_default();
+    }
+    let bytes = parse_evm_address(&evm_address).unwrap_or_default();
+    let len = bytes.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+    match std::str::from_utf8(&bytes[..len]) {
+        Ok(padded) if len > 0 && padded.chars().all(|c| c.is_ascii_graphic()) => padded.to_string(),
+        _ => evm_address,
+    }
+}
+
+fn record_evm_address(
+    storage: &mut dyn Storage,
+    associations: &AddressAssociations,
+    address: &str,
+) -> String {
+    let evm_address = lookup_evm_address(storage, associations, address);
+    let derived = normalize_evm_address(address).is_err()
+        && associations.evm_address(storage, address).is_none();
+    if derived && address.len() > 20 {
+        storage.set(
+            derived_address_key(&evm_address).as_bytes(),
+            address.as_bytes(),
+        );
+    }
+    evm_address
+}
+
+fn normalize_evm_address(address: &str) -> AnyResult<String> {
+    if !address.starts_with("0x") {
+        bail!("invalid EVM address {}", address);
+    }
+    let bytes = parse_evm_address(address)?;
+    Ok(to_checksum_address(&bytes).to_lowercase())
+}
+
+fn load_erc20(storage: &dyn Storage, contract_address: &str) -> AnyResult<MockErc20> {
+    let key = erc20_key(&normalize_evm_address(contract_address)?);
+    load(storage, &key)?.ok_or_else(|| anyhow!("no ERC20 contract at {}", contract_address))
+}
+
+fn load_erc721(storage: &dyn Storage, contract_address: &str) -> AnyResult<MockErc721> {
+    let key = erc721_key(&normalize_evm_address(contract_address)?);
+    load(storage, &key)?.ok_or_else(|| anyhow!("no ERC721 contract at {}", contract_address))
+}
+
+fn load_erc1155(storage: &dyn Storage, contract_address: &str) -> AnyResult<MockErc1155> {
+    let key = erc1155_key(&normalize_evm_address(contract_address)?);
+    load(storage, &key)?.ok_or_else(|| anyhow!("no ERC1155 contract at {}", contract_address))
+}
+
+fn load<T: DeserializeOwned>(storage: &dyn Storage, key: &str) -> AnyResult<Option<T>> {
+    match storage.get(key.as_bytes()) {
+        Some(value) => Ok(Some(serde_json::from_slice(&value)?)),
+        None => Ok(None),
+    }
+}
+
+fn save<T: Serialize>(storage: &mut dyn Storage, key: &str, value: &T) -> AnyResult<()> {
+    storage.set(key.as_bytes(), &serde_json::to_vec(value)?);
+    Ok(())
+}
+
+fn erc20_key(address: &str) -> String {
+    format!("EvmErc20-{}", address)
+}
+
+fn erc721_key(address: &str) -> String {
+    format!("EvmErc721-{}", address)
+}
+
+fn erc1155_key(address: &str) -> String {
+    format!("EvmErc1155-{}", address)
+}
+
+fn derived_address_key(address: &str) -> String {
+    format!("EvmDerivedAddress-{}", address)
+}
+
+fn split_selector(calldata: &[u8]) -> AnyResult<(&[u8], &[u8])> {
+    if calldata.len() < 4 {
+        bail!(revert("missing function selector"));
+    }
+    Ok(calldata.split_at(4))
+}
+
+fn ensure_non_payable(value: Uint128) -> AnyResult<()> {
+    if !value.is_zero() {
+        bail!(revert("function is not payable"));
+    }
+    Ok(())
+}
+
+fn payload_response(signature: &str, args: &[AbiToken]) -> AnyResult<Binary> {
+    Ok(to_json_binary(&ErcPayloadResponse {
+        encoded_payload: Binary::from(encode_function_call(signature, args)?).to_base64(),
+    })?)
+}
+
+fn evm_address_token(
+    storage: &dyn Storage,
+    associations: &AddressAssociations,
+    address: &str,
+) -> AnyResult<AbiToken> {
+    Ok(AbiToken::address(&lookup_evm_address(
+        storage,
+        associations,
+        address,
+    ))?)
+}
+
+fn uint_token(amount: Uint128) -> AbiToken {
+    AbiToken::uint(amount)
+}
+
+fn token_id_token(token_id: &str) -> AnyResult<AbiToken> {
+    Ok(AbiToken::Uint(token_id.parse::<Uint256>()?))
+}
+
+fn address_arg(token: &AbiToken) -> String {
+    token
+        .clone()
+        .into_address()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+fn token_id_arg(token: &AbiToken) -> String {
+    token.clone().into_uint().unwrap_or_default().to_string()
+}
+
+fn array_arg(token: &AbiToken) -> Vec<AbiToken> {
+    token.clone().into_tokens().unwrap_or_default()
+}
+
+// Indexes past u64::MAX are out of bounds of any mock contract
+fn index_arg(token: &AbiToken) -> u64 {
+    let bytes = token.clone().into_uint().unwrap_or_default().to_be_bytes();
+    if bytes[..24].iter().any(|b| *b != 0) {
+        return u64::MAX;
+    }
+    u64::from_be_bytes(bytes[24..].try_into().unwrap())
+}
+
+fn amount_arg(token: &AbiToken) -> AnyResult<Uint128> {
+    let bytes = token.clone().into_uint().unwrap_or_default().to_be_bytes();
+    if bytes[..16].iter().any(|b| *b != 0) {
+        bail!(revert("amount overflows uint128"));
+    }
+    Ok(Uint128::new(u128::from_be_bytes(
+        bytes[16..].try_into().unwrap(),
+    )))
+}
+
+fn revert(reason: impl Into<String>) -> anyhow::Error {
+    SeiError::EvmRevert {
+        reason: reason.into(),
+    }
+    .into()
+}
@@ -1,23 +1,61 @@
+use anyhow::Result as AnyResult;
 use cosmwasm_std::{
     from_json,
     testing::{MockApi, MockQuerier, MockStorage},
-    Api, BalanceResponse, BankQuery, BlockInfo, Empty, GovMsg, IbcMsg, IbcQuery, MemoryStorage,
-    Storage, Timestamp,
+    Addr, Api, BalanceResponse, BankQuery, BlockInfo, Decimal, Empty, GovMsg, IbcMsg, IbcQuery,
+    QueryRequest, Record, Storage, Timestamp,
 };
 use cw_multi_test::{
-    App, AppBuilder, BankKeeper, DistributionKeeper, FailingModule, Module, Router, StakeKeeper,
+    App, AppBuilder, AppResponse, DistributionKeeper, FailingModule, Module, Router, StakeKeeper,
     WasmKeeper,
 };
-use sei_cosmwasm::{DenomOracleExchangeRatePair, SeiMsg, SeiQueryWrapper};
+use sei_cosmwasm::{
+    format_rfc3339, BulkOrderPlacementsResponse, DenomOracleExchangeRatePair, Epoch, EpochResponse,
+    SeiMsg, SeiQuery, SeiQueryWrapper, SeiRoute, SudoMsg as SeiSudoMsg, UnsuccessfulOrder,
+};
 
+use crate::bank::SeiBank;
 use crate::module::SeiModule;
+use crate::oracle::OracleRateUpdate;
+
+/// App type produced by `mock_app`, wired with the Sei custom module
+pub type SeiApp = App<
+    SeiBank,
+    MockApi,
+    MockStorage,
+    SeiModule,
+    WasmKeeper<SeiMsg, SeiQueryWrapper>,
+    StakeKeeper,
+    DistributionKeeper,
+    FailingModule<IbcMsg, IbcQuery, Empty>,
+    FailingModule<GovMsg, Empty, Empty>,
+>;
+
+/// Router type passed to the `mock_app` init function
+pub type SeiRouter = Router<
+    SeiBank,
+    SeiModule,
+    WasmKeeper<SeiMsg, SeiQueryWrapper>,
+    StakeKeeper,
+    DistributionKeeper,
+    FailingModule<IbcMsg, IbcQuery, Empty>,
+    FailingModule<GovMsg, Empty, Empty>,
+>;
+
+type SeiAppBuilder = AppBuilder<
+    SeiBank,
+    MockApi,
+    MockStorage,
+    SeiModule,
+    WasmKeeper<SeiMsg, SeiQueryWrapper>,
+    StakeKeeper,
+    DistributionKeeper,
+    FailingModule<IbcMsg, IbcQuery, Empty>,
+    FailingModule<GovMsg, Empty, Empty>,
+>;
 
 // Get balance
-pub fn get_balance(
-    app: &App<BankKeeper, MockApi, MemoryStorage, SeiModule, WasmKeeper<SeiMsg, SeiQueryWrapper>>,
-    addr: String,
-    denom: String,
-) -> BalanceResponse {
+pub fn get_balance(app: &SeiApp, addr: String, denom: String) -> BalanceResponse {
     let arr = app.read_module(|router, api, storage| {
         router.bank.query(
             api,
@@ -30,54 +68,20 @@ pub fn get_balance(
             },
             BankQuery::Balance {
                 address: addr,
-                denom: denom,
+                denom,
             },
         )
     });
-    from_json(&arr.unwrap()).unwrap()
+    from_json(arr.unwrap()).unwrap()
 }
 
 // Mock app
-pub fn mock_app<F>(
-    init_fn: F,
-    rates: Vec<DenomOracleExchangeRatePair>,
-) -> App<
-    BankKeeper,
-    MockApi,
-    MockStorage,
-    SeiModule,
-    WasmKeeper<SeiMsg, SeiQueryWrapper>,
-    StakeKeeper,
-    DistributionKeeper,
-    FailingModule<IbcMsg, IbcQuery, Empty>,
-    FailingModule<GovMsg, Empty, Empty>,
->
+pub fn mock_app<F>(init_fn: F, rates: Vec<DenomOracleExchangeRatePair>) -> SeiApp
 where
-    F: FnOnce(
-        &mut Router<
-            BankKeeper,
-            SeiModule,
-            WasmKeeper<SeiMsg, SeiQueryWrapper>,
-            StakeKeeper,
-            DistributionKeeper,
-            FailingModule<IbcMsg, IbcQuery, Empty>,
-            FailingModule<GovMsg, Empty, Empty>,
-        >,
-        &dyn Api,
-        &mut dyn Storage,
-    ),
+    F: FnOnce(&mut SeiRouter, &dyn Api, &mut dyn Storage),
 {
-    let appbuilder: AppBuilder<
-        BankKeeper,
-        MockApi,
-        MockStorage,
-        SeiModule,
-        WasmKeeper<SeiMsg, SeiQueryWrapper>,
-        StakeKeeper,
-        DistributionKeeper,
-        FailingModule<IbcMsg, IbcQuery, Empty>,
-        FailingModule<GovMsg, Empty, Empty>,
-    > = AppBuilder::new()
+    let appbuilder: SeiAppBuilder = AppBuilder::new()
+        .with_bank(SeiBank::new())
         .with_custom(SeiModule::new_with_oracle_exchange_rates(rates))
         .with_wasm::<SeiModule, WasmKeeper<SeiMsg, SeiQueryWrapper>>(WasmKeeper::new())
         .with_staking(StakeKeeper::new())
@@ -85,3 +89,152 @@ where
 
     appbuilder.build(init_fn)
 }
+
+/// Sudo responses of the dex end block hooks, in the order they were called
+#[derive(Clone, Debug, Default)]
+pub struct EndBlockResponse {
+    pub responses: Vec<AppResponse>,
+    /// Orders rejected by the contracts' `BulkOrderPlacements` sudo
+    pub unsuccessful_orders: Vec<(Addr, UnsuccessfulOrder)>,
+}
+
+/// Ends the current block the way the chain's dex end blocker does. For each contract with
+/// activity this block it calls
+/// 1. `SudoMsg::BulkOrderCancellations` with the ids of the orders cancelled this block,
+/// 2. `SudoMsg::BulkOrderPlacements` with the orders placed and the funds deposited this block,
+///    removing the orders listed as unsuccessful in the returned `BulkOrderPlacementsResponse`
+///    from the book,
+///
+/// then matches the order books and sends a `SudoMsg::Settlement` to each contract with fills.
+/// Addresses without a contract are skipped.
+///
+/// The end block is atomic: if a sudo fails, the storage of the app is restored as it was
+/// before the call, keeping the orders, cancellations and escrowed funds of the block.
+pub fn end_block(app: &mut SeiApp) -> AnyResult<EndBlockResponse> {
+    let snapshot: Vec<Record> = app.init_modules(|_, _, storage| {
+        storage
+            .range(None, None, cosmwasm_std::Order::Ascending)
+            .collect()
+    });
+    let res = run_end_block(app);
+    if res.is_err() {
+        app.init_modules(|_, _, storage| {
+            let keys: Vec<Vec<u8>> = storage
+                .range_keys(None, None, cosmwasm_std::Order::Ascending)
+                .collect();
+            for key in keys {
+                storage.remove(&key);
+            }
+            for (key, value) in snapshot {
+                storage.set(&key, &value);
+            }
+        });
+    }
+    res
+}
+
+fn run_end_block(app: &mut SeiApp) -> AnyResult<EndBlockResponse> {
+    let mut res = EndBlockResponse::default();
+
+    let batches =
+        app.init_modules(|router, _, storage| router.custom.take_block_batches(storage))?;
+    for (contract_address, batch) in batches {
+        if app
+            .wrap()
+            .query_wasm_contract_info(contract_address.to_string())
+            .is_err()
+        {
+            continue;
+        }
+
+        if !batch.cancellations.is_empty() {
+            let msg = SeiSudoMsg::BulkOrderCancellations {
+                ids: batch.cancellations,
+            };
+            res.responses
+                .push(app.wasm_sudo(contract_address.clone(), &msg)?);
+        }
+
+        if !batch.orders.is_empty() {
+            let msg = SeiSudoMsg::BulkOrderPlacements {
+                orders: batch.orders.into_iter().map(|(_, order)| order).collect(),
+                deposits: batch.deposits,
+            };
+            let placements = app.wasm_sudo(contract_address.clone(), &msg)?;
+            if let Some(data) = &placements.data {
+                let response: BulkOrderPlacementsResponse = from_json(data)?;
+                let block = app.block_info();
+                app.init_modules(|router, api, storage| {
+                    router.custom.fail_orders(
+                        api,
+                        storage,
+                        router,
+                        &block,
+                        &contract_address,
+                        &response.unsuccessful_orders,
+                    )
+                })?;
+                res.unsuccessful_orders.extend(
+                    response
+                        .unsuccessful_orders
+                        .into_iter()
+                        .map(|order| (contract_address.clone(), order)),
+                );
+            }
+            res.responses.push(placements);
+        }
+    }
+
+    let block = app.block_info();
+    let settlements = app.init_modules(|router, api, storage| {
+        router.custom.end_block(api, storage, router, &block)
+    })?;
+    for (contract_address, settlement) in settlements {
+        if app
+            .wrap()
+            .query_wasm_contract_info(contract_address.to_string())
+            .is_ok()
+        {
+            res.responses
+                .push(app.wasm_sudo(contract_address, &settlement)?);
+        }
+    }
+
+    Ok(res)
+}
+
+/// Ends the current block with `end_block` and moves the app to the next block
+pub fn next_block(app: &mut SeiApp) -> AnyResult<EndBlockResponse> {
+    let res = end_block(app)?;
+    app.update_block(cw_multi_test::next_block);
+    Ok(res)
+}
+
+/// Starts a new epoch at the current block, updating the `SeiModule` epoch with `set_epoch`
+pub fn advance_epoch(app: &mut SeiApp) -> AnyResult<Epoch> {
+    let res: EpochResponse = app.wrap().query(&QueryRequest::Custom(SeiQueryWrapper {
+        route: SeiRoute::Epoch,
+        query_data: SeiQuery::Epoch {},
+    }))?;
+    let block = app.block_info();
+    let epoch = Epoch {
+        current_epoch: res.epoch.current_epoch + 1,
+        current_epoch_start_time: format_rfc3339(block.time),
+        current_epoch_height: block.height as i64,
+        ..res.epoch
+    };
+    app.init_modules(|router, _, _| router.custom = router.custom.set_epoch(epoch.clone()));
+    Ok(epoch)
+}
+
+/// Sets the oracle exchange rate of `denom` to `exchange_rate` at the current block time
+pub fn update_oracle_rate(app: &mut SeiApp, denom: &str, exchange_rate: Decimal) -> AnyResult<()> {
+    let update = OracleRateUpdate::new(app.block_info().time.seconds(), denom, exchange_rate);
+    replay_oracle_rates(app, &[update])
+}
+
+/// Adds a price history, e.g. parsed with `oracle::parse_rate_updates_csv`, to the oracle. The
+/// rates are returned by the oracle queries as the block time reaches their timestamps.
+pub fn replay_oracle_rates(app: &mut SeiApp, updates: &[OracleRateUpdate]) -> AnyResult<()> {
+    app.init_modules(|router, _, storage| router.custom.push_oracle_rates(storage, updates))
+}
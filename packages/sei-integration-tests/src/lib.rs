@@ -1,5 +1,11 @@
 // Exposed for testing only
 // Both unit tests and integration tests are compiled to native code, so everything in here does not need to compile to Wasm.
 #![cfg(not(target_arch = "wasm32"))]
+pub mod address;
+pub mod bank;
+pub mod dex;
+pub mod evm;
 pub mod helper;
 pub mod module;
+pub mod oracle;
+pub mod tokenfactory;
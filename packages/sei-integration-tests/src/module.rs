@@ -1,17 +1,37 @@
-use anyhow::Result as AnyResult;
-use base64::{engine::general_purpose, Engine as _};
+use anyhow::{bail, Result as AnyResult};
 use cosmwasm_std::{
-    from_json, to_json_binary, Addr, Api, BankMsg, Binary, BlockInfo, Coin, CosmosMsg, CustomQuery,
-    Decimal, Querier, Storage, Uint128, Uint64,
+    from_json, to_json_binary, Addr, Api, BalanceResponse, BankMsg, BankQuery, Binary, BlockInfo,
+    Coin, CosmosMsg, CustomQuery, Decimal, Querier, QueryRequest, Storage, SystemError, Uint128,
+    Uint64,
 };
 use cw_multi_test::{AppResponse, BankSudo, CosmosRouter, Module, SudoMsg};
+
+use crate::address::{addresses_from_pubkey, associate_address, AddressAssociations};
+use crate::dex::{
+    add_to_book, creator_registration, end_block_matching, escrow_funds, fail_orders,
+    historical_prices, load_order_books, load_order_responses, load_pairs, load_registration,
+    load_trades, market_summary, order_book_depth, record_block_cancellations,
+    record_block_deposits, record_block_orders, register_pair, release_escrow, remove_from_books,
+    save_registration, take_block_batches, unregister_contract, update_tick_sizes, validate_order,
+    DexBlockBatch, DexContractInfo, OrderBook, Transfers, DEX_MODULE_ADDRESS,
+};
+use crate::evm::{
+    execute_call_evm_helper, query_evm_helper, register_erc1155, register_erc20, register_erc721,
+    MockErc1155, MockErc20, MockErc721,
+};
+use crate::oracle::{push_rates, rates_at, OracleRateUpdate};
+use crate::tokenfactory::{
+    change_admin, create_denom, denom_admin, denoms_from_creator, ensure_admin, set_metadata,
+};
 use schemars::JsonSchema;
 use sei_cosmwasm::{
-    Cancellation, DenomOracleExchangeRatePair, DexPair, DexTwap, DexTwapsResponse, Epoch,
-    EpochResponse, EvmAddressResponse, ExchangeRatesResponse, GetOrderByIdResponse,
-    GetOrdersResponse, OracleTwap, OracleTwapsResponse, Order, OrderResponse,
-    OrderSimulationResponse, OrderStatus, PositionDirection, SeiAddressResponse, SeiMsg, SeiQuery,
-    SeiQueryWrapper, StaticCallResponse, SudoMsg as SeiSudoMsg,
+    format_rfc3339, Cancellation, DenomAuthorityMetadata, DenomAuthorityMetadataResponse,
+    DenomOracleExchangeRatePair, DenomsFromCreatorResponse, DexPair, DexTwap, DexTwapsResponse,
+    Epoch, EpochResponse, EvmAddressResponse, ExchangeRatesResponse, GetLatestPriceResponse,
+    GetOrderByIdResponse, GetOrdersResponse, GetRegisteredPairsResponse, Metadata, OracleTwap,
+    OracleTwapsResponse, Order, OrderResponse, OrderSimulationResponse, OrderStatus, PriceResponse,
+    SeiAddressResponse, SeiError, SeiMsg, SeiQuery, SeiQueryWrapper, SeiRoute,
+    SudoMsg as SeiSudoMsg, UnsuccessfulOrder,
 };
 use serde::de::DeserializeOwned;
 use std::{
@@ -23,11 +43,13 @@ use std::{
 pub struct SeiModule {
     epoch: Epoch,
     exchange_rates: HashMap<String, Vec<DenomOracleExchangeRatePair>>,
+    associations: AddressAssociations,
 }
 
 const GENESIS_EPOCH: Epoch = Epoch {
     genesis_time: String::new(),
-    duration: 60,
+    // One minute, in nanoseconds
+    duration: 60_000_000_000,
     current_epoch: 1,
     current_epoch_start_time: String::new(),
     current_epoch_height: 1,
@@ -41,6 +63,7 @@ impl SeiModule {
         SeiModule {
             epoch: GENESIS_EPOCH,
             exchange_rates: HashMap::new(),
+            associations: default_associations(),
         }
     }
 
@@ -48,9 +71,7 @@ impl SeiModule {
         let mut exchange_rates: HashMap<String, Vec<DenomOracleExchangeRatePair>> = HashMap::new();
 
         for rate in rates {
-            let arr = exchange_rates
-                .entry(rate.denom.clone())
-                .or_insert_with(Vec::new);
+            let arr = exchange_rates.entry(rate.denom.clone()).or_default();
 
             match arr.binary_search_by(|x| {
                 rate.oracle_exchange_rate
@@ -64,16 +85,249 @@ impl SeiModule {
 
         SeiModule {
             epoch: GENESIS_EPOCH,
-            exchange_rates: exchange_rates,
+            exchange_rates,
+            associations: default_associations(),
         }
     }
 
     pub fn set_epoch(&self, new_epoch: Epoch) -> Self {
         SeiModule {
             epoch: new_epoch,
-            exchange_rates: (&self.exchange_rates).clone(),
+            exchange_rates: self.exchange_rates.clone(),
+            associations: self.associations.clone(),
+        }
+    }
+
+    /// Returns the epoch at `block`. Once the epoch has a `current_epoch_start_time`, e.g. set
+    /// with `set_epoch` or `helper::advance_epoch`, it advances every `duration` nanoseconds of
+    /// block time. The height of an epoch started since is that of its first block at the mean
+    /// block time since the start of the set epoch.
+    pub fn epoch_at(&self, block: &BlockInfo) -> Epoch {
+        let epoch = &self.epoch;
+        let (start, current) = match (epoch.start_timestamp(), epoch.epoch_at(block.time)) {
+            (Ok(start), Ok(current)) if current > epoch.current_epoch => (start, current),
+            _ => return epoch.clone(),
+        };
+        let new_start = start.plus_nanos((current - epoch.current_epoch) * epoch.duration);
+        let blocks = block
+            .height
+            .saturating_sub(epoch.current_epoch_height as u64) as u128;
+        let elapsed = (block.time.nanos() - start.nanos()) as u128;
+        let since_start = (new_start.nanos() - start.nanos()) as u128;
+        let height =
+            epoch.current_epoch_height + ((since_start * blocks + elapsed - 1) / elapsed) as i64;
+        Epoch {
+            current_epoch: current,
+            current_epoch_start_time: format_rfc3339(new_start),
+            current_epoch_height: height,
+            ..epoch.clone()
         }
     }
+
+    /// Associates `sei_address` with `evm_address` from genesis, replacing the genesis
+    /// associations of both addresses. `SEI_ADDRESS` and `EVM_ADDRESS` are associated by default.
+    pub fn with_address_association(
+        mut self,
+        sei_address: impl Into<String>,
+        evm_address: impl Into<String>,
+    ) -> Self {
+        self.associations
+            .insert(sei_address.into(), evm_address.into());
+        self
+    }
+
+    /// Associates the Sei and EVM addresses derived from the secp256k1 `pubkey` from genesis,
+    /// see `addresses_from_pubkey`.
+    pub fn with_pubkey_association(self, pubkey: &[u8]) -> AnyResult<Self> {
+        let (sei_address, evm_address) = addresses_from_pubkey(pubkey)?;
+        Ok(self.with_address_association(sei_address, evm_address))
+    }
+
+    /// Associates `sei_address` with `evm_address` during a test, e.g. through
+    /// `App::init_modules`. Fails with `SeiError::AddressAlreadyAssociated` if either address is
+    /// already associated with another address, as on chain.
+    pub fn associate_address(
+        &self,
+        storage: &mut dyn Storage,
+        sei_address: &str,
+        evm_address: &str,
+    ) -> AnyResult<()> {
+        associate_address(storage, &self.associations, sei_address, evm_address)
+    }
+
+    /// Associates the Sei and EVM addresses derived from the secp256k1 `pubkey` during a test
+    /// and returns them.
+    pub fn associate_pubkey(
+        &self,
+        storage: &mut dyn Storage,
+        pubkey: &[u8],
+    ) -> AnyResult<(String, String)> {
+        let (sei_address, evm_address) = addresses_from_pubkey(pubkey)?;
+        associate_address(storage, &self.associations, &sei_address, &evm_address)?;
+        Ok((sei_address, evm_address))
+    }
+
+    /// Registers the dex `pair` of `contract_address`. Orders placed for a contract with
+    /// registered pairs are validated against the pair's tick sizes.
+    pub fn register_dex_pair(
+        &self,
+        storage: &mut dyn Storage,
+        contract_address: &Addr,
+        pair: DexPair,
+    ) -> AnyResult<()> {
+        register_pair(storage, contract_address, pair)
+    }
+
+    /// Returns the dex registration of `contract_address`, see `SeiMsg::RegisterContract`
+    pub fn dex_contract_info(
+        &self,
+        storage: &dyn Storage,
+        contract_address: &Addr,
+    ) -> AnyResult<Option<DexContractInfo>> {
+        load_registration(storage, contract_address)
+    }
+
+    /// Returns the order book of the `price_denom`/`asset_denom` pair of `contract_address`
+    pub fn order_book(
+        &self,
+        storage: &dyn Storage,
+        contract_address: &Addr,
+        price_denom: &str,
+        asset_denom: &str,
+    ) -> AnyResult<Option<OrderBook>> {
+        Ok(load_order_books(storage, contract_address)?
+            .into_iter()
+            .find(|b| b.price_denom == price_denom && b.asset_denom == asset_denom))
+    }
+
+    /// Returns and clears the orders placed and cancelled per contract during the current block,
+    /// see `helper::end_block`.
+    pub fn take_block_batches(
+        &self,
+        storage: &mut dyn Storage,
+    ) -> AnyResult<Vec<(Addr, DexBlockBatch)>> {
+        take_block_batches(storage)
+    }
+
+    /// Rejects the `unsuccessful_orders` a contract returned from its `BulkOrderPlacements` sudo
+    /// and refunds the funds escrowed for them
+    pub fn fail_orders<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        contract_address: &Addr,
+        unsuccessful_orders: &[UnsuccessfulOrder],
+    ) -> AnyResult<()>
+    where
+        ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        let refunds = fail_orders(storage, contract_address, unsuccessful_orders)?;
+        send_from_dex(api, storage, router, block, refunds)
+    }
+
+    /// Matches the order books at the end of `block`, sends the escrowed funds of the filled
+    /// and cancelled orders and returns the `SudoMsg::Settlement` to send to each contract with
+    /// fills, see `helper::end_block`.
+    pub fn end_block<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+    ) -> AnyResult<Vec<(Addr, SeiSudoMsg)>>
+    where
+        ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        let matching = end_block_matching(storage, block)?;
+        send_from_dex(api, storage, router, block, matching.transfers)?;
+        Ok(matching
+            .settlements
+            .into_iter()
+            .map(|(contract_address, entries)| {
+                let settlement = SeiSudoMsg::Settlement {
+                    epoch: self.epoch_at(block).current_epoch as i64,
+                    entries,
+                };
+                (contract_address, settlement)
+            })
+            .collect())
+    }
+
+    /// Adds `updates` to the oracle price timeline. Each exchange rate is returned by the
+    /// `ExchangeRates` and `OracleTwaps` queries once the block time reaches its timestamp,
+    /// see `helper::update_oracle_rate` and `helper::replay_oracle_rates`.
+    pub fn push_oracle_rates(
+        &self,
+        storage: &mut dyn Storage,
+        updates: &[OracleRateUpdate],
+    ) -> AnyResult<()> {
+        push_rates(storage, updates)
+    }
+
+    /// Returns the exchange rates per denom known at `block`, most recent first
+    pub fn oracle_rates_at(
+        &self,
+        storage: &dyn Storage,
+        block: &BlockInfo,
+    ) -> AnyResult<HashMap<String, Vec<DenomOracleExchangeRatePair>>> {
+        rates_at(storage, &self.exchange_rates, block.time.seconds())
+    }
+
+    /// Registers a mock ERC-20 contract at the EVM `address`, to be called through
+    /// `SeiMsg::CallEvm`, `SeiQuery::StaticCall` and the `Erc20*` queries.
+    pub fn register_erc20(
+        &self,
+        storage: &mut dyn Storage,
+        address: &str,
+        token: MockErc20,
+    ) -> AnyResult<()> {
+        register_erc20(storage, &self.associations, address, token)
+    }
+
+    /// Registers a mock ERC-721 contract at the EVM `address`, to be called through
+    /// `SeiMsg::CallEvm`, `SeiQuery::StaticCall` and the `Erc721*` queries.
+    pub fn register_erc721(
+        &self,
+        storage: &mut dyn Storage,
+        address: &str,
+        token: MockErc721,
+    ) -> AnyResult<()> {
+        register_erc721(storage, &self.associations, address, token)
+    }
+
+    /// Registers a mock ERC-1155 contract at the EVM `address`, to be called through
+    /// `SeiMsg::CallEvm`, `SeiQuery::StaticCall` and the `Erc1155*` queries.
+    pub fn register_erc1155(
+        &self,
+        storage: &mut dyn Storage,
+        address: &str,
+        token: MockErc1155,
+    ) -> AnyResult<()> {
+        register_erc1155(storage, &self.associations, address, token)
+    }
+}
+
+fn unknown_query(route: &SeiRoute) -> SystemError {
+    let module = match route {
+        SeiRoute::Oracle => "Oracle",
+        SeiRoute::Dex => "Dex",
+        SeiRoute::Epoch => "Epoch",
+        SeiRoute::Tokenfactory => "TokenFactory",
+        SeiRoute::Evm => "EVM",
+    };
+    SystemError::UnsupportedRequest {
+        kind: format!("Unknown Sei {} Query", module),
+    }
+}
+
+fn default_associations() -> AddressAssociations {
+    let mut associations = AddressAssociations::default();
+    associations.insert(SEI_ADDRESS.to_string(), EVM_ADDRESS.to_string());
+    associations
 }
 
 impl Default for SeiModule {
@@ -105,31 +359,104 @@ impl Module for SeiModule {
                 orders,
                 funds,
                 contract_address,
-            } => {
-                return execute_place_orders_helper(
-                    storage,
-                    block,
-                    orders,
-                    funds,
-                    contract_address,
-                );
-            }
+            } => execute_place_orders_helper(
+                api,
+                storage,
+                router,
+                block,
+                sender,
+                orders,
+                funds,
+                contract_address,
+            ),
             SeiMsg::CancelOrders {
                 cancellations,
                 contract_address,
-            } => {
-                return execute_cancel_orders_helper(storage, cancellations, contract_address);
-            }
+            } => execute_cancel_orders_helper(
+                api,
+                storage,
+                router,
+                block,
+                sender,
+                cancellations,
+                contract_address,
+            ),
+            SeiMsg::RegisterContract {
+                contract_address,
+                code_id,
+                need_hook,
+                need_order_matching,
+                rent,
+            } => execute_register_contract_helper(
+                api,
+                storage,
+                router,
+                block,
+                sender,
+                contract_address,
+                DexContractInfo {
+                    code_id,
+                    creator: String::new(),
+                    need_hook,
+                    need_order_matching,
+                    rent_balance: rent,
+                },
+            ),
+            SeiMsg::RegisterPairs {
+                contract_address,
+                pairs,
+            } => execute_register_pairs_helper(storage, sender, contract_address, pairs),
+            SeiMsg::UpdateTickSizes {
+                contract_address,
+                pairs,
+            } => execute_update_tick_sizes_helper(storage, sender, contract_address, pairs),
+            SeiMsg::DepositRent {
+                contract_address,
+                amount,
+            } => execute_deposit_rent_helper(
+                api,
+                storage,
+                router,
+                block,
+                sender,
+                contract_address,
+                amount,
+            ),
+            SeiMsg::UnregisterContract { contract_address } => execute_unregister_contract_helper(
+                api,
+                storage,
+                router,
+                block,
+                sender,
+                contract_address,
+            ),
             SeiMsg::CreateDenom { subdenom } => {
-                return execute_create_denom_helper(storage, sender, subdenom);
+                execute_create_denom_helper(storage, sender, subdenom)
             }
             SeiMsg::MintTokens { amount } => {
-                return execute_mint_tokens_helper(api, storage, router, block, sender, amount);
+                execute_mint_tokens_helper(api, storage, router, block, sender, amount)
             }
             SeiMsg::BurnTokens { amount } => {
-                return execute_burn_tokens_helper(api, storage, router, block, sender, amount);
+                execute_burn_tokens_helper(api, storage, router, block, sender, amount)
+            }
+            SeiMsg::ChangeAdmin {
+                denom,
+                new_admin_address,
+            } => execute_change_admin_helper(storage, sender, denom, new_admin_address),
+            SeiMsg::SetMetadata { metadata } => {
+                execute_set_metadata_helper(storage, sender, metadata)
+            }
+            SeiMsg::CallEvm { value, to, data } => {
+                execute_call_evm_helper(storage, &self.associations, sender, value, to, data)
             }
-            _ => panic!("Unexpected custom exec msg"),
+            SeiMsg::DelegateCallEvm { to, data } => execute_call_evm_helper(
+                storage,
+                &self.associations,
+                sender,
+                Uint128::zero(),
+                to,
+                data,
+            ),
         }
     }
 
@@ -141,13 +468,17 @@ impl Module for SeiModule {
         block: &BlockInfo,
         request: Self::QueryT,
     ) -> AnyResult<Binary> {
+        // The chain only parses the queries of the module of the route
+        if request.route != request.query_data.route() {
+            bail!(unknown_query(&request.route));
+        }
         match request.query_data {
-            SeiQuery::ExchangeRates {} => Ok(to_json_binary(&get_exchange_rates(
-                self.exchange_rates.clone(),
-            ))?),
+            SeiQuery::ExchangeRates { start_after, limit } => Ok(to_json_binary(
+                &get_exchange_rates(self.oracle_rates_at(storage, block)?, start_after, limit),
+            )?),
             SeiQuery::OracleTwaps { lookback_seconds } => Ok(to_json_binary(&get_oracle_twaps(
                 block,
-                self.exchange_rates.clone(),
+                self.oracle_rates_at(storage, block)?,
                 lookback_seconds,
             ))?),
             SeiQuery::DexTwaps {
@@ -158,7 +489,7 @@ impl Module for SeiModule {
                 block,
                 contract_address,
                 lookback_seconds,
-            ))?),
+            )?)?),
             SeiQuery::OrderSimulation {
                 order,
                 contract_address,
@@ -167,47 +498,119 @@ impl Module for SeiModule {
                 order,
                 contract_address,
             ))?),
-            SeiQuery::Epoch {} => return query_get_epoch_helper(self.epoch.clone()),
+            SeiQuery::Epoch {} => query_get_epoch_helper(self.epoch_at(block)),
             SeiQuery::GetOrders {
                 contract_address,
                 account,
+                start_after,
+                limit,
+            } => query_get_orders_helper(storage, contract_address, account, start_after, limit),
+            SeiQuery::GetLatestPrice {
+                contract_address,
+                price_denom,
+                asset_denom,
+            } => query_get_latest_price_helper(storage, contract_address, price_denom, asset_denom),
+            SeiQuery::OrderBookDepth {
+                contract_address,
+                price_denom,
+                asset_denom,
+                limit,
             } => {
-                return query_get_orders_helper(storage, contract_address, account);
+                let book = self
+                    .order_book(storage, &contract_address, &price_denom, &asset_denom)?
+                    .unwrap_or_default();
+                Ok(to_json_binary(&order_book_depth(&book, limit))?)
             }
-            // TODO: Implement Set and Get latest price in integration tests
-            SeiQuery::GetLatestPrice { .. } => {
-                panic!("Get Latest Price Query not implemented")
+            SeiQuery::GetRegisteredPairs { contract_address } => {
+                Ok(to_json_binary(&GetRegisteredPairsResponse {
+                    pairs: load_pairs(storage, &contract_address)?,
+                })?)
             }
-            SeiQuery::GetOrderById {
+            SeiQuery::GetMarketSummary {
                 contract_address,
                 price_denom,
                 asset_denom,
-                id,
+                lookback_seconds,
             } => {
-                return query_get_order_by_id_helper(
-                    storage,
-                    contract_address,
-                    price_denom,
-                    asset_denom,
-                    id,
-                );
-            }
-            SeiQuery::StaticCall { .. } => Ok(to_json_binary(&get_static_call_response())?),
-            SeiQuery::GetEvmAddress { sei_address } => {
-                Ok(to_json_binary(&get_evm_address(sei_address))?)
+                let trades = load_trades(storage, &contract_address, &price_denom, &asset_denom)?;
+                let since = block.time.seconds().saturating_sub(lookback_seconds);
+                Ok(to_json_binary(&market_summary(&trades, since))?)
             }
-            SeiQuery::GetSeiAddress { evm_address } => {
-                Ok(to_json_binary(&get_sei_address(evm_address))?)
+            SeiQuery::GetHistoricalPrices {
+                contract_address,
+                price_denom,
+                asset_denom,
+                period_length_in_seconds,
+                number_of_periods,
+            } => {
+                let trades = load_trades(storage, &contract_address, &price_denom, &asset_denom)?;
+                Ok(to_json_binary(&historical_prices(
+                    &trades,
+                    block.time.seconds(),
+                    period_length_in_seconds,
+                    number_of_periods,
+                ))?)
             }
-            // TODO: Implement get denom authority metadata in integration tests
-            SeiQuery::DenomAuthorityMetadata { .. } => {
-                panic!("Denom Authority Metadata not implemented")
+            SeiQuery::GetOrderById {
+                contract_address,
+                price_denom,
+                asset_denom,
+                id,
+            } => query_get_order_by_id_helper(
+                storage,
+                contract_address,
+                price_denom,
+                asset_denom,
+                id,
+            ),
+            SeiQuery::StaticCall { .. }
+            | SeiQuery::Erc20TransferPayload { .. }
+            | SeiQuery::Erc20TransferFromPayload { .. }
+            | SeiQuery::Erc20ApprovePayload { .. }
+            | SeiQuery::Erc20Allowance { .. }
+            | SeiQuery::Erc20TokenInfo { .. }
+            | SeiQuery::Erc20Balance { .. }
+            | SeiQuery::Erc721TransferPayload { .. }
+            | SeiQuery::Erc721ApprovePayload { .. }
+            | SeiQuery::Erc721Owner { .. }
+            | SeiQuery::Erc721Approved { .. }
+            | SeiQuery::Erc721IsApprovedForAll { .. }
+            | SeiQuery::Erc721SetApprovalAllPayload { .. }
+            | SeiQuery::Erc721NameSymbol { .. }
+            | SeiQuery::Erc721Uri { .. }
+            | SeiQuery::Erc721TotalSupply { .. }
+            | SeiQuery::Erc721TokenByIndex { .. }
+            | SeiQuery::Erc721TokenOfOwnerByIndex { .. }
+            | SeiQuery::Erc721BalanceOf { .. }
+            | SeiQuery::Erc721RoyaltyInfo { .. }
+            | SeiQuery::Erc1155TransferPayload { .. }
+            | SeiQuery::Erc1155BatchTransferPayload { .. }
+            | SeiQuery::Erc1155SetApprovalAllPayload { .. }
+            | SeiQuery::Erc1155BalanceOf { .. }
+            | SeiQuery::Erc1155BalanceOfBatch { .. }
+            | SeiQuery::Erc1155IsApprovedForAll { .. }
+            | SeiQuery::Erc1155Uri { .. }
+            | SeiQuery::Erc1155RoyaltyInfo { .. } => {
+                query_evm_helper(storage, &self.associations, request.query_data)
             }
-            // TODO: Implement get denom from creator in integration tests
-            SeiQuery::DenomsFromCreator { .. } => {
-                panic!("Denoms From Creator not implemented")
+            SeiQuery::GetEvmAddress { sei_address } => Ok(to_json_binary(&get_evm_address(
+                storage,
+                &self.associations,
+                sei_address,
+            ))?),
+            SeiQuery::GetSeiAddress { evm_address } => Ok(to_json_binary(&get_sei_address(
+                storage,
+                &self.associations,
+                evm_address,
+            ))?),
+            SeiQuery::DenomAuthorityMetadata { denom } => {
+                query_denom_authority_metadata_helper(storage, denom)
             }
-            _ => panic!("Unexpected custom query msg"),
+            SeiQuery::DenomsFromCreator {
+                creator,
+                start_after,
+                limit,
+            } => query_denoms_from_creator_helper(storage, creator, start_after, limit),
         }
     }
 
@@ -248,22 +651,91 @@ impl Module for SeiModule {
 
 // Helper functions
 
+// Returns at most `limit` of the `items` (ordered by `key`) after `start_after`, and the key of
+// the last returned item if more items follow
+fn paginate<T, K: PartialOrd>(
+    items: Vec<T>,
+    key: impl Fn(&T) -> K,
+    start_after: Option<K>,
+    limit: Option<u32>,
+) -> (Vec<T>, Option<K>) {
+    let mut items: Vec<T> = items
+        .into_iter()
+        .filter(|item| match &start_after {
+            Some(start_after) => key(item) > *start_after,
+            None => true,
+        })
+        .collect();
+    let limit = match limit {
+        Some(limit) if limit > 0 => limit as usize,
+        _ => return (items, None),
+    };
+    if items.len() <= limit {
+        return (items, None);
+    }
+    items.truncate(limit);
+    let next_key = items.last().map(&key);
+    (items, next_key)
+}
+
+// Sends `amount` usei from `from` to `to` through the bank module, if not zero
+fn send_usei<ExecC, QueryC>(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+    block: &BlockInfo,
+    from: Addr,
+    to: &str,
+    amount: Uint128,
+) -> AnyResult<()>
+where
+    ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+    QueryC: CustomQuery + DeserializeOwned + 'static,
+{
+    if amount.is_zero() {
+        return Ok(());
+    }
+    router.execute(
+        api,
+        storage,
+        block,
+        from,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: to.to_string(),
+            amount: vec![Coin::new(amount.u128(), "usei")],
+        }),
+    )?;
+    Ok(())
+}
+
 // Dex Module Msg
 
 // Execute: PlaceOrders()
-fn execute_place_orders_helper(
+#[allow(clippy::too_many_arguments)]
+fn execute_place_orders_helper<ExecC, QueryC>(
+    api: &dyn Api,
     storage: &mut dyn Storage,
+    router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
     block: &BlockInfo,
+    sender: Addr,
     orders: Vec<Order>,
-    _funds: Vec<Coin>,
+    funds: Vec<Coin>,
     contract_address: Addr,
-) -> AnyResult<AppResponse> {
+) -> AnyResult<AppResponse>
+where
+    ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+    QueryC: CustomQuery + DeserializeOwned + 'static,
+{
     // Storage:
     // OrderIdCounter -> OrderId
     // contract_address + "-" + OrderResponses -> OrderResponse[]
     // contract_address + "-" + OrderResponseById + "-" + Price Denom + "-" + Asset Denom + "-" + OrderId -> OrderResponse
     // "OrderTimestamp-" + OrderId -> OrderTimestamp
 
+    for order in orders.iter() {
+        validate_order(storage, &contract_address, order)?;
+    }
+
     // Get latest order id
     let mut latest_order_id: u64 = 0;
     let curr = storage.get("OrderIdCounter".as_bytes());
@@ -286,6 +758,7 @@ fn execute_place_orders_helper(
         order_responses = serde_json::from_str(&responses_json).unwrap();
     }
     // Iterate through orders, make OrderResponse
+    let mut block_orders = vec![];
     for order in orders.iter() {
         let order_response = OrderResponse {
             id: latest_order_id,
@@ -297,8 +770,8 @@ fn execute_place_orders_helper(
             order_type: order.order_type,
             position_direction: order.position_direction,
             data: order.data.clone(),
-            account: "test account".to_string(),
-            contract_address: "test contract".to_string(),
+            account: sender.to_string(),
+            contract_address: contract_address.to_string(),
             status_description: "desc".to_string(),
         };
         order_responses.push(order_response.clone());
@@ -322,10 +795,42 @@ fn execute_place_orders_helper(
             format!("OrderTimestamp-{}", latest_order_id).as_bytes(),
             &block.time.seconds().to_be_bytes(),
         );
+        add_to_book(
+            storage,
+            &contract_address,
+            sender.as_str(),
+            latest_order_id,
+            order,
+        )?;
+        block_orders.push((latest_order_id, order.clone()));
 
         latest_order_id += 1;
     }
 
+    // Move the funds into the dex module escrow
+    let funds: Vec<Coin> = funds.into_iter().filter(|c| !c.amount.is_zero()).collect();
+    if !funds.is_empty() {
+        router.execute(
+            api,
+            storage,
+            block,
+            sender.clone(),
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: DEX_MODULE_ADDRESS.to_string(),
+                amount: funds.clone(),
+            }),
+        )?;
+    }
+    escrow_funds(
+        storage,
+        &contract_address,
+        sender.as_str(),
+        &block_orders,
+        &funds,
+    )?;
+    record_block_deposits(storage, &contract_address, sender.as_str(), &funds)?;
+    record_block_orders(storage, &contract_address, block_orders)?;
+
     let responses_json = serde_json::to_string(&order_responses);
 
     // update GetOrders() -> OrderResponse[] storage
@@ -346,68 +851,239 @@ fn execute_place_orders_helper(
 }
 
 // Execute: CancelOrders()
-fn execute_cancel_orders_helper(
+fn execute_cancel_orders_helper<ExecC, QueryC>(
+    api: &dyn Api,
     storage: &mut dyn Storage,
+    router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+    block: &BlockInfo,
+    sender: Addr,
     cancellations: Vec<Cancellation>,
     contract_address: Addr,
-) -> AnyResult<AppResponse> {
+) -> AnyResult<AppResponse>
+where
+    ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+    QueryC: CustomQuery + DeserializeOwned + 'static,
+{
     // get existing orders
     let order_responses_key = contract_address.to_string() + "-" + "OrderResponses";
 
     let existing_order_responses = storage.get(order_responses_key.as_bytes());
-    if !existing_order_responses.is_some() {
-        return Err(anyhow::anyhow!(
-            "CancelOrders: orders for contract_address do not exist"
-        ));
+    if existing_order_responses.is_none() {
+        return Err(SeiError::OrdersNotFound {
+            contract_address: contract_address.to_string(),
+        }
+        .into());
     }
 
-    let responses_json: String =
-        serde_json::from_slice(&existing_order_responses.clone().unwrap()).unwrap();
-    let mut order_responses: Vec<OrderResponse> = serde_json::from_str(&responses_json).unwrap();
+    let responses_json: String = serde_json::from_slice(&existing_order_responses.unwrap())?;
+    let mut order_responses: Vec<OrderResponse> = serde_json::from_str(&responses_json)?;
 
     let order_ids: Vec<u64> = cancellations.iter().map(|c| -> u64 { c.id }).collect();
     for order_id in order_ids.clone() {
-        let order_response: Vec<OrderResponse> = order_responses
-            .clone()
-            .into_iter()
-            .filter(|o| order_id.clone() == o.id)
-            .collect();
+        let order_response = order_responses
+            .iter()
+            .find(|o| order_id == o.id)
+            .ok_or(SeiError::OrderNotFound { id: order_id })?;
+        // Only the contract and the account that placed the order can cancel it
+        if sender != contract_address && sender != order_response.account {
+            return Err(SeiError::NotOrderOwner {
+                id: order_id,
+                sender: sender.to_string(),
+            }
+            .into());
+        }
+        if order_response.status != OrderStatus::Placed {
+            return Err(SeiError::OrderNotPlaced { id: order_id }.into());
+        }
         let order_id_key = contract_address.to_string()
             + "-"
             + "OrderResponseById"
             + "-"
-            + &order_response[0].price_denom.clone()
+            + &order_response.price_denom
             + "-"
-            + &order_response[0].asset_denom.clone()
+            + &order_response.asset_denom
             + "-"
             + &order_id.to_string();
         // Remove individual for GetOrderById()
         storage.remove(order_id_key.as_bytes());
     }
 
-    order_responses = order_responses
-        .into_iter()
-        .filter(|o| !order_ids.contains(&o.id))
-        .collect();
+    order_responses.retain(|o| !order_ids.contains(&o.id));
+    remove_from_books(storage, &contract_address, &order_ids)?;
+    record_block_cancellations(storage, &contract_address, &order_ids)?;
 
-    let responses_json = serde_json::to_string(&order_responses);
+    // Refund the funds escrowed for the cancelled orders
+    let refunds = release_escrow(storage, &contract_address, &order_ids)?;
+    send_from_dex(api, storage, router, block, refunds)?;
+
+    let responses_json = serde_json::to_string(&order_responses)?;
 
     // update GetOrders() -> OrderResponse[] storage
     storage.set(
         order_responses_key.as_bytes(),
-        &serde_json::to_vec(&responses_json.unwrap_or_default()).unwrap(),
+        &serde_json::to_vec(&responses_json)?,
     );
 
     Ok(AppResponse {
         events: vec![],
-        data: Some(to_json_binary(&contract_address).unwrap()),
+        data: Some(to_json_binary(&contract_address)?),
     })
 }
 
+// Sends the (recipient, funds) `transfers` from the dex module account
+fn send_from_dex<ExecC, QueryC>(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+    block: &BlockInfo,
+    transfers: Transfers,
+) -> AnyResult<()>
+where
+    ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+    QueryC: CustomQuery + DeserializeOwned + 'static,
+{
+    for (recipient, amount) in transfers {
+        router.execute(
+            api,
+            storage,
+            block,
+            Addr::unchecked(DEX_MODULE_ADDRESS),
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount,
+            }),
+        )?;
+    }
+    Ok(())
+}
+
+// Execute: RegisterContract()
+fn execute_register_contract_helper<ExecC, QueryC>(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+    block: &BlockInfo,
+    sender: Addr,
+    contract_address: Addr,
+    mut info: DexContractInfo,
+) -> AnyResult<AppResponse>
+where
+    ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+    QueryC: CustomQuery + DeserializeOwned + 'static,
+{
+    // Re-registering keeps the rent already deposited
+    let rent = info.rent_balance;
+    if load_registration(storage, &contract_address)?.is_some() {
+        let registered = creator_registration(storage, &contract_address, &sender)?;
+        info.rent_balance += registered.rent_balance;
+    }
+    info.creator = sender.to_string();
+    send_usei(
+        api,
+        storage,
+        router,
+        block,
+        sender,
+        DEX_MODULE_ADDRESS,
+        rent,
+    )?;
+    save_registration(storage, &contract_address, &info)?;
+    Ok(AppResponse::default())
+}
+
+// Execute: RegisterPairs()
+fn execute_register_pairs_helper(
+    storage: &mut dyn Storage,
+    sender: Addr,
+    contract_address: Addr,
+    pairs: Vec<DexPair>,
+) -> AnyResult<AppResponse> {
+    creator_registration(storage, &contract_address, &sender)?;
+    for pair in pairs {
+        register_pair(storage, &contract_address, pair)?;
+    }
+    Ok(AppResponse::default())
+}
+
+// Execute: UpdateTickSizes()
+fn execute_update_tick_sizes_helper(
+    storage: &mut dyn Storage,
+    sender: Addr,
+    contract_address: Addr,
+    pairs: Vec<DexPair>,
+) -> AnyResult<AppResponse> {
+    creator_registration(storage, &contract_address, &sender)?;
+    update_tick_sizes(storage, &contract_address, pairs)?;
+    Ok(AppResponse::default())
+}
+
+// Execute: DepositRent()
+fn execute_deposit_rent_helper<ExecC, QueryC>(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+    block: &BlockInfo,
+    sender: Addr,
+    contract_address: Addr,
+    amount: Uint128,
+) -> AnyResult<AppResponse>
+where
+    ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+    QueryC: CustomQuery + DeserializeOwned + 'static,
+{
+    let mut info = match load_registration(storage, &contract_address)? {
+        Some(info) => info,
+        None => bail!(SeiError::ContractNotRegistered {
+            contract_address: contract_address.to_string(),
+        }),
+    };
+    send_usei(
+        api,
+        storage,
+        router,
+        block,
+        sender,
+        DEX_MODULE_ADDRESS,
+        amount,
+    )?;
+    info.rent_balance += amount;
+    save_registration(storage, &contract_address, &info)?;
+    Ok(AppResponse::default())
+}
+
+// Execute: UnregisterContract()
+fn execute_unregister_contract_helper<ExecC, QueryC>(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+    block: &BlockInfo,
+    sender: Addr,
+    contract_address: Addr,
+) -> AnyResult<AppResponse>
+where
+    ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+    QueryC: CustomQuery + DeserializeOwned + 'static,
+{
+    let info = creator_registration(storage, &contract_address, &sender)?;
+    unregister_contract(storage, &contract_address)?;
+    send_usei(
+        api,
+        storage,
+        router,
+        block,
+        Addr::unchecked(DEX_MODULE_ADDRESS),
+        sender.as_str(),
+        info.rent_balance,
+    )?;
+    Ok(AppResponse::default())
+}
+
 // Oracle Module
 
 fn get_exchange_rates(
     rates: HashMap<String, Vec<DenomOracleExchangeRatePair>>,
+    start_after: Option<String>,
+    limit: Option<u32>,
 ) -> ExchangeRatesResponse {
     let mut exchange_rates: Vec<DenomOracleExchangeRatePair> = Vec::new();
 
@@ -415,9 +1091,13 @@ fn get_exchange_rates(
         let rate = rates.get(key).unwrap();
         exchange_rates.push(rate[0].clone());
     }
+    exchange_rates.sort_by(|a, b| a.denom.cmp(&b.denom));
 
+    let (exchange_rates, next_key) =
+        paginate(exchange_rates, |r| r.denom.clone(), start_after, limit);
     ExchangeRatesResponse {
         denom_oracle_exchange_rate_pairs: exchange_rates,
+        next_key,
     }
 }
 
@@ -427,7 +1107,7 @@ fn get_oracle_twaps(
     lookback_seconds: u64,
 ) -> OracleTwapsResponse {
     let mut oracle_twaps: Vec<OracleTwap> = Vec::new();
-    let lbs = lookback_seconds as u64;
+    let lbs = lookback_seconds;
 
     for key in rates.keys() {
         let pair_rates = rates.get(key).unwrap();
@@ -436,11 +1116,11 @@ fn get_oracle_twaps(
         let mut time: u64 = block.time.seconds();
         let mut last_rate = Decimal::zero();
 
-        if pair_rates[0].oracle_exchange_rate.last_update < Uint64::new(start - lbs) {
+        if pair_rates[0].oracle_exchange_rate.last_update < Uint64::new(start.saturating_sub(lbs)) {
             oracle_twaps.push(OracleTwap {
                 denom: key.clone(),
                 twap: pair_rates[0].oracle_exchange_rate.exchange_rate,
-                lookback_seconds: lookback_seconds,
+                lookback_seconds,
             });
             continue;
         }
@@ -448,20 +1128,21 @@ fn get_oracle_twaps(
         // Average prices of rates for the past lookback_seconds
         for rate in pair_rates {
             last_rate = rate.oracle_exchange_rate.exchange_rate;
-            if Uint64::new(start) - rate.oracle_exchange_rate.last_update < Uint64::new(lbs) {
+            let last_update = rate.oracle_exchange_rate.last_update.u64();
+            if start.saturating_sub(last_update) < lbs {
                 sum += last_rate.mul(Decimal::from_ratio(
-                    Uint128::new((time - rate.oracle_exchange_rate.last_update.u64()).into()),
+                    Uint128::new(time.saturating_sub(last_update).into()),
                     Uint128::one(),
                 ));
-                time = rate.oracle_exchange_rate.last_update.u64();
+                time = last_update;
             } else {
                 break;
             }
         }
 
-        if Uint64::new(start - time) < Uint64::new(lbs) {
+        if start.saturating_sub(time) < lbs {
             let sec: u64 = lbs;
-            let diff = sec.sub(start - time);
+            let diff = sec.sub(start.saturating_sub(time));
             sum += last_rate.mul(Decimal::from_ratio(
                 Uint128::new(diff.into()),
                 Uint128::one(),
@@ -474,13 +1155,11 @@ fn get_oracle_twaps(
                 Uint128::new(lbs.into()),
                 Uint128::one(),
             )),
-            lookback_seconds: lookback_seconds,
+            lookback_seconds,
         });
     }
 
-    OracleTwapsResponse {
-        oracle_twaps: oracle_twaps,
-    }
+    OracleTwapsResponse { oracle_twaps }
 }
 
 fn get_dex_twaps(
@@ -488,17 +1167,12 @@ fn get_dex_twaps(
     block: &BlockInfo,
     contract_address: Addr,
     lookback_seconds: u64,
-) -> DexTwapsResponse {
+) -> AnyResult<DexTwapsResponse> {
     let mut dex_twaps: HashMap<(String, String), Decimal> = HashMap::new();
     let mut prev_time = block.time.seconds();
 
-    let order_response: GetOrdersResponse = from_json(
-        &query_get_orders_helper(storage, contract_address, Addr::unchecked("")).unwrap(),
-    )
-    .unwrap();
-
-    let mut orders = order_response.orders.clone();
-    orders.sort_by(|a, b| b.id.cmp(&a.id));
+    let mut orders = load_order_responses(storage, &contract_address)?.unwrap_or_default();
+    orders.sort_by_key(|o| std::cmp::Reverse(o.id));
 
     for order in orders {
         let timestamp = u64::from_be_bytes(
@@ -510,12 +1184,9 @@ fn get_dex_twaps(
         );
 
         let mut update_fn = |time: u64| {
-            if !dex_twaps.contains_key(&(order.asset_denom.clone(), order.price_denom.clone())) {
-                dex_twaps.insert(
-                    (order.asset_denom.clone(), order.price_denom.clone()),
-                    Decimal::zero(),
-                );
-            }
+            dex_twaps
+                .entry((order.asset_denom.clone(), order.price_denom.clone()))
+                .or_insert_with(Decimal::zero);
 
             let sum = dex_twaps
                 .get(&(order.asset_denom.clone(), order.price_denom.clone()))
@@ -549,11 +1220,11 @@ fn get_dex_twaps(
                 quantity_tick_size: Decimal::from_ratio(1u128, 10000u128),
             },
             twap: sum.div(Decimal::from_ratio(lookback_seconds, 1u64)),
-            lookback_seconds: lookback_seconds,
+            lookback_seconds,
         });
     }
 
-    DexTwapsResponse { twaps }
+    Ok(DexTwapsResponse { twaps })
 }
 
 fn get_order_simulation(
@@ -561,61 +1232,68 @@ fn get_order_simulation(
     order: Order,
     contract_address: Addr,
 ) -> OrderSimulationResponse {
-    let mut executed_quantity = Decimal::zero();
-
-    let orders: GetOrdersResponse = from_json(
-        &query_get_orders_helper(storage, contract_address, Addr::unchecked("")).unwrap(),
-    )
-    .unwrap();
-
-    let valid_orders = if order.position_direction == PositionDirection::Long {
-        PositionDirection::Short
-    } else {
-        PositionDirection::Long
-    };
-
-    for order_response in orders.orders {
-        if order_response.position_direction == valid_orders {
-            if (order_response.position_direction == PositionDirection::Long
-                && order.price <= order_response.price)
-                || (order_response.position_direction == PositionDirection::Short
-                    && order.price >= order_response.price)
-            {
-                executed_quantity += order_response.quantity;
-            }
-        }
-    }
-
-    OrderSimulationResponse {
-        executed_quantity: if executed_quantity > order.quantity {
-            order.quantity
-        } else {
-            executed_quantity
-        },
-    }
+    let executed_quantity = load_order_books(storage, &contract_address)
+        .unwrap_or_default()
+        .iter()
+        .find(|b| b.price_denom == order.price_denom && b.asset_denom == order.asset_denom)
+        .map(|book| book.simulate(&order))
+        .unwrap_or_default();
+
+    OrderSimulationResponse { executed_quantity }
 }
 
 // Query: GetOrders()
 fn query_get_orders_helper(
     storage: &dyn Storage,
     contract_address: Addr,
-    _account: Addr,
+    account: Addr,
+    start_after: Option<u64>,
+    limit: Option<u32>,
 ) -> AnyResult<Binary> {
-    let order_responses_key = contract_address.to_string() + "-" + "OrderResponses";
-    let existing_order_responses = storage.get(order_responses_key.as_bytes());
-    if !existing_order_responses.is_some() {
-        return Err(anyhow::anyhow!(
-            "GetOrders: orders for contract_address do not exist"
-        ));
-    }
-    let responses_json: String =
-        serde_json::from_slice(&existing_order_responses.clone().unwrap()).unwrap();
+    let mut order_responses = match load_order_responses(storage, &contract_address)? {
+        Some(order_responses) => order_responses,
+        None => bail!(SeiError::OrdersNotFound {
+            contract_address: contract_address.to_string(),
+        }),
+    };
+    order_responses.retain(|o| o.account == account.as_str());
+    order_responses.sort_by_key(|o| o.id);
 
-    let order_responses: Vec<OrderResponse> = serde_json::from_str(&responses_json).unwrap();
+    let (orders, next_key) = paginate(order_responses, |o| o.id, start_after, limit);
+    Ok(to_json_binary(&GetOrdersResponse { orders, next_key })?)
+}
 
-    return Ok(to_json_binary(&GetOrdersResponse {
-        orders: order_responses,
-    })?);
+// Query: GetLatestPrice()
+fn query_get_latest_price_helper(
+    storage: &dyn Storage,
+    contract_address: Addr,
+    price_denom: String,
+    asset_denom: String,
+) -> AnyResult<Binary> {
+    let trade = match load_trades(storage, &contract_address, &price_denom, &asset_denom)?.pop() {
+        Some(trade) => trade,
+        None => bail!(SeiError::PriceNotFound {
+            contract_address: contract_address.to_string(),
+            price_denom,
+            asset_denom,
+        }),
+    };
+    let pair = load_pairs(storage, &contract_address)?
+        .into_iter()
+        .find(|p| p.price_denom == price_denom && p.asset_denom == asset_denom)
+        .unwrap_or(DexPair {
+            price_denom,
+            asset_denom,
+            price_tick_size: Decimal::zero(),
+            quantity_tick_size: Decimal::zero(),
+        });
+    Ok(to_json_binary(&GetLatestPriceResponse {
+        price: PriceResponse {
+            snapshot_timestamp_in_seconds: trade.timestamp,
+            price: trade.price,
+            pair,
+        },
+    })?)
 }
 
 // Query: GetOrderById()
@@ -637,8 +1315,8 @@ fn query_get_order_by_id_helper(
         + &id.to_string();
     let existing_order_response = storage.get(order_id_key.as_bytes());
 
-    if !existing_order_response.is_some() {
-        return Err(anyhow::anyhow!("GetOrderById: order for id does not exist"));
+    if existing_order_response.is_none() {
+        return Err(SeiError::OrderNotFound { id }.into());
     }
 
     let response_json: String =
@@ -646,27 +1324,25 @@ fn query_get_order_by_id_helper(
 
     let order_response: OrderResponse = serde_json::from_str(&response_json).unwrap();
 
-    return Ok(to_json_binary(&GetOrderByIdResponse {
+    Ok(to_json_binary(&GetOrderByIdResponse {
         order: order_response,
-    })?);
+    })?)
 }
 
 // Epoch Module Queries
 
 fn get_epoch(epoch: Epoch) -> EpochResponse {
-    EpochResponse { epoch: epoch }
-}
-
-fn get_static_call_response() -> StaticCallResponse {
-    StaticCallResponse {
-        encoded_data: general_purpose::STANDARD.encode(b"static call response"),
-    }
+    EpochResponse { epoch }
 }
 
-fn get_evm_address(sei_address: String) -> EvmAddressResponse {
-    let (evm_address, associated) = match sei_address.as_str() {
-        SEI_ADDRESS => (EVM_ADDRESS.to_string(), true),
-        _ => (String::new(), false), // default case
+fn get_evm_address(
+    storage: &dyn Storage,
+    associations: &AddressAssociations,
+    sei_address: String,
+) -> EvmAddressResponse {
+    let (evm_address, associated) = match associations.evm_address(storage, &sei_address) {
+        Some(evm_address) => (evm_address, true),
+        None => (String::new(), false),
     };
 
     EvmAddressResponse {
@@ -675,10 +1351,14 @@ fn get_evm_address(sei_address: String) -> EvmAddressResponse {
     }
 }
 
-fn get_sei_address(evm_address: String) -> SeiAddressResponse {
-    let (sei_address, associated) = match evm_address.as_str() {
-        EVM_ADDRESS => (SEI_ADDRESS.to_string(), true),
-        _ => (String::new(), false), // default case
+fn get_sei_address(
+    storage: &dyn Storage,
+    associations: &AddressAssociations,
+    evm_address: String,
+) -> SeiAddressResponse {
+    let (sei_address, associated) = match associations.sei_address(storage, &evm_address) {
+        Some(sei_address) => (sei_address, true),
+        None => (String::new(), false),
     };
 
     SeiAddressResponse {
@@ -689,7 +1369,7 @@ fn get_sei_address(evm_address: String) -> SeiAddressResponse {
 
 // Query: GetEpoch()
 fn query_get_epoch_helper(epoch: Epoch) -> AnyResult<Binary> {
-    return Ok(to_json_binary(&get_epoch(epoch))?);
+    Ok(to_json_binary(&get_epoch(epoch))?)
 }
 
 // TokenFactory Msg
@@ -700,11 +1380,7 @@ fn execute_create_denom_helper(
     sender: Addr,
     subdenom: String,
 ) -> AnyResult<AppResponse> {
-    let denom = format!("factory/{}/{}", sender, subdenom);
-    if storage.get(denom.as_bytes()).is_some() {
-        return Err(anyhow::anyhow!("denom already exists"));
-    }
-    storage.set(denom.as_bytes(), sender.to_string().as_bytes());
+    let denom = create_denom(storage, &sender, &subdenom)?;
     Ok(AppResponse {
         events: vec![],
         data: Some(to_json_binary(&denom).unwrap()),
@@ -724,12 +1400,7 @@ where
     ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
     QueryC: CustomQuery + DeserializeOwned + 'static,
 {
-    let owner = storage.get(amount.denom.as_bytes());
-    if owner.is_none() || owner.unwrap() != sender.to_string().as_bytes() {
-        return Err(anyhow::anyhow!(
-            "Must be owner of coin factory denom to mint"
-        ));
-    }
+    ensure_admin(storage, &amount.denom, &sender, "mint")?;
     router.sudo(
         api,
         storage,
@@ -754,21 +1425,89 @@ where
     ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
     QueryC: CustomQuery + DeserializeOwned + 'static,
 {
-    let owner = storage.get(amount.denom.as_bytes());
-    if owner.is_none() || owner.unwrap() != sender.to_string().as_bytes() {
-        return Err(anyhow::anyhow!(
-            "Must be owner of coin factory denom to burn"
-        ));
-    }
-    Ok(router
-        .execute(
-            api,
-            storage,
-            block,
-            sender,
-            CosmosMsg::Bank(BankMsg::Burn {
-                amount: vec![amount],
-            }),
-        )
-        .unwrap())
+    ensure_admin(storage, &amount.denom, &sender, "burn")?;
+    let balance: BalanceResponse = from_json(router.query(
+        api,
+        storage,
+        block,
+        QueryRequest::Bank(BankQuery::Balance {
+            address: sender.to_string(),
+            denom: amount.denom.clone(),
+        }),
+    )?)?;
+    if balance.amount.amount < amount.amount {
+        return Err(SeiError::InsufficientFunds {
+            denom: amount.denom,
+            required: amount.amount,
+            provided: balance.amount.amount,
+        }
+        .into());
+    }
+    router.execute(
+        api,
+        storage,
+        block,
+        sender,
+        CosmosMsg::Bank(BankMsg::Burn {
+            amount: vec![amount],
+        }),
+    )
+}
+
+// Execute: ChangeAdmin()
+fn execute_change_admin_helper(
+    storage: &mut dyn Storage,
+    sender: Addr,
+    denom: String,
+    new_admin_address: String,
+) -> AnyResult<AppResponse> {
+    change_admin(storage, &sender, &denom, &new_admin_address)?;
+    Ok(AppResponse {
+        events: vec![],
+        data: None,
+    })
+}
+
+// Execute: SetMetadata()
+fn execute_set_metadata_helper(
+    storage: &mut dyn Storage,
+    sender: Addr,
+    metadata: Metadata,
+) -> AnyResult<AppResponse> {
+    set_metadata(storage, &sender, metadata)?;
+    Ok(AppResponse {
+        events: vec![],
+        data: None,
+    })
+}
+
+// Query: DenomAuthorityMetadata()
+fn query_denom_authority_metadata_helper(
+    storage: &dyn Storage,
+    denom: String,
+) -> AnyResult<Binary> {
+    let admin = match denom_admin(storage, &denom)? {
+        Some(admin) => admin,
+        None => return Err(SeiError::DenomNotFound { denom }.into()),
+    };
+    Ok(to_json_binary(&DenomAuthorityMetadataResponse {
+        authority_metadata: DenomAuthorityMetadata { admin },
+    })?)
+}
+
+// Query: DenomsFromCreator()
+fn query_denoms_from_creator_helper(
+    storage: &dyn Storage,
+    creator: Addr,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> AnyResult<Binary> {
+    let mut denoms = denoms_from_creator(storage, &creator)?;
+    denoms.sort();
+
+    let (denoms, next_key) = paginate(denoms, |d| d.clone(), start_after, limit);
+    Ok(to_json_binary(&DenomsFromCreatorResponse {
+        denoms,
+        next_key,
+    })?)
 }
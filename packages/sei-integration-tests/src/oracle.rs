@@ -0,0 +1,141 @@
+use anyhow::{anyhow, bail, Result as AnyResult};
+use cosmwasm_std::{Decimal, Storage, Uint64};
+use sei_cosmwasm::{DenomOracleExchangeRatePair, OracleExchangeRate};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// Storage:
+// "OracleExchangeRates" -> JSON list of the exchange rates pushed during the test, in push order
+
+const ORACLE_EXCHANGE_RATES_KEY: &[u8] = b"OracleExchangeRates";
+
+/// Exchange rate of `denom` set by the oracle at the unix time `timestamp` (seconds).
+///
+/// Like the rates given to `SeiModule::new_with_oracle_exchange_rates`, it becomes visible to
+/// the `ExchangeRates` and `OracleTwaps` queries once the block time reaches `timestamp`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct OracleRateUpdate {
+    pub timestamp: u64,
+    pub denom: String,
+    pub exchange_rate: Decimal,
+}
+
+impl OracleRateUpdate {
+    pub fn new(timestamp: u64, denom: impl Into<String>, exchange_rate: Decimal) -> Self {
+        OracleRateUpdate {
+            timestamp,
+            denom: denom.into(),
+            exchange_rate,
+        }
+    }
+
+    /// The mock keeps the update time in seconds in `last_update`, and in milliseconds in
+    /// `last_update_timestamp` as the chain does
+    pub fn to_exchange_rate_pair(&self) -> DenomOracleExchangeRatePair {
+        DenomOracleExchangeRatePair {
+            denom: self.denom.clone(),
+            oracle_exchange_rate: OracleExchangeRate {
+                exchange_rate: self.exchange_rate,
+                last_update: Uint64::new(self.timestamp),
+                last_update_timestamp: self.timestamp * 1_000,
+            },
+        }
+    }
+}
+
+/// Parses a price history with a `timestamp,denom,exchange_rate` header, e.g.
+///
+/// ```text
+/// timestamp,denom,exchange_rate
+/// 1571797419,usei,0.5
+/// 1571797424,usei,0.55
+/// ```
+pub fn parse_rate_updates_csv(csv: &str) -> AnyResult<Vec<OracleRateUpdate>> {
+    let mut lines = csv
+        .lines()
+        .map(str::trim)
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty());
+
+    let columns: Vec<&str> = match lines.next() {
+        Some((_, header)) => header.split(',').map(str::trim).collect(),
+        None => return Ok(vec![]),
+    };
+    let column = |name: &str| {
+        columns
+            .iter()
+            .position(|c| *c == name)
+            .ok_or_else(|| anyhow!("price history: missing {} column", name))
+    };
+    let (timestamp, denom, exchange_rate) = (
+        column("timestamp")?,
+        column("denom")?,
+        column("exchange_rate")?,
+    );
+
+    lines
+        .map(|(i, line)| {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            if fields.len() != columns.len() {
+                bail!(
+                    "price history: line {}: expected {} fields",
+                    i + 1,
+                    columns.len()
+                );
+            }
+            Ok(OracleRateUpdate {
+                timestamp: fields[timestamp]
+                    .parse()
+                    .map_err(|_| anyhow!("price history: line {}: invalid timestamp", i + 1))?,
+                denom: fields[denom].to_string(),
+                exchange_rate: fields[exchange_rate]
+                    .parse()
+                    .map_err(|_| anyhow!("price history: line {}: invalid exchange rate", i + 1))?,
+            })
+        })
+        .collect()
+}
+
+/// Parses a price history given as a JSON list of `OracleRateUpdate`, e.g.
+/// `[{"timestamp": 1571797419, "denom": "usei", "exchange_rate": "0.5"}]`
+pub fn parse_rate_updates_json(json: &str) -> AnyResult<Vec<OracleRateUpdate>> {
+    Ok(serde_json::from_str(json)?)
+}
+
+pub(crate) fn push_rates(storage: &mut dyn Storage, updates: &[OracleRateUpdate]) -> AnyResult<()> {
+    let mut rates = load_pushed_rates(storage)?;
+    rates.extend(updates.iter().map(OracleRateUpdate::to_exchange_rate_pair));
+    storage.set(ORACLE_EXCHANGE_RATES_KEY, &serde_json::to_vec(&rates)?);
+    Ok(())
+}
+
+/// Returns the exchange rates per denom visible at the unix time `now` (seconds), most recent
+/// first: the genesis rates and the pushed rates updated at or before `now`. Of rates with the
+/// same update time, the last one pushed wins.
+pub(crate) fn rates_at(
+    storage: &dyn Storage,
+    genesis_rates: &HashMap<String, Vec<DenomOracleExchangeRatePair>>,
+    now: u64,
+) -> AnyResult<HashMap<String, Vec<DenomOracleExchangeRatePair>>> {
+    let mut rates = genesis_rates.clone();
+    for rate in load_pushed_rates(storage)?
+        .into_iter()
+        .filter(|r| r.oracle_exchange_rate.last_update.u64() <= now)
+    {
+        let denom_rates = rates.entry(rate.denom.clone()).or_default();
+        let pos = denom_rates.partition_point(|r| {
+            r.oracle_exchange_rate.last_update > rate.oracle_exchange_rate.last_update
+        });
+        denom_rates.insert(pos, rate);
+    }
+
+    rates.retain(|_, denom_rates| !denom_rates.is_empty());
+    Ok(rates)
+}
+
+fn load_pushed_rates(storage: &dyn Storage) -> AnyResult<Vec<DenomOracleExchangeRatePair>> {
+    match storage.get(ORACLE_EXCHANGE_RATES_KEY) {
+        Some(rates) => Ok(serde_json::from_slice(&rates)?),
+        None => Ok(vec![]),
+    }
+}
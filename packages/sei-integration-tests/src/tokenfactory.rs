@@ -0,0 +1,175 @@
+use anyhow::{bail, Result as AnyResult};
+use cosmwasm_std::{Addr, Order, Storage};
+use sei_cosmwasm::{Metadata, SeiError};
+
+// Storage:
+// denom -> admin address ("" once the admin renounced the denom)
+// "TokenfactoryCreatorDenoms-" + creator -> JSON list of denoms created by creator
+// "TokenfactoryDenomMetadata-" + denom -> JSON bank metadata of denom
+
+const CREATOR_DENOMS_PREFIX: &str = "TokenfactoryCreatorDenoms-";
+const DENOM_METADATA_PREFIX: &str = "TokenfactoryDenomMetadata-";
+
+pub(crate) fn create_denom(
+    storage: &mut dyn Storage,
+    creator: &Addr,
+    subdenom: &str,
+) -> AnyResult<String> {
+    let denom = format!("factory/{}/{}", creator, subdenom);
+    if storage.get(denom.as_bytes()).is_some() {
+        bail!(SeiError::DenomAlreadyExists { denom });
+    }
+    storage.set(denom.as_bytes(), creator.as_bytes());
+
+    let mut denoms = denoms_from_creator(storage, creator)?;
+    denoms.push(denom.clone());
+    storage.set(
+        creator_denoms_key(creator).as_bytes(),
+        &serde_json::to_vec(&denoms)?,
+    );
+    Ok(denom)
+}
+
+/// Returns the admin of the tokenfactory `denom`, `None` if the denom does not exist and an
+/// empty string if its admin renounced it
+pub(crate) fn denom_admin(storage: &dyn Storage, denom: &str) -> AnyResult<Option<String>> {
+    if !is_factory_denom(denom) {
+        return Ok(None);
+    }
+    match storage.get(denom.as_bytes()) {
+        Some(admin) => Ok(Some(String::from_utf8(admin)?)),
+        None => Ok(None),
+    }
+}
+
+/// Fails with "tokenfactory: must be owner of coin factory denom to `action`" unless `sender` is
+/// the admin of `denom`
+pub(crate) fn ensure_admin(
+    storage: &dyn Storage,
+    denom: &str,
+    sender: &Addr,
+    action: &str,
+) -> AnyResult<()> {
+    match denom_admin(storage, denom)? {
+        Some(admin) if !admin.is_empty() && admin == sender.as_str() => Ok(()),
+        _ => bail!(SeiError::NotDenomAdmin {
+            action: action.to_string(),
+        }),
+    }
+}
+
+/// Transfers the admin of `denom` to `new_admin`, an empty `new_admin` renounces the denom
+pub(crate) fn change_admin(
+    storage: &mut dyn Storage,
+    sender: &Addr,
+    denom: &str,
+    new_admin: &str,
+) -> AnyResult<()> {
+    if denom_admin(storage, denom)?.is_none() {
+        bail!(SeiError::DenomNotFound {
+            denom: denom.to_string(),
+        });
+    }
+    ensure_admin(storage, denom, sender, "change admin")?;
+    storage.set(denom.as_bytes(), new_admin.as_bytes());
+    Ok(())
+}
+
+pub(crate) fn set_metadata(
+    storage: &mut dyn Storage,
+    sender: &Addr,
+    metadata: Metadata,
+) -> AnyResult<()> {
+    if denom_admin(storage, &metadata.base)?.is_none() {
+        bail!(SeiError::DenomNotFound {
+            denom: metadata.base,
+        });
+    }
+    ensure_admin(storage, &metadata.base, sender, "set metadata")?;
+    validate_metadata(&metadata)?;
+    storage.set(
+        denom_metadata_key(&metadata.base).as_bytes(),
+        &serde_json::to_vec(&metadata)?,
+    );
+    Ok(())
+}
+
+pub(crate) fn denoms_from_creator(storage: &dyn Storage, creator: &Addr) -> AnyResult<Vec<String>> {
+    match storage.get(creator_denoms_key(creator).as_bytes()) {
+        Some(denoms) => Ok(serde_json::from_slice(&denoms)?),
+        None => Ok(vec![]),
+    }
+}
+
+pub(crate) fn denom_metadata(storage: &dyn Storage, denom: &str) -> AnyResult<Option<Metadata>> {
+    match storage.get(denom_metadata_key(denom).as_bytes()) {
+        Some(metadata) => Ok(Some(serde_json::from_slice(&metadata)?)),
+        None => Ok(None),
+    }
+}
+
+/// Returns the metadata of all denoms with metadata, ordered by base denom
+pub(crate) fn all_denom_metadata(storage: &dyn Storage) -> AnyResult<Vec<Metadata>> {
+    let start = DENOM_METADATA_PREFIX.as_bytes().to_vec();
+    let mut end = start.clone();
+    *end.last_mut().unwrap() += 1;
+    storage
+        .range(Some(&start), Some(&end), Order::Ascending)
+        .map(|(_, metadata)| Ok(serde_json::from_slice(&metadata)?))
+        .collect()
+}
+
+// Mirrors the checks of the bank module's Metadata.Validate
+fn validate_metadata(metadata: &Metadata) -> AnyResult<()> {
+    if metadata.name.trim().is_empty() {
+        bail!(invalid_metadata("name field cannot be blank"));
+    }
+    if metadata.symbol.trim().is_empty() {
+        bail!(invalid_metadata("symbol field cannot be blank"));
+    }
+    match metadata.denom_units.first() {
+        Some(unit) if unit.denom == metadata.base && unit.exponent == 0 => {}
+        _ => bail!(invalid_metadata(format!(
+            "the first denomination unit must be the base denom {} with exponent 0",
+            metadata.base
+        ))),
+    }
+    if metadata
+        .denom_units
+        .windows(2)
+        .any(|units| units[0].exponent >= units[1].exponent)
+    {
+        bail!(invalid_metadata(
+            "the denomination units must be sorted by ascending exponent"
+        ));
+    }
+    if !metadata
+        .denom_units
+        .iter()
+        .any(|unit| unit.denom == metadata.display)
+    {
+        bail!(invalid_metadata(format!(
+            "the display denom {} is not a denomination unit",
+            metadata.display
+        )));
+    }
+    Ok(())
+}
+
+fn invalid_metadata(reason: impl Into<String>) -> SeiError {
+    SeiError::InvalidMetadata {
+        reason: reason.into(),
+    }
+}
+
+fn is_factory_denom(denom: &str) -> bool {
+    denom.starts_with("factory/")
+}
+
+fn creator_denoms_key(creator: &Addr) -> String {
+    format!("{}{}", CREATOR_DENOMS_PREFIX, creator)
+}
+
+fn denom_metadata_key(denom: &str) -> String {
+    format!("{}{}", DENOM_METADATA_PREFIX, denom)
+}